@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+# Generate SQL files for benchmarking dropping many tables.
+#
+# `batched.sql` drops all tables in a single `DROP TABLE` statement, resulting
+# in a single catalog mutation request to metastore. `individual.sql` drops each
+# table with its own statement, requiring a metastore round trip (and catalog
+# refresh) per table.
+
+set -e
+
+reporoot="$(git rev-parse --show-toplevel)"
+num_tables="${NUM_TABLES:-500}"
+out="${reporoot}/benchmarks/artifacts/drop_tables"
+
+mkdir -p "${out}/queries"
+
+: > "${out}/load.sql"
+: > "${out}/queries/individual.sql"
+names=()
+for i in $(seq 1 "${num_tables}"); do
+    echo "CREATE TABLE drop_bench_${i} (a INT);" >> "${out}/load.sql"
+    echo "DROP TABLE drop_bench_${i};" >> "${out}/queries/individual.sql"
+    names+=("drop_bench_${i}")
+done
+
+(IFS=,; echo "DROP TABLE ${names[*]};") > "${out}/queries/batched.sql"
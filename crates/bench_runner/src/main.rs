@@ -73,6 +73,9 @@ fn main() -> Result<()> {
             None,
             false,
             false,
+            false,
+            false,
+            None,
         )
         .await?;
         tokio::spawn(server.serve(server_conf));
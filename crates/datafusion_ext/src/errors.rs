@@ -6,6 +6,21 @@ pub enum ExtensionError {
     #[error("{0}")]
     String(String),
 
+    #[error("{func} expects {expected}, got {got}")]
+    InvalidArgs {
+        func: String,
+        expected: String,
+        got: usize,
+    },
+
+    #[error("{func} expects {param} to be of type {expected}, got {got}")]
+    InvalidArgType {
+        func: String,
+        param: &'static str,
+        expected: &'static str,
+        got: String,
+    },
+
     #[error("Unable to find {obj_typ}: '{name}'")]
     MissingObject { obj_typ: &'static str, name: String },
 
@@ -21,6 +36,15 @@ pub enum ExtensionError {
     #[error(transparent)]
     Access(Box<dyn std::error::Error + Send + Sync>),
 
+    #[error("Not found: {0}")]
+    NotFound(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Transient error, retrying may succeed: {0}")]
+    Transient(Box<dyn std::error::Error + Send + Sync>),
+
     #[error(transparent)]
     DataFusion(#[from] datafusion::error::DataFusionError),
 
@@ -21,6 +21,11 @@ pub trait TableFunc: Sync + Send {
     /// The name for this table function. This name will be used when looking up
     /// function implementations.
     fn name(&self) -> &str;
+    /// A short description of what this function does. Defaults to an empty
+    /// string.
+    fn description(&self) -> &str {
+        ""
+    }
     fn runtime_preference(&self) -> RuntimePreference;
     fn detect_runtime(
         &self,
@@ -43,7 +48,166 @@ pub trait TableFunc: Sync + Send {
     fn signature(&self) -> Option<Signature> {
         None
     }
+    /// Return the parameters for this function. When provided, arguments are
+    /// checked with `validate_args` before creating a provider.
+    fn params(&self) -> Option<TableFuncParams> {
+        None
+    }
+    /// Validate arguments and options against this function's parameters.
+    fn validate_args(
+        &self,
+        args: &[FuncParamValue],
+        opts: &HashMap<String, FuncParamValue>,
+    ) -> Result<()> {
+        match self.params() {
+            Some(params) => params.validate(self.name(), args, opts),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Expected type of a positional table function argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFuncParamType {
+    String,
+    Ident,
+    Integer,
+    Boolean,
+}
+
+impl TableFuncParamType {
+    fn is_valid(&self, value: &FuncParamValue) -> bool {
+        match self {
+            Self::String => String::is_param_valid(value),
+            Self::Ident => IdentValue::is_param_valid(value),
+            Self::Integer => i64::is_param_valid(value),
+            Self::Boolean => bool::is_param_valid(value),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Ident => "ident",
+            Self::Integer => "integer",
+            Self::Boolean => "boolean",
+        }
+    }
+}
+
+/// A single positional parameter for a table function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableFuncParam {
+    pub name: &'static str,
+    pub typ: TableFuncParamType,
+}
+
+impl TableFuncParam {
+    pub const fn new(name: &'static str, typ: TableFuncParamType) -> Self {
+        TableFuncParam { name, typ }
+    }
 }
+
+/// Parameters for a table function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableFuncParams {
+    /// Positional parameters that must always be provided.
+    pub required: &'static [TableFuncParam],
+    /// Positional parameters that may be provided after the required ones.
+    pub optional: &'static [TableFuncParam],
+    /// Named options that must always be provided.
+    pub required_opts: &'static [&'static str],
+}
+
+impl TableFuncParams {
+    pub const fn new(
+        required: &'static [TableFuncParam],
+        optional: &'static [TableFuncParam],
+    ) -> Self {
+        TableFuncParams {
+            required,
+            optional,
+            required_opts: &[],
+        }
+    }
+
+    pub const fn with_required_opts(mut self, required_opts: &'static [&'static str]) -> Self {
+        self.required_opts = required_opts;
+        self
+    }
+
+    /// Check the arguments and options match the parameters, producing an
+    /// error describing what was expected if not.
+    pub fn validate(
+        &self,
+        func: &str,
+        args: &[FuncParamValue],
+        opts: &HashMap<String, FuncParamValue>,
+    ) -> Result<()> {
+        self.validate_count(func, args)?;
+
+        let params = self.required.iter().chain(self.optional.iter());
+        for (param, arg) in params.zip(args) {
+            if !param.typ.is_valid(arg) {
+                return Err(ExtensionError::InvalidArgType {
+                    func: func.to_string(),
+                    param: param.name,
+                    expected: param.typ.as_str(),
+                    got: arg.to_string(),
+                });
+            }
+        }
+
+        if let Some(opt) = self
+            .required_opts
+            .iter()
+            .find(|opt| !opts.contains_key(**opt))
+        {
+            return Err(ExtensionError::MissingNamedArgument(opt));
+        }
+
+        Ok(())
+    }
+
+    fn validate_count(&self, func: &str, args: &[FuncParamValue]) -> Result<()> {
+        let min = self.required.len();
+        let max = min + self.optional.len();
+        if (min..=max).contains(&args.len()) {
+            return Ok(());
+        }
+
+        let count = if min == max {
+            let plural = if min == 1 { "" } else { "s" };
+            format!("{min} positional argument{plural}")
+        } else {
+            format!("{min} to {max} positional arguments")
+        };
+
+        let names: Vec<_> = self
+            .required
+            .iter()
+            .map(|param| param.name.to_string())
+            .chain(
+                self.optional
+                    .iter()
+                    .map(|param| format!("[{}]", param.name)),
+            )
+            .collect();
+
+        let expected = if names.is_empty() {
+            count
+        } else {
+            format!("{count} ({})", names.join(", "))
+        };
+
+        Err(ExtensionError::InvalidArgs {
+            func: func.to_string(),
+            expected,
+            got: args.len(),
+        })
+    }
+}
+
 pub trait TableFuncContextProvider: Sync + Send {
     fn get_database_entry(&self, name: &str) -> Option<&DatabaseEntry>;
     fn get_credentials_entry(&self, name: &str) -> Option<&CredentialsEntry>;
@@ -199,6 +363,22 @@ impl FromFuncParamValue for String {
     }
 }
 
+impl FromFuncParamValue for bool {
+    fn from_param(value: FuncParamValue) -> Result<Self> {
+        match value {
+            FuncParamValue::Scalar(ScalarValue::Boolean(Some(b))) => Ok(b),
+            other => Err(ExtensionError::InvalidParamValue {
+                param: other.to_string(),
+                expected: "boolean",
+            }),
+        }
+    }
+
+    fn is_param_valid(value: &FuncParamValue) -> bool {
+        matches!(value, FuncParamValue::Scalar(ScalarValue::Boolean(Some(_))))
+    }
+}
+
 impl<T> FromFuncParamValue for Vec<T>
 where
     T: FromFuncParamValue,
@@ -381,3 +561,108 @@ impl FromFuncParamValue for Decimal128 {
             || i64::is_param_valid(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCATION: TableFuncParam = TableFuncParam::new("location", TableFuncParamType::String);
+    const CREDENTIALS: TableFuncParam =
+        TableFuncParam::new("credentials", TableFuncParamType::Ident);
+
+    fn location_args(n: usize) -> Vec<FuncParamValue> {
+        let mut args = vec![FuncParamValue::Scalar(ScalarValue::Utf8(Some(
+            "file.parquet".to_string(),
+        )))];
+        args.extend((1..n).map(|i| FuncParamValue::Ident(format!("creds{i}"))));
+        args.truncate(n);
+        args
+    }
+
+    #[test]
+    fn validate_params() {
+        let no_opts = HashMap::new();
+        let params = TableFuncParams::new(&[LOCATION], &[CREDENTIALS]);
+        params
+            .validate("my_func", &location_args(1), &no_opts)
+            .unwrap();
+        params
+            .validate("my_func", &location_args(2), &no_opts)
+            .unwrap();
+
+        let missing = params
+            .validate("my_func", &location_args(0), &no_opts)
+            .unwrap_err();
+        assert_eq!(
+            "my_func expects 1 to 2 positional arguments (location, [credentials]), got 0",
+            missing.to_string()
+        );
+
+        let extra = params
+            .validate("my_func", &location_args(3), &no_opts)
+            .unwrap_err();
+        assert_eq!(
+            "my_func expects 1 to 2 positional arguments (location, [credentials]), got 3",
+            extra.to_string()
+        );
+
+        let exact = TableFuncParams::new(&[LOCATION], &[]);
+        let err = exact
+            .validate("my_func", &location_args(0), &no_opts)
+            .unwrap_err();
+        assert_eq!(
+            "my_func expects 1 positional argument (location), got 0",
+            err.to_string()
+        );
+
+        let none = TableFuncParams::new(&[], &[]);
+        let err = none
+            .validate("my_func", &location_args(1), &no_opts)
+            .unwrap_err();
+        assert_eq!(
+            "my_func expects 0 positional arguments, got 1",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn validate_param_types() {
+        let no_opts = HashMap::new();
+        let params = TableFuncParams::new(&[LOCATION], &[CREDENTIALS]);
+
+        let args = vec![FuncParamValue::Scalar(ScalarValue::Int64(Some(1)))];
+        let err = params.validate("my_func", &args, &no_opts).unwrap_err();
+        assert_eq!(
+            "my_func expects location to be of type string, got 1",
+            err.to_string()
+        );
+
+        let args = vec![
+            FuncParamValue::Scalar(ScalarValue::Utf8(Some("file.parquet".to_string()))),
+            FuncParamValue::Scalar(ScalarValue::Utf8(Some("creds".to_string()))),
+        ];
+        let err = params.validate("my_func", &args, &no_opts).unwrap_err();
+        assert_eq!(
+            "my_func expects credentials to be of type ident, got creds",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn validate_required_opts() {
+        let params = TableFuncParams::new(&[LOCATION], &[]).with_required_opts(&["region"]);
+
+        let err = params
+            .validate("my_func", &location_args(1), &HashMap::new())
+            .unwrap_err();
+        assert_eq!("Missing named argument: 'region'", err.to_string());
+
+        let opts = HashMap::from([(
+            "region".to_string(),
+            FuncParamValue::Scalar(ScalarValue::Utf8(Some("us-east-1".to_string()))),
+        )]);
+        params
+            .validate("my_func", &location_args(1), &opts)
+            .unwrap();
+    }
+}
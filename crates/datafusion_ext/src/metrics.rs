@@ -1,23 +1,29 @@
+use async_trait::async_trait;
 use datafusion::{
     arrow::datatypes::SchemaRef,
     arrow::{datatypes::Schema, record_batch::RecordBatch},
+    datasource::TableProvider,
     error::Result,
-    execution::TaskContext,
+    execution::{context::SessionState, TaskContext},
+    logical_expr::{TableProviderFilterPushDown, TableType},
     physical_expr::PhysicalSortExpr,
     physical_plan::{
         metrics::{BaselineMetrics, ExecutionPlanMetricsSet, Gauge, MetricBuilder, MetricsSet},
         DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, RecordBatchStream,
         SendableRecordBatchStream, Statistics,
     },
+    prelude::Expr,
 };
 use futures::{Stream, StreamExt};
 use std::task::{Context, Poll};
+use std::time::Duration;
 use std::{any::Any, pin::Pin};
 use std::{fmt, marker::PhantomData};
 use std::{fmt::Debug, sync::Arc};
 
 const BYTES_READ_GAUGE_NAME: &str = "bytes_read";
 const BYTES_WRITTEN_GAUGE_NAME: &str = "bytes_written";
+const METADATA_FETCH_TIME_NAME: &str = "metadata_fetch";
 
 #[derive(Debug, Default)]
 pub struct DataSourceMetricsOpts {
@@ -287,6 +293,182 @@ impl RecordBatchStream for BoxedStreamAdapater {
     }
 }
 
+/// Wrapper around a table provider returned from a table function that
+/// records how long it took to create the provider.
+///
+/// Table functions typically do all of their metadata fetching (listing
+/// objects, reading manifests, etc) when creating the provider. Reporting that
+/// separately from the scan helps distinguish slow object storage from slow
+/// execution.
+#[derive(Clone)]
+pub struct TableFuncMetricsProvider {
+    name: String,
+    create_time: Duration,
+    provider: Arc<dyn TableProvider>,
+}
+
+impl TableFuncMetricsProvider {
+    pub fn new(
+        name: impl Into<String>,
+        create_time: Duration,
+        provider: Arc<dyn TableProvider>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            create_time,
+            provider,
+        }
+    }
+}
+
+#[async_trait]
+impl TableProvider for TableFuncMetricsProvider {
+    /// Returns the wrapped provider so that callers downcasting to a concrete
+    /// provider still see through the wrapper.
+    fn as_any(&self) -> &dyn Any {
+        self.provider.as_any()
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.provider.schema()
+    }
+
+    fn table_type(&self) -> TableType {
+        self.provider.table_type()
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.provider.get_table_definition()
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let plan = self
+            .provider
+            .scan(state, projection, filters, limit)
+            .await?;
+        Ok(Arc::new(TableFuncMetricsExec::new(
+            self.name.clone(),
+            self.create_time,
+            plan,
+        )))
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.provider.supports_filters_pushdown(filters)
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        self.provider.statistics()
+    }
+
+    async fn insert_into(
+        &self,
+        state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.provider.insert_into(state, input, overwrite).await
+    }
+}
+
+/// Passthrough execution plan reporting the provider creation time for a
+/// table function, along with the number of rows it produced.
+#[derive(Debug, Clone)]
+pub struct TableFuncMetricsExec {
+    name: String,
+    create_time: Duration,
+    child: Arc<dyn ExecutionPlan>,
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl TableFuncMetricsExec {
+    pub fn new(name: String, create_time: Duration, child: Arc<dyn ExecutionPlan>) -> Self {
+        let metrics = ExecutionPlanMetricsSet::new();
+        MetricBuilder::new(&metrics)
+            .subset_time(METADATA_FETCH_TIME_NAME, 0)
+            .add_duration(create_time);
+
+        Self {
+            name,
+            create_time,
+            child,
+            metrics,
+        }
+    }
+}
+
+impl ExecutionPlan for TableFuncMetricsExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        self.child.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.child.output_partitioning()
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.child.output_ordering()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.child.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(Self::new(
+            self.name.clone(),
+            self.create_time,
+            children[0].clone(),
+        )))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let stream = self.child.execute(partition, context)?;
+        // Bytes read are tracked by the data sources themselves, only track
+        // baseline metrics here.
+        Ok(Box::pin(BoxedStreamAdapater::new(
+            stream,
+            partition,
+            &self.metrics,
+            DataSourceMetricsOpts::default(),
+        )))
+    }
+
+    fn statistics(&self) -> Statistics {
+        self.child.statistics()
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+impl DisplayAs for TableFuncMetricsExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TableFuncMetricsExec: function={}", self.name)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregatedMetrics {
     /// Total time taken for a plan to execute.
@@ -336,3 +518,32 @@ impl AggregatedMetrics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::physical_plan::empty::EmptyExec;
+
+    #[test]
+    fn table_func_metrics_report_create_time() {
+        let child = Arc::new(EmptyExec::new(false, Arc::new(Schema::empty())));
+        let exec = TableFuncMetricsExec::new(
+            "my_func".to_string(),
+            Duration::from_millis(120),
+            child.clone(),
+        );
+
+        let metadata_fetch = |exec: &dyn ExecutionPlan| {
+            exec.metrics()
+                .unwrap()
+                .sum_by_name(METADATA_FETCH_TIME_NAME)
+                .map(|v| v.as_usize())
+        };
+        let expected = Some(Duration::from_millis(120).as_nanos() as usize);
+        assert_eq!(expected, metadata_fetch(&exec));
+
+        // Recreating the plan keeps the original timing.
+        let exec = Arc::new(exec).with_new_children(vec![child]).unwrap();
+        assert_eq!(expected, metadata_fetch(exec.as_ref()));
+    }
+}
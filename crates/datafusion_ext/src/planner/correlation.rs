@@ -0,0 +1,152 @@
+//! Validation of correlated subqueries.
+use datafusion::common::tree_node::{TreeNode, VisitRecursion};
+use datafusion::common::{DataFusionError, Result};
+use datafusion::logical_expr::expr::{Exists, InSubquery};
+use datafusion::logical_expr::{Expr, LogicalPlan, Subquery};
+
+/// Check that all correlated subqueries in a plan can be executed.
+///
+/// Subqueries are decorrelated by pulling correlated predicates up into a
+/// join, which only works for predicates in a filter (WHERE or HAVING).
+/// Correlated columns referenced anywhere else in a subquery (the select list,
+/// ORDER BY, join conditions, etc) would otherwise only fail once the query is
+/// executed.
+pub fn validate_correlated_subqueries(plan: &LogicalPlan) -> Result<()> {
+    for expr in plan.expressions() {
+        for subquery in find_subqueries(&expr)? {
+            check_subquery_plan(&subquery.subquery)?;
+            validate_correlated_subqueries(&subquery.subquery)?;
+        }
+    }
+    for input in plan.inputs() {
+        validate_correlated_subqueries(input)?;
+    }
+    Ok(())
+}
+
+/// Check that the nodes of a subquery's plan only reference outer columns in
+/// filters.
+fn check_subquery_plan(plan: &LogicalPlan) -> Result<()> {
+    if !matches!(plan, LogicalPlan::Filter(_)) {
+        for expr in plan.expressions() {
+            if let Some(col) = find_outer_reference(&expr)? {
+                return Err(DataFusionError::Plan(format!(
+                    "Correlated column '{col}' is not supported in the {} of a subquery; correlated columns can only be referenced in WHERE or HAVING",
+                    node_description(plan),
+                )));
+            }
+        }
+    }
+    for input in plan.inputs() {
+        check_subquery_plan(input)?;
+    }
+    Ok(())
+}
+
+/// Find the subqueries directly referenced by an expression.
+///
+/// This doesn't descend into the subqueries' plans.
+fn find_subqueries(expr: &Expr) -> Result<Vec<Subquery>> {
+    let mut subqueries = Vec::new();
+    expr.apply(&mut |expr| {
+        match expr {
+            Expr::Exists(Exists { subquery, .. })
+            | Expr::InSubquery(InSubquery { subquery, .. })
+            | Expr::ScalarSubquery(subquery) => subqueries.push(subquery.clone()),
+            _ => (),
+        }
+        Ok(VisitRecursion::Continue)
+    })?;
+    Ok(subqueries)
+}
+
+/// Find the first outer reference column in an expression.
+fn find_outer_reference(expr: &Expr) -> Result<Option<String>> {
+    let mut found = None;
+    expr.apply(&mut |expr| {
+        if let Expr::OuterReferenceColumn(_, col) = expr {
+            found = Some(col.flat_name());
+            return Ok(VisitRecursion::Stop);
+        }
+        Ok(VisitRecursion::Continue)
+    })?;
+    Ok(found)
+}
+
+/// Describe where in a query a plan node's expressions came from.
+fn node_description(plan: &LogicalPlan) -> &'static str {
+    match plan {
+        LogicalPlan::Projection(_) => "select list",
+        LogicalPlan::Aggregate(_) => "aggregate",
+        LogicalPlan::Window(_) => "window functions",
+        LogicalPlan::Sort(_) => "ORDER BY",
+        LogicalPlan::Join(_) => "join condition",
+        LogicalPlan::Distinct(_) => "DISTINCT ON",
+        LogicalPlan::Values(_) => "VALUES list",
+        _ => "plan",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::arrow::datatypes::DataType;
+    use datafusion::common::Column;
+    use datafusion::logical_expr::{col, exists, lit, LogicalPlanBuilder};
+    use std::sync::Arc;
+
+    fn outer_ref(name: &str) -> Expr {
+        Expr::OuterReferenceColumn(DataType::Int32, Column::new(Some("t1"), name))
+    }
+
+    fn outer() -> LogicalPlanBuilder {
+        LogicalPlanBuilder::values(vec![vec![lit(1_i32)]])
+            .unwrap()
+            .alias("t1")
+            .unwrap()
+    }
+
+    fn inner() -> LogicalPlanBuilder {
+        LogicalPlanBuilder::values(vec![vec![lit(2_i32)]])
+            .unwrap()
+            .alias("t2")
+            .unwrap()
+    }
+
+    #[test]
+    fn correlated_filter_allowed() {
+        let subquery = inner()
+            .filter(col("t2.column1").eq(outer_ref("column1")))
+            .unwrap()
+            .build()
+            .unwrap();
+        let plan = outer()
+            .filter(exists(Arc::new(subquery)))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        validate_correlated_subqueries(&plan).unwrap();
+    }
+
+    #[test]
+    fn correlated_projection_rejected() {
+        let subquery = inner()
+            .project(vec![col("t2.column1") + outer_ref("column1")])
+            .unwrap()
+            .build()
+            .unwrap();
+        let plan = outer()
+            .filter(exists(Arc::new(subquery)))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let err = validate_correlated_subqueries(&plan).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Correlated column 't1.column1' is not supported in the select list"),
+            "{err}"
+        );
+    }
+}
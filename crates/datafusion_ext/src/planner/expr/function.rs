@@ -39,13 +39,16 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         schema: &DFSchema,
         planner_context: &mut PlannerContext,
     ) -> Result<Expr> {
-        let name = if function.name.0.len() > 1 {
-            // DF doesn't handle compound identifiers
-            // (e.g. "foo.bar") for function names yet
-            function.name.to_string()
-        } else {
-            self.normalizer.normalize(function.name.0[0].clone())
-        };
+        // Schema-qualified names (e.g. "pg_catalog.foo") are passed through
+        // as a single dotted name, leaving resolution of the qualifier to the
+        // context provider.
+        let name = function
+            .name
+            .0
+            .iter()
+            .map(|ident| self.normalizer.normalize(ident.clone()))
+            .collect::<Vec<_>>()
+            .join(".");
 
         // next, scalar built-in
         if let Ok(fun) = BuiltinScalarFunction::from_str(&name) {
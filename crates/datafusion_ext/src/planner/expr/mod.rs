@@ -573,6 +573,16 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         schema: &DFSchema,
         planner_context: &mut PlannerContext,
     ) -> Result<Expr> {
+        let expr = self
+            .sql_expr_to_logical_expr(expr, schema, planner_context)
+            .await?;
+
+        // Membership in an empty list is never satisfied, regardless of the
+        // value being tested (including NULL). Fold it to a constant.
+        if list.is_empty() {
+            return Ok(lit(negated));
+        }
+
         let mut list_expr = Vec::with_capacity(list.len());
         for e in list {
             let e = self
@@ -582,10 +592,7 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         }
 
         Ok(Expr::InList(InList::new(
-            Box::new(
-                self.sql_expr_to_logical_expr(expr, schema, planner_context)
-                    .await?,
-            ),
+            Box::new(expr),
             list_expr,
             negated,
         )))
@@ -18,9 +18,9 @@
 use crate::planner::{AsyncContextProvider, SqlQueryPlanner};
 use datafusion::common::{DFSchema, DataFusionError, Result};
 use datafusion::logical_expr::expr::Sort;
-use datafusion::logical_expr::Expr;
+use datafusion::logical_expr::{lower, Expr};
 use datafusion::sql::planner::PlannerContext;
-use datafusion::sql::sqlparser::ast::{Expr as SQLExpr, OrderByExpr, Value};
+use datafusion::sql::sqlparser::ast::{Expr as SQLExpr, ObjectName, OrderByExpr, Value};
 
 impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
     /// convert sql OrderByExpr to Expr::Sort
@@ -38,6 +38,11 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                 nulls_first,
             } = e;
 
+            let (expr, collation) = match expr {
+                SQLExpr::Collate { expr, collation } => (expr.as_ref(), Some(collation)),
+                expr => (expr, None),
+            };
+
             let expr = match expr {
                 SQLExpr::Value(Value::Number(v, _)) => {
                     let field_index = v
@@ -64,6 +69,10 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                         .await?
                 }
             };
+            let expr = match collation {
+                Some(collation) => apply_collation(expr, collation)?,
+                None => expr,
+            };
             let asc = asc.unwrap_or(true);
             expr_vec.push(Expr::Sort(Sort::new(
                 Box::new(expr),
@@ -76,3 +85,26 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         Ok(expr_vec)
     }
 }
+
+/// Apply a collation to a sort expression.
+///
+/// Only a small set of collations are supported:
+///
+/// - 'C', 'POSIX', and 'default' sort by bytes, which is the default behavior.
+/// - 'nocase' and 'case_insensitive' sort strings ignoring case.
+fn apply_collation(expr: Expr, collation: &ObjectName) -> Result<Expr> {
+    let name = collation
+        .0
+        .iter()
+        .map(|ident| ident.value.as_str())
+        .collect::<Vec<_>>()
+        .join(".");
+
+    match name.to_lowercase().as_str() {
+        "c" | "posix" | "default" => Ok(expr),
+        "nocase" | "case_insensitive" => Ok(lower(expr)),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported collation: {name}"
+        ))),
+    }
+}
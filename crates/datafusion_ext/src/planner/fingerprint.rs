@@ -0,0 +1,79 @@
+//! Fingerprints for logical plans.
+use std::hash::{Hash, Hasher};
+
+use datafusion::logical_expr::LogicalPlan;
+use siphasher::sip::SipHasher13;
+
+/// Fixed keys for the fingerprint hasher. std's `DefaultHasher` makes no
+/// guarantees about its algorithm or keys, so fingerprints use an explicit
+/// hasher instead.
+const FINGERPRINT_KEYS: (u64, u64) = (0x676c_6172_6564_6221, 0x706c_616e_5f66_7072);
+
+/// Compute a fingerprint for a logical plan, suitable for keying a plan cache.
+///
+/// Structurally identical plans produce the same fingerprint. Parameter
+/// placeholders are hashed by their ids rather than their values, so
+/// fingerprinting a prepared statement's plan before binding parameters gives
+/// the same fingerprint for every execution.
+///
+/// Fingerprints are stable across runs, but should not be persisted across
+/// versions since the plan structure may change.
+pub fn plan_fingerprint(plan: &LogicalPlan) -> u64 {
+    let (k0, k1) = FINGERPRINT_KEYS;
+    let mut hasher = SipHasher13::new_with_keys(k0, k1);
+    plan.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::logical_expr::expr::Placeholder;
+    use datafusion::logical_expr::{col, lit, Expr, LogicalPlanBuilder};
+    use datafusion::scalar::ScalarValue;
+
+    fn plan(filter: Expr) -> LogicalPlan {
+        LogicalPlanBuilder::values(vec![vec![lit(1), lit("a")], vec![lit(2), lit("b")]])
+            .unwrap()
+            .filter(filter)
+            .unwrap()
+            .project(vec![col("column2")])
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn identical_plans_match() {
+        let a = plan(col("column1").gt(lit(1)));
+        let b = plan(col("column1").gt(lit(1)));
+        assert_eq!(plan_fingerprint(&a), plan_fingerprint(&b));
+    }
+
+    #[test]
+    fn different_plans_differ() {
+        let a = plan(col("column1").gt(lit(1)));
+        let b = plan(col("column1").lt(lit(1)));
+        assert_ne!(plan_fingerprint(&a), plan_fingerprint(&b));
+    }
+
+    #[test]
+    fn parameter_values_factored_out() {
+        let placeholder = || Expr::Placeholder(Placeholder::new("$1".to_string(), None));
+
+        // The same prepared statement planned twice, to be executed with
+        // different parameter values.
+        let first = plan(col("column1").gt(placeholder()));
+        let second = plan(col("column1").gt(placeholder()));
+        assert_eq!(plan_fingerprint(&first), plan_fingerprint(&second));
+
+        // Values are only part of the fingerprint once bound.
+        let first = first
+            .with_param_values(vec![ScalarValue::Int32(Some(1))])
+            .unwrap();
+        let second = second
+            .with_param_values(vec![ScalarValue::Int32(Some(2))])
+            .unwrap();
+        assert_ne!(plan_fingerprint(&first), plan_fingerprint(&second));
+    }
+}
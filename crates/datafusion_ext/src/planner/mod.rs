@@ -16,12 +16,15 @@
 // under the License.
 
 //! SQL Query Planner (produces logical plan from SQL AST)
+pub mod correlation;
 mod expr;
+pub mod fingerprint;
 mod query;
 mod relation;
 mod select;
 mod set_expr;
 mod statement;
+pub mod streaming;
 pub mod utils;
 mod values;
 
@@ -15,14 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::planner::correlation::validate_correlated_subqueries;
 use crate::planner::{AsyncContextProvider, SqlQueryPlanner};
 
 use async_recursion::async_recursion;
 use datafusion::common::{DataFusionError, Result, ScalarValue};
-use datafusion::logical_expr::{Expr, LogicalPlan, LogicalPlanBuilder};
+use datafusion::logical_expr::{
+    expr, lit, BuiltInWindowFunction, Expr, LogicalPlan, LogicalPlanBuilder, Projection,
+    WindowFrame, WindowFunction,
+};
 use datafusion::sql::planner::PlannerContext;
 use datafusion::sql::sqlparser::ast::{
-    Expr as SQLExpr, Offset as SQLOffset, OrderByExpr, Query, Value,
+    Distinct, Expr as SQLExpr, Fetch, Offset as SQLOffset, OrderByExpr, Query, SetExpr, Value,
 };
 
 use datafusion::sql::sqlparser::parser::ParserError::ParserError;
@@ -30,8 +34,11 @@ use datafusion::sql::sqlparser::parser::ParserError::ParserError;
 impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
     /// Generate a logical plan from an SQL query
     pub async fn query_to_plan(&mut self, query: Query) -> Result<LogicalPlan> {
-        self.query_to_plan_with_context(query, &mut PlannerContext::new())
-            .await
+        let plan = self
+            .query_to_plan_with_context(query, &mut PlannerContext::new())
+            .await?;
+        validate_correlated_subqueries(&plan)?;
+        Ok(plan)
     }
 
     pub async fn query_to_plan_with_context(
@@ -51,7 +58,7 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         query: Query,
         planner_context: &mut PlannerContext,
     ) -> Result<LogicalPlan> {
-        let set_expr = query.body;
+        let mut set_expr = query.body;
         if let Some(with) = query.with {
             // Process CTEs from top to bottom
             // do not allow self-references
@@ -82,9 +89,146 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                 planner_context.insert_cte(cte_name, logical_plan);
             }
         }
+
+        // DISTINCT ON depends on the query's ORDER BY, so it's planned here
+        // rather than alongside the rest of the select.
+        let distinct_on = match set_expr.as_mut() {
+            SetExpr::Select(select) => match select.distinct.take() {
+                Some(Distinct::On(exprs)) => Some(exprs),
+                other => {
+                    select.distinct = other;
+                    None
+                }
+            },
+            _ => None,
+        };
+
         let plan = self.set_expr_to_plan(*set_expr, planner_context).await?;
+        let plan = match distinct_on {
+            Some(on) => {
+                self.distinct_on(plan, on, &query.order_by, planner_context)
+                    .await?
+            }
+            None => plan,
+        };
         let plan = self.order_by(plan, query.order_by, planner_context).await?;
-        self.limit(plan, query.offset, query.limit).await
+        let limit = match query.fetch {
+            Some(fetch) => {
+                if query.limit.is_some() {
+                    return Err(DataFusionError::Plan(
+                        "Cannot specify both LIMIT and FETCH".to_string(),
+                    ));
+                }
+                Self::fetch_to_limit(fetch)?
+            }
+            None => query.limit,
+        };
+        self.limit(plan, query.offset, limit).await
+    }
+
+    /// Convert an ANSI `FETCH { FIRST | NEXT } n ROWS ONLY` clause into the
+    /// equivalent `LIMIT` expression.
+    fn fetch_to_limit(fetch: Fetch) -> Result<Option<SQLExpr>> {
+        if fetch.with_ties {
+            return Err(DataFusionError::NotImplemented(
+                "FETCH ... WITH TIES is not supported".to_string(),
+            ));
+        }
+        if fetch.percent {
+            return Err(DataFusionError::NotImplemented(
+                "FETCH ... PERCENT is not supported".to_string(),
+            ));
+        }
+        // `FETCH FIRST ROW ONLY` without a quantity fetches a single row.
+        Ok(Some(fetch.quantity.unwrap_or_else(|| {
+            SQLExpr::Value(Value::Number("1".to_string(), false))
+        })))
+    }
+
+    /// Plan `DISTINCT ON (...)`, keeping the first row of each group of rows
+    /// with equal ON expressions according to the ORDER BY.
+    ///
+    /// This is planned as a `ROW_NUMBER()` window partitioned by the ON
+    /// expressions, filtered to the first row of each partition.
+    async fn distinct_on(
+        &mut self,
+        input: LogicalPlan,
+        on: Vec<SQLExpr>,
+        order_by: &[OrderByExpr],
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let schema = input.schema().clone();
+
+        let mut on_exprs = Vec::with_capacity(on.len());
+        for e in on {
+            on_exprs.push(self.sql_to_expr(e, &schema, planner_context).await?);
+        }
+        let sort_exprs = self
+            .order_by_to_sort_expr(order_by, &schema, planner_context)
+            .await?;
+
+        // Same restriction as Postgres, otherwise which row is kept for each
+        // group would be unpredictable.
+        let matches_order_by = sort_exprs
+            .iter()
+            .take(on_exprs.len())
+            .all(|sort| match sort {
+                Expr::Sort(sort) => on_exprs.contains(sort.expr.as_ref()),
+                _ => false,
+            });
+        if !matches_order_by {
+            return Err(DataFusionError::Plan(
+                "SELECT DISTINCT ON expressions must match initial ORDER BY expressions"
+                    .to_string(),
+            ));
+        }
+
+        // ORDER BY may reference columns that aren't in the select list. Let
+        // the sort add those columns to the input, then window over the
+        // sort's input instead of the sort itself.
+        let (input, sort_exprs) = if sort_exprs.is_empty() {
+            (input, sort_exprs)
+        } else {
+            match LogicalPlanBuilder::from(input).sort(sort_exprs)?.build()? {
+                LogicalPlan::Sort(sort) => (sort.input.as_ref().clone(), sort.expr),
+                LogicalPlan::Projection(Projection { input, .. }) => match input.as_ref() {
+                    LogicalPlan::Sort(sort) => (sort.input.as_ref().clone(), sort.expr.clone()),
+                    other => {
+                        return Err(DataFusionError::Internal(format!(
+                            "Unexpected plan below projection for DISTINCT ON: {other:?}"
+                        )))
+                    }
+                },
+                other => {
+                    return Err(DataFusionError::Internal(format!(
+                        "Unexpected plan for DISTINCT ON sort: {other:?}"
+                    )))
+                }
+            }
+        };
+
+        let num_input_fields = input.schema().fields().len();
+        let row_number = Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::RowNumber),
+            Vec::new(),
+            on_exprs,
+            sort_exprs,
+            WindowFrame::new(!order_by.is_empty()),
+        ));
+        let plan = LogicalPlanBuilder::window_plan(input, vec![row_number])?;
+
+        // The window expression is appended after the input columns.
+        let row_number = Expr::Column(plan.schema().field(num_input_fields).qualified_column());
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|f| Expr::Column(f.qualified_column()))
+            .collect::<Vec<_>>();
+
+        LogicalPlanBuilder::from(plan)
+            .filter(row_number.eq(lit(1_u64)))?
+            .project(columns)?
+            .build()
     }
 
     /// Wrap a plan in a limit
@@ -68,6 +68,16 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         join: Join,
         planner_context: &mut PlannerContext,
     ) -> Result<LogicalPlan> {
+        if let JoinOperator::CrossJoin = join.join_operator {
+            // SELECT * FROM t CROSS JOIN unnest(t.arr)
+            if let Some(plan) = self
+                .plan_lateral_unnest(&left, &join.relation, planner_context)
+                .await?
+            {
+                return Ok(plan);
+            }
+        }
+
         let right = self.create_relation(join.relation, planner_context).await?;
         match join.join_operator {
             JoinOperator::LeftOuter(constraint) => {
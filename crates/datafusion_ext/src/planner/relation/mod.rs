@@ -17,12 +17,18 @@
 
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
 use crate::functions::FuncParamValue;
 use crate::planner::{AsyncContextProvider, SqlQueryPlanner};
 
 use async_recursion::async_recursion;
-use datafusion::common::{DataFusionError, OwnedTableReference, Result};
+use datafusion::common::{
+    Column, DFSchema, DataFusionError, OwnedTableReference, Result, UnnestOptions,
+};
+use datafusion::logical_expr::Expr;
+use datafusion::optimizer::simplify_expressions::{ExprSimplifier, SimplifyContext};
+use datafusion::physical_expr::execution_props::ExecutionProps;
 
 use datafusion::logical_expr::{LogicalPlan, LogicalPlanBuilder};
 
@@ -33,6 +39,13 @@ use datafusion::sql::sqlparser::ast;
 mod join;
 
 impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
+    /// Create a `LogicalPlan` for a relation outside of a query, e.g. the
+    /// source of a MERGE.
+    pub async fn relation_to_plan(&mut self, relation: ast::TableFactor) -> Result<LogicalPlan> {
+        self.create_relation(relation, &mut PlannerContext::new())
+            .await
+    }
+
     /// Create a `LogicalPlan` that scans the named relation
     #[async_recursion]
     async fn create_relation(
@@ -82,7 +95,7 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                             // Table factor has arguments, look up table returning
                             // function.
                             for arg in args {
-                                let (name, val) = self.get_constant_function_arg(arg)?;
+                                let (name, val) = self.get_constant_function_arg(arg).await?;
                                 if let Some(name) = name {
                                     named_args.insert(name, val);
                                 } else {
@@ -136,6 +149,34 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                     .await?,
                 alias,
             ),
+            ast::TableFactor::UNNEST {
+                alias,
+                array_expr,
+                with_offset,
+                ..
+            } => {
+                // SELECT * FROM unnest([1, 2, 3])
+                //
+                // The parser produces a dedicated table factor for this instead
+                // of a regular table function call.
+                if with_offset {
+                    return Err(DataFusionError::NotImplemented(
+                        "UNNEST WITH OFFSET".to_string(),
+                    ));
+                }
+
+                let table_ref = OwnedTableReference::Bare {
+                    table: "unnest".into(),
+                };
+                let args = vec![self.get_param_val(*array_expr).await?];
+                let provider = self
+                    .schema_provider
+                    .get_table_func(table_ref.clone(), args, HashMap::new())
+                    .await?;
+
+                let plan = LogicalPlanBuilder::scan(table_ref, provider, None)?.build()?;
+                (plan, alias)
+            }
             // @todo Support TableFactory::TableFunction?
             _ => {
                 return Err(DataFusionError::NotImplemented(format!(
@@ -150,23 +191,95 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
         }
     }
 
+    /// Plan an `UNNEST` whose argument references columns of `input` as an
+    /// unnest over `input`, e.g. `SELECT * FROM t, unnest(t.arr)`.
+    ///
+    /// Each row of `input` is repeated once per element of its array, and rows
+    /// with null or empty arrays are dropped. The unnested column is named
+    /// after the column alias, then the table alias, falling back to
+    /// `unnest`. The column isn't qualified by the table alias.
+    ///
+    /// Returns `None` if the relation isn't an `UNNEST` referencing columns,
+    /// in which case it should be planned on its own.
+    pub(crate) async fn plan_lateral_unnest(
+        &mut self,
+        input: &LogicalPlan,
+        relation: &ast::TableFactor,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Option<LogicalPlan>> {
+        let (alias, array_expr, with_offset) = match relation {
+            ast::TableFactor::UNNEST {
+                alias,
+                array_expr,
+                with_offset,
+                ..
+            } => (alias, array_expr, *with_offset),
+            _ => return Ok(None),
+        };
+
+        let expr = self
+            .sql_to_expr(array_expr.as_ref().clone(), input.schema(), planner_context)
+            .await?;
+        if expr.to_columns()?.is_empty() {
+            return Ok(None);
+        }
+
+        if with_offset {
+            return Err(DataFusionError::NotImplemented(
+                "UNNEST WITH OFFSET".to_string(),
+            ));
+        }
+
+        let name = match alias {
+            Some(ast::TableAlias { name, columns }) => match columns.as_slice() {
+                [] => self.normalizer.normalize(name.clone()),
+                [column] => self.normalizer.normalize(column.clone()),
+                _ => {
+                    return Err(DataFusionError::Plan(format!(
+                        "UNNEST returns 1 column, but {} column aliases were provided",
+                        columns.len()
+                    )))
+                }
+            },
+            None => "unnest".to_string(),
+        };
+
+        let mut exprs: Vec<_> = input
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| Expr::Column(field.qualified_column()))
+            .collect();
+        exprs.push(expr.alias(name.clone()));
+
+        let plan = LogicalPlanBuilder::from(input.clone())
+            .project(exprs)?
+            .unnest_column_with_options(
+                Column::from_name(name),
+                UnnestOptions::new().with_preserve_nulls(false),
+            )?
+            .build()?;
+
+        Ok(Some(plan))
+    }
+
     /// Get a constant expression literal from a function argument.
     ///
     /// Returns an optional name for the argument.
-    fn get_constant_function_arg(
+    async fn get_constant_function_arg(
         &mut self,
         arg: ast::FunctionArg,
     ) -> Result<(Option<String>, FuncParamValue)> {
         match arg {
             ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Expr(expr)) => {
-                Ok((None, self.get_param_val(expr)?))
+                Ok((None, self.get_param_val(expr).await?))
             }
             ast::FunctionArg::Named {
                 name,
                 arg: ast::FunctionArgExpr::Expr(expr),
             } => {
                 let name = self.normalizer.normalize(name);
-                Ok((Some(name), self.get_param_val(expr)?))
+                Ok((Some(name), self.get_param_val(expr).await?))
             }
             other => Err(DataFusionError::NotImplemented(format!(
                 "Non-constant function argument: {other:?}",
@@ -175,42 +288,45 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
     }
 
     /// Get the parameter value from expr.
-    fn get_param_val(&self, expr: ast::Expr) -> Result<FuncParamValue> {
+    ///
+    /// Expressions that aren't simple literals are planned and constant
+    /// folded, e.g. `'s3://bucket/' || 'table'`.
+    #[async_recursion]
+    async fn get_param_val(&mut self, expr: ast::Expr) -> Result<FuncParamValue> {
         match expr {
             ast::Expr::Identifier(ident) => {
                 Ok(FuncParamValue::Ident(self.normalizer.normalize(ident)))
             }
             ast::Expr::Array(arr) => {
-                let arr = arr
-                    .elem
-                    .iter()
-                    .map(|e| self.get_param_val(e.clone()))
-                    .collect::<Result<Vec<FuncParamValue>>>();
-                Ok(FuncParamValue::Array(arr?))
+                let mut vals = Vec::with_capacity(arr.elem.len());
+                for e in arr.elem {
+                    vals.push(self.get_param_val(e).await?);
+                }
+                Ok(FuncParamValue::Array(vals))
             }
-            ast::Expr::UnaryOp { op, expr } => match op {
-                ast::UnaryOperator::Minus => {
-                    match *expr {
-                        // optimization: if it's a number literal, we apply the negative operator
-                        // here directly to calculate the new literal.
-                        ast::Expr::Value(ast::Value::Number(n, _)) => match n.parse::<i64>() {
-                            Ok(n) => Ok(FuncParamValue::Scalar(ScalarValue::Int64(Some(-n)))),
-                            Err(_) => {
-                                let n = n.parse::<f64>().map_err(|_e| {
-                                    DataFusionError::Internal(format!(
-                                        "negative operator can be only applied to integer and float operands, got: {n}"))
-                                })?;
-                                Ok(FuncParamValue::Scalar(ScalarValue::Float64(Some(-n))))
-                            }
-                        },
-                        other => Err(DataFusionError::NotImplemented(format!(
-                            "Non-constant function argument: {other:?}",
-                        ))),
+            ast::Expr::UnaryOp {
+                op: ast::UnaryOperator::Minus,
+                expr,
+            } => match *expr {
+                // optimization: if it's a number literal, we apply the negative operator
+                // here directly to calculate the new literal.
+                ast::Expr::Value(ast::Value::Number(n, _)) => match n.parse::<i64>() {
+                    Ok(n) => Ok(FuncParamValue::Scalar(ScalarValue::Int64(Some(-n)))),
+                    Err(_) => {
+                        let n = n.parse::<f64>().map_err(|_e| {
+                            DataFusionError::Internal(format!(
+                                "negative operator can be only applied to integer and float operands, got: {n}"))
+                        })?;
+                        Ok(FuncParamValue::Scalar(ScalarValue::Float64(Some(-n))))
                     }
+                },
+                other => {
+                    self.fold_constant_param(ast::Expr::UnaryOp {
+                        op: ast::UnaryOperator::Minus,
+                        expr: Box::new(other),
+                    })
+                    .await
                 }
-                other => Err(DataFusionError::NotImplemented(format!(
-                    "Non-constant function argument: {other:?}",
-                ))),
             },
 
             ast::Expr::Value(v) => match self.parse_value(v, &[]) {
@@ -223,8 +339,33 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                 Err(e) => Err(e),
             },
 
-            other => Err(DataFusionError::NotImplemented(format!(
-                "Non-constant function argument: {other:?}",
+            other => self.fold_constant_param(other).await,
+        }
+    }
+
+    /// Plan an expression and fold it into a single literal value.
+    ///
+    /// Errors if the expression references columns or otherwise can't be
+    /// evaluated during planning.
+    async fn fold_constant_param(&mut self, expr: ast::Expr) -> Result<FuncParamValue> {
+        let schema = DFSchema::empty();
+        let planned = self
+            .sql_to_expr(expr.clone(), &schema, &mut PlannerContext::new())
+            .await
+            .map_err(|e| {
+                DataFusionError::Plan(format!(
+                    "Function argument must be a constant expression: {expr}: {e}"
+                ))
+            })?;
+
+        let props = ExecutionProps::new();
+        let simplifier =
+            ExprSimplifier::new(SimplifyContext::new(&props).with_schema(Arc::new(schema)));
+
+        match simplifier.simplify(planned)? {
+            Expr::Literal(lit) => Ok(FuncParamValue::Scalar(lit)),
+            other => Err(DataFusionError::Plan(format!(
+                "Function argument must be a constant expression: {other}"
             ))),
         }
     }
@@ -17,8 +17,8 @@
 
 use crate::planner::{AsyncContextProvider, SqlQueryPlanner};
 use crate::utils::{
-    check_columns_satisfy_exprs, extract_aliases, rebase_expr, resolve_aliases_to_exprs,
-    resolve_columns, resolve_positions_to_exprs,
+    check_columns_satisfy_exprs, explain_ambiguous_reference, extract_aliases, rebase_expr,
+    resolve_aliases_to_exprs, resolve_columns, resolve_positions_to_exprs,
 };
 use async_recursion::async_recursion;
 use datafusion::common::{plan_err, DataFusionError, Result};
@@ -221,8 +221,10 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
             .distinct
             .map(|distinct| match distinct {
                 Distinct::Distinct => Ok(true),
+                // Top level DISTINCT ON is handled when planning the query
+                // since it relies on the query's ORDER BY.
                 Distinct::On(_) => Err(DataFusionError::NotImplemented(
-                    "DISTINCT ON Exprs not supported".to_string(),
+                    "DISTINCT ON is not supported within set operations".to_string(),
                 )),
             })
             .transpose()?
@@ -277,7 +279,8 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                     filter_expr,
                     &[&[plan.schema()], &fallback_schemas, &outer_query_schema_vec],
                     &[using_columns],
-                )?;
+                )
+                .map_err(|e| explain_ambiguous_reference(e, plan.schema()))?;
 
                 Ok(LogicalPlan::Filter(Filter::try_new(
                     filter_expr,
@@ -303,14 +306,24 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                 let mut from = from.into_iter();
 
                 let left = from.next().unwrap();
-                let left = self.plan_table_with_joins(left, planner_context).await?;
-                let mut left = LogicalPlanBuilder::from(left);
+                let mut left = self.plan_table_with_joins(left, planner_context).await?;
 
                 for right in from {
+                    // SELECT * FROM t, unnest(t.arr)
+                    if right.joins.is_empty() {
+                        if let Some(plan) = self
+                            .plan_lateral_unnest(&left, &right.relation, planner_context)
+                            .await?
+                        {
+                            left = plan;
+                            continue;
+                        }
+                    }
+
                     let right = self.plan_table_with_joins(right, planner_context).await?;
-                    left = left.cross_join(right)?;
+                    left = LogicalPlanBuilder::from(left).cross_join(right)?.build()?;
                 }
-                Ok(left.build()?)
+                Ok(left)
             }
         }
     }
@@ -355,7 +368,8 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                     expr,
                     &[&[plan.schema()]],
                     &plan.using_columns()?,
-                )?;
+                )
+                .map_err(|e| explain_ambiguous_reference(e, plan.schema()))?;
                 Ok(vec![col])
             }
             SelectItem::ExprWithAlias { expr, alias } => {
@@ -366,7 +380,8 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
                     select_expr,
                     &[&[plan.schema()]],
                     &plan.using_columns()?,
-                )?;
+                )
+                .map_err(|e| explain_ambiguous_reference(e, plan.schema()))?;
                 let expr = Expr::Alias(Alias::new(col, self.normalizer.normalize(alias)));
                 Ok(vec![expr])
             }
@@ -55,6 +55,15 @@ impl<'a, S: AsyncContextProvider> SqlQueryPlanner<'a, S> {
 
                 let left_plan = self.set_expr_to_plan(*left, planner_context).await?;
                 let right_plan = self.set_expr_to_plan(*right, planner_context).await?;
+
+                let left_cols = left_plan.schema().fields().len();
+                let right_cols = right_plan.schema().fields().len();
+                if left_cols != right_cols {
+                    return Err(DataFusionError::Plan(format!(
+                        "{op} branches have {left_cols} vs {right_cols} columns"
+                    )));
+                }
+
                 match (op, all) {
                     (SetOperator::Union, true) => LogicalPlanBuilder::from(left_plan)
                         .union(right_plan)?
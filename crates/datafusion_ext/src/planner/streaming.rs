@@ -0,0 +1,105 @@
+//! Classify logical plans by whether they can stream results.
+use datafusion::logical_expr::LogicalPlan;
+
+/// Check if a plan can start producing rows before its full result has been
+/// computed.
+///
+/// A plan is streamable if there's no blocking operator (sort, aggregate,
+/// window, distinct) between the output and the sources. Joins and unions are
+/// streamable if all of their inputs are. Anything that isn't a query (DDL,
+/// EXPLAIN, extension nodes) is conservatively considered not streamable.
+pub fn is_streamable(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Sort(_)
+        | LogicalPlan::Aggregate(_)
+        | LogicalPlan::Window(_)
+        | LogicalPlan::Distinct(_) => false,
+
+        LogicalPlan::Projection(_)
+        | LogicalPlan::Filter(_)
+        | LogicalPlan::Limit(_)
+        | LogicalPlan::SubqueryAlias(_)
+        | LogicalPlan::Repartition(_)
+        | LogicalPlan::Unnest(_)
+        | LogicalPlan::Union(_)
+        | LogicalPlan::Join(_)
+        | LogicalPlan::CrossJoin(_) => plan.inputs().into_iter().all(is_streamable),
+
+        LogicalPlan::TableScan(_) | LogicalPlan::Values(_) | LogicalPlan::EmptyRelation(_) => true,
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::logical_expr::{col, lit, LogicalPlanBuilder};
+
+    fn values() -> LogicalPlanBuilder {
+        LogicalPlanBuilder::values(vec![vec![lit(1), lit("a")], vec![lit(2), lit("b")]]).unwrap()
+    }
+
+    #[test]
+    fn plain_select_is_streamable() {
+        let plan = values()
+            .filter(col("column1").gt(lit(1)))
+            .unwrap()
+            .project(vec![col("column2")])
+            .unwrap()
+            .limit(0, Some(10))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(is_streamable(&plan));
+    }
+
+    #[test]
+    fn order_by_is_not_streamable() {
+        let plan = values()
+            .sort(vec![col("column1").sort(true, false)])
+            .unwrap()
+            .project(vec![col("column2")])
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(!is_streamable(&plan));
+    }
+
+    #[test]
+    fn aggregate_is_not_streamable() {
+        let plan = values()
+            .aggregate(
+                vec![col("column2")],
+                Vec::<datafusion::logical_expr::Expr>::new(),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(!is_streamable(&plan));
+    }
+
+    #[test]
+    fn union_requires_all_inputs() {
+        let streaming = values().build().unwrap();
+        let sorted = values()
+            .sort(vec![col("column1").sort(true, false)])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let plan = LogicalPlanBuilder::from(streaming.clone())
+            .union(streaming.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(is_streamable(&plan));
+
+        let plan = LogicalPlanBuilder::from(streaming)
+            .union(sorted)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(!is_streamable(&plan));
+    }
+}
@@ -20,12 +20,42 @@
 use datafusion::arrow::datatypes::{DataType, DECIMAL128_MAX_PRECISION, DECIMAL_DEFAULT_SCALE};
 
 use datafusion::common::tree_node::{Transformed, TreeNode};
-use datafusion::common::{DataFusionError, Result, ScalarValue};
+use datafusion::common::{DFSchema, DataFusionError, Result, ScalarValue, SchemaError};
 use datafusion::logical_expr::expr::{GroupingSet, WindowFunction};
 use datafusion::logical_expr::utils::{expr_as_column_expr, find_column_exprs};
 use datafusion::logical_expr::{expr::Alias, Expr, LogicalPlan};
 use std::collections::HashMap;
 
+/// Replace an ambiguous column reference error with one listing the columns
+/// the reference could refer to.
+///
+/// Other errors are returned unchanged.
+pub(crate) fn explain_ambiguous_reference(
+    err: DataFusionError,
+    schema: &DFSchema,
+) -> DataFusionError {
+    match err {
+        DataFusionError::SchemaError(SchemaError::AmbiguousReference { field })
+            if field.relation.is_none() =>
+        {
+            let candidates: Vec<_> = schema
+                .fields_with_unqualified_name(&field.name)
+                .into_iter()
+                .map(|f| f.qualified_name())
+                .collect();
+            if candidates.len() < 2 {
+                return DataFusionError::SchemaError(SchemaError::AmbiguousReference { field });
+            }
+            DataFusionError::Plan(format!(
+                "column '{}' is ambiguous; candidates: {}",
+                field.name,
+                candidates.join(", ")
+            ))
+        }
+        other => other,
+    }
+}
+
 /// Make a best-effort attempt at resolving all columns in the expression tree
 pub(crate) fn resolve_columns(expr: &Expr, plan: &LogicalPlan) -> Result<Expr> {
     expr.clone().transform_up(&|nested_expr| {
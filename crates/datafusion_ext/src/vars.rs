@@ -92,7 +92,10 @@ impl SessionVars {
      memory_limit_bytes: Option<usize>,
      max_tunnel_count: Option<usize>,
      max_credentials_count: Option<usize>,
+     max_result_rows: Option<usize>,
      is_cloud_instance: bool,
+     read_only: bool,
+     allow_ambient_credentials: bool,
      dialect: Dialect
     }
 }
@@ -203,9 +206,18 @@ impl SessionVars {
     pub fn with_max_credentials_count(self, value: usize, setter: VarType) -> Self {
         with_property!(self, max_credentials_count, setter, Some(value))
     }
+    pub fn with_max_result_rows(self, value: usize, setter: VarType) -> Self {
+        with_property!(self, max_result_rows, setter, Some(value))
+    }
     pub fn with_is_cloud_instance(self, value: bool, setter: VarType) -> Self {
         with_property!(self, is_cloud_instance, setter, value)
     }
+    pub fn with_read_only(self, value: bool, setter: VarType) -> Self {
+        with_property!(self, read_only, setter, value)
+    }
+    pub fn with_allow_ambient_credentials(self, value: bool, setter: VarType) -> Self {
+        with_property!(self, allow_ambient_credentials, setter, value)
+    }
 }
 
 impl ConfigExtension for SessionVars {
@@ -180,6 +180,14 @@ pub(super) const MAX_CREDENTIALS_COUNT: ServerVar<Option<usize>> = ServerVar {
     description: "Max credentials allowed",
 };
 
+pub(super) const MAX_RESULT_ROWS: ServerVar<Option<usize>> = ServerVar {
+    name: "max_result_rows",
+    value: &None,
+    group: "glaredb",
+    user_configurable: false,
+    description: "Max number of rows a single query may return",
+};
+
 pub(super) const IS_CLOUD_INSTANCE: ServerVar<bool> = ServerVar {
     name: "is_cloud_instance",
     value: &false,
@@ -188,6 +196,22 @@ pub(super) const IS_CLOUD_INSTANCE: ServerVar<bool> = ServerVar {
     description: "Determines if the server is local or cloud",
 };
 
+pub(super) const READ_ONLY: ServerVar<bool> = ServerVar {
+    name: "read_only",
+    value: &false,
+    group: "glaredb",
+    user_configurable: false,
+    description: "Reject statements that modify the database",
+};
+
+pub(super) const ALLOW_AMBIENT_CREDENTIALS: ServerVar<bool> = ServerVar {
+    name: "allow_ambient_credentials",
+    value: &false,
+    group: "glaredb",
+    user_configurable: false,
+    description: "Allow table functions to use the server's own cloud credentials",
+};
+
 pub(super) const DIALECT: ServerVar<Dialect> = ServerVar {
     name: "dialect",
     value: &Dialect::Sql,
@@ -45,7 +45,10 @@ pub struct SessionVarsInner {
     pub memory_limit_bytes: SessionVar<Option<usize>>,
     pub max_tunnel_count: SessionVar<Option<usize>>,
     pub max_credentials_count: SessionVar<Option<usize>>,
+    pub max_result_rows: SessionVar<Option<usize>>,
     pub is_cloud_instance: SessionVar<bool>,
+    pub read_only: SessionVar<bool>,
+    pub allow_ambient_credentials: SessionVar<bool>,
     pub dialect: SessionVar<Dialect>,
 }
 
@@ -107,8 +110,14 @@ impl SessionVarsInner {
             Ok(&self.max_tunnel_count)
         } else if name.eq_ignore_ascii_case(MAX_CREDENTIALS_COUNT.name) {
             Ok(&self.max_credentials_count)
+        } else if name.eq_ignore_ascii_case(MAX_RESULT_ROWS.name) {
+            Ok(&self.max_result_rows)
         } else if name.eq_ignore_ascii_case(IS_CLOUD_INSTANCE.name) {
             Ok(&self.is_cloud_instance)
+        } else if name.eq_ignore_ascii_case(READ_ONLY.name) {
+            Ok(&self.read_only)
+        } else if name.eq_ignore_ascii_case(ALLOW_AMBIENT_CREDENTIALS.name) {
+            Ok(&self.allow_ambient_credentials)
         } else if name.eq_ignore_ascii_case(DIALECT.name) {
             Ok(&self.dialect)
         } else {
@@ -162,6 +171,8 @@ impl SessionVarsInner {
             self.max_tunnel_count.set_from_str(val, setter)
         } else if name.eq_ignore_ascii_case(MAX_CREDENTIALS_COUNT.name) {
             self.max_credentials_count.set_from_str(val, setter)
+        } else if name.eq_ignore_ascii_case(MAX_RESULT_ROWS.name) {
+            self.max_result_rows.set_from_str(val, setter)
         } else if name.eq_ignore_ascii_case(DIALECT.name) {
             self.dialect.set_from_str(val, setter)
         } else {
@@ -192,7 +203,10 @@ impl SessionVarsInner {
             self.memory_limit_bytes.config_entry(),
             self.max_tunnel_count.config_entry(),
             self.max_credentials_count.config_entry(),
+            self.max_result_rows.config_entry(),
             self.is_cloud_instance.config_entry(),
+            self.read_only.config_entry(),
+            self.allow_ambient_credentials.config_entry(),
             self.dialect.config_entry(),
         ]
     }
@@ -222,7 +236,10 @@ impl Default for SessionVarsInner {
             memory_limit_bytes: SessionVar::new(&MEMORY_LIMIT_BYTES),
             max_tunnel_count: SessionVar::new(&MAX_TUNNEL_COUNT),
             max_credentials_count: SessionVar::new(&MAX_CREDENTIALS_COUNT),
+            max_result_rows: SessionVar::new(&MAX_RESULT_ROWS),
             is_cloud_instance: SessionVar::new(&IS_CLOUD_INSTANCE),
+            read_only: SessionVar::new(&READ_ONLY),
+            allow_ambient_credentials: SessionVar::new(&ALLOW_AMBIENT_CREDENTIALS),
             dialect: SessionVar::new(&DIALECT),
         }
     }
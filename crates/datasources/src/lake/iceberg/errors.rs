@@ -21,6 +21,9 @@ pub enum IcebergError {
     #[error(transparent)]
     SerdeJson(#[from] serde_json::Error),
 
+    #[error("Version {0} of the table was committed concurrently")]
+    CommitConflict(i32),
+
     #[error("{0}")]
     Static(&'static str),
 }
@@ -1,4 +1,4 @@
 pub mod errors;
 pub mod table;
 
-mod spec;
+pub mod spec;
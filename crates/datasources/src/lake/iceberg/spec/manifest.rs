@@ -220,6 +220,9 @@ pub struct ManifestEntry {
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataFile {
+    /// `0`: data
+    /// `1`: position deletes
+    /// `2`: equality deletes
     pub content: i32,
     pub file_path: String,
     pub file_format: String,
@@ -252,3 +255,44 @@ pub struct I64Entry {
     key: i32,
     value: i64,
 }
+
+impl DataFile {
+    /// Get the type of content stored in this file.
+    pub fn content_type(&self) -> Result<DataFileContent> {
+        self.content.try_into()
+    }
+}
+
+/// The type of content stored in a data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+impl TryFrom<i32> for DataFileContent {
+    type Error = IcebergError;
+    fn try_from(value: i32) -> Result<Self> {
+        Ok(match value {
+            0 => DataFileContent::Data,
+            1 => DataFileContent::PositionDeletes,
+            2 => DataFileContent::EqualityDeletes,
+            other => {
+                return Err(IcebergError::DataInvalid(format!(
+                    "'{other}' is not valid content for data file"
+                )))
+            }
+        })
+    }
+}
+
+impl fmt::Display for DataFileContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataFileContent::Data => write!(f, "data"),
+            DataFileContent::PositionDeletes => write!(f, "position_deletes"),
+            DataFileContent::EqualityDeletes => write!(f, "equality_deletes"),
+        }
+    }
+}
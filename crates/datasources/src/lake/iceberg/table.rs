@@ -1,4 +1,6 @@
-use super::spec::{Manifest, ManifestContent, ManifestList, Snapshot, TableMetadata};
+use super::spec::{
+    Manifest, ManifestContent, ManifestList, Schema, Snapshot, StructField, TableMetadata,
+};
 
 use crate::common::url::DatasourceUrl;
 use crate::lake::iceberg::errors::{IcebergError, Result};
@@ -15,15 +17,25 @@ use datafusion::execution::context::SessionState;
 use datafusion::execution::context::TaskContext;
 use datafusion::execution::object_store::ObjectStoreUrl;
 use datafusion::logical_expr::{Expr, TableProviderFilterPushDown, TableType};
-use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::parquet::arrow::async_reader::ParquetObjectReader;
+use datafusion::parquet::arrow::{ParquetRecordBatchStreamBuilder, PARQUET_FIELD_ID_META_KEY};
+use datafusion::physical_expr::expressions::Column;
+use datafusion::physical_expr::{PhysicalExpr, PhysicalSortExpr};
+use datafusion::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::union::UnionExec;
 use datafusion::physical_plan::{
     DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
     Statistics,
 };
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use object_store::{path::Path as ObjectPath, ObjectMeta, ObjectStore};
 use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::io::Cursor;
 use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct IcebergTable {
@@ -41,11 +53,33 @@ impl IcebergTable {
         Ok(IcebergTable { state })
     }
 
+    /// Open a table using an exact metadata file, skipping discovery of the
+    /// latest table version.
+    ///
+    /// The metadata location may be relative to the table's root, or a full
+    /// path that begins with the table's location.
+    pub async fn open_with_metadata_location(
+        location: DatasourceUrl,
+        store: Arc<dyn ObjectStore>,
+        metadata_location: &str,
+    ) -> Result<IcebergTable> {
+        let relative = relative_to_root(&location.to_string(), metadata_location);
+        let path = format_object_path(&location, relative)?;
+        let state = TableState::open_metadata(location, store, path).await?;
+
+        Ok(IcebergTable { state })
+    }
+
     /// Get the table metadata.
     pub fn metadata(&self) -> &TableMetadata {
         &self.state.metadata
     }
 
+    /// Get the current snapshot according to the loaded table metadata.
+    pub fn current_snapshot(&self) -> Result<&Snapshot> {
+        self.state.current_snapshot()
+    }
+
     /// Read all manifests for the current snapshot according to the currently
     /// loaded table metadata.
     pub async fn read_manifests(&self) -> Result<Vec<Manifest>> {
@@ -53,21 +87,347 @@ impl IcebergTable {
         Ok(manifests)
     }
 
+    /// Stream the manifests for the current snapshot.
+    ///
+    /// Unlike `read_manifests`, each manifest is only read once the stream is
+    /// polled for it, so callers can process manifests one at a time without
+    /// holding all of them in memory.
+    pub fn stream_manifests(&self) -> BoxStream<'static, Result<Manifest>> {
+        let state = self.state.clone();
+        stream::once(async move {
+            let list = state.read_manifest_list().await?;
+            let manifests = stream::iter(list.entries).then(move |ent| {
+                let state = state.clone();
+                async move { state.read_manifest(&ent.manifest_path).await }
+            });
+            Ok::<_, IcebergError>(manifests)
+        })
+        .try_flatten()
+        .boxed()
+    }
+
     /// Get the table's arrow schema.
     pub fn table_arrow_schema(&self) -> Result<ArrowSchema> {
         self.state.table_arrow_schema()
     }
 
+    /// Check the integrity of the current snapshot.
+    ///
+    /// Verifies that every file referenced by the snapshot's manifests exists
+    /// in the object store, and that the record counts of the data files add
+    /// up to the total in the snapshot summary.
+    ///
+    /// All problems found are returned instead of stopping at the first one.
+    pub async fn validate(&self) -> Result<Vec<ValidationProblem>> {
+        let snapshot = self.state.current_snapshot()?;
+        let manifests = self.state.read_manifests().await?;
+
+        let mut problems = Vec::new();
+        let mut total_records: i64 = 0;
+
+        for manifest in &manifests {
+            let is_data = matches!(manifest.metadata.content, ManifestContent::Data);
+
+            // Deleted entries are kept for history, and the file they point
+            // to may have been removed already.
+            for entry in manifest
+                .entries
+                .iter()
+                .filter(|ent| ent.status != MANIFEST_ENTRY_STATUS_DELETED)
+            {
+                if is_data {
+                    total_records += entry.data_file.record_count;
+                }
+
+                let path = self
+                    .state
+                    .resolver
+                    .relative_path(&entry.data_file.file_path);
+                let path = format_object_path(&self.state.location, path)?;
+                match self.state.store.head(&path).await {
+                    Ok(_) => (),
+                    Err(object_store::Error::NotFound { .. }) => {
+                        problems.push(ValidationProblem::MissingFile {
+                            path: entry.data_file.file_path.clone(),
+                        })
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
+
+        if let Some(expected) = snapshot.summary.get("total-records") {
+            let expected = expected.parse::<i64>().map_err(|e| {
+                IcebergError::DataInvalid(format!(
+                    "Invalid value for 'total-records' in snapshot summary: {e}"
+                ))
+            })?;
+            if expected != total_records {
+                problems.push(ValidationProblem::RecordCountMismatch {
+                    expected,
+                    actual: total_records,
+                });
+            }
+        }
+
+        Ok(problems)
+    }
+
+    /// Remove old snapshots from the table, deleting files that are no longer
+    /// referenced by any remaining snapshot.
+    ///
+    /// Always works against the latest version of the table. A snapshot is
+    /// kept if it's the current snapshot, is referenced by a branch or tag,
+    /// is one of the `retain_last` most recent snapshots, or was created at or
+    /// after `older_than`.
+    ///
+    /// A new metadata file is written without the expired snapshots before
+    /// any files are deleted. The new version is only created if it doesn't
+    /// already exist, so if another writer commits to the table concurrently,
+    /// this fails with a conflict without deleting anything.
+    pub async fn expire_snapshots(&self, opts: ExpireSnapshotsOptions) -> Result<ExpiredSnapshots> {
+        let location = &self.state.location;
+        let store = &self.state.store;
+
+        let version = read_version_hint(location, store.as_ref()).await?;
+        let metadata_path = format!("metadata/v{version}.metadata.json");
+        let bs = store
+            .get(&format_object_path(location, &metadata_path)?)
+            .await?
+            .bytes()
+            .await?;
+
+        // The metadata is edited as json so that fields we don't model are
+        // carried over to the new version untouched.
+        let mut raw: serde_json::Value = serde_json::from_slice(&bs)?;
+        let metadata: TableMetadata = serde_json::from_value(raw.clone())?;
+        let state = TableState {
+            location: location.clone(),
+            store: store.clone(),
+            resolver: PathResolver::from_metadata(&metadata),
+            metadata,
+        };
+
+        let mut keep: HashSet<i64> = HashSet::new();
+        keep.extend(state.metadata.current_snapshot_id);
+        if let Some(refs) = raw["refs"].as_object() {
+            keep.extend(refs.values().filter_map(|r| r["snapshot-id"].as_i64()));
+        }
+
+        let mut snapshots: Vec<&Snapshot> = state.metadata.snapshots.iter().collect();
+        snapshots.sort_by_key(|s| std::cmp::Reverse(s.timestamp_ms));
+        let older_than = opts.older_than.timestamp_millis();
+        for (idx, snapshot) in snapshots.iter().enumerate() {
+            if idx < opts.retain_last || snapshot.timestamp_ms >= older_than {
+                keep.insert(snapshot.snapshot_id);
+            }
+        }
+
+        let (retained, expired): (Vec<&Snapshot>, Vec<&Snapshot>) = snapshots
+            .into_iter()
+            .partition(|s| keep.contains(&s.snapshot_id));
+        if expired.is_empty() {
+            return Ok(ExpiredSnapshots::default());
+        }
+
+        let mut live = HashSet::new();
+        for snapshot in retained {
+            live.extend(state.snapshot_files(snapshot).await?);
+        }
+        let mut unreferenced = BTreeSet::new();
+        for snapshot in &expired {
+            unreferenced.extend(
+                state
+                    .snapshot_files(snapshot)
+                    .await?
+                    .into_iter()
+                    .filter(|f| !live.contains(f)),
+            );
+        }
+
+        let now = Utc::now().timestamp_millis();
+        let prev_updated = raw["last-updated-ms"].clone();
+        for key in ["snapshots", "snapshot-log"] {
+            if let Some(entries) = raw[key].as_array_mut() {
+                entries.retain(|ent| {
+                    ent["snapshot-id"]
+                        .as_i64()
+                        .map_or(true, |id| keep.contains(&id))
+                });
+            }
+        }
+        raw["last-updated-ms"] = now.into();
+        if let Some(log) = raw["metadata-log"].as_array_mut() {
+            log.push(serde_json::json!({
+                "timestamp-ms": prev_updated,
+                "metadata-file": format!(
+                    "{}/{metadata_path}",
+                    state.metadata.location.trim_end_matches('/')
+                ),
+            }));
+        }
+
+        // Local paths are canonicalized when formatted, so the new metadata
+        // file's path is built from the existing directory.
+        let next = version + 1;
+        let metadata_dir = format_object_path(location, "metadata")?;
+
+        // Commit by creating the next metadata version only if it doesn't
+        // exist yet. If a concurrent commit created it first, abort before
+        // deleting anything since its snapshots may reference the files.
+        let staged = metadata_dir.child(format!(".v{next}.metadata.json.{}", Uuid::new_v4()));
+        store.put(&staged, serde_json::to_vec(&raw)?.into()).await?;
+        let committed = store
+            .copy_if_not_exists(
+                &staged,
+                &metadata_dir.child(format!("v{next}.metadata.json")),
+            )
+            .await;
+        if let Err(e) = store.delete(&staged).await {
+            warn!(%e, %staged, "failed to delete staged iceberg metadata");
+        }
+        match committed {
+            Ok(()) => (),
+            Err(object_store::Error::AlreadyExists { .. }) => {
+                return Err(IcebergError::CommitConflict(next))
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        // The version hint only points readers at the latest version, so it's
+        // overwritten rather than created. Avoid moving it back if another
+        // commit has already advanced it past ours.
+        if read_version_hint(location, store.as_ref()).await? < next {
+            store
+                .put(
+                    &metadata_dir.child("version-hint.text"),
+                    next.to_string().into(),
+                )
+                .await?;
+        }
+
+        for file in &unreferenced {
+            match store.delete(&format_object_path(location, file)?).await {
+                Ok(_) | Err(object_store::Error::NotFound { .. }) => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(ExpiredSnapshots {
+            snapshot_ids: expired.iter().map(|s| s.snapshot_id).collect(),
+            deleted_files: unreferenced.into_iter().collect(),
+        })
+    }
+
     pub async fn table_reader(&self) -> Result<Arc<dyn TableProvider>> {
-        let schema = self.table_arrow_schema()?;
+        self.table_reader_with_options(IcebergReadOptions::default())
+            .await
+    }
+
+    /// Create a table reader using the provided read options.
+    pub async fn table_reader_with_options(
+        &self,
+        opts: IcebergReadOptions,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let read_schema = self.state.read_schema(opts.merge_schemas)?;
+        let schema = read_schema.to_arrow_schema()?;
 
         Ok(Arc::new(IcebergTableReader {
             schema: Arc::new(schema),
+            read_schema,
             state: self.state.clone(),
+            max_concurrency: opts.max_concurrency.max(1),
         }))
     }
 }
 
+/// Options for reading data from an iceberg table.
+#[derive(Debug, Clone, Copy)]
+pub struct IcebergReadOptions {
+    /// Max number of data files read at once.
+    pub max_concurrency: usize,
+    /// Include fields that have been dropped from the table, reading them as
+    /// NULL for data files written after the drop.
+    pub merge_schemas: bool,
+}
+
+impl Default for IcebergReadOptions {
+    fn default() -> Self {
+        IcebergReadOptions {
+            max_concurrency: default_max_concurrency(),
+            merge_schemas: false,
+        }
+    }
+}
+
+/// Default number of data files read concurrently during a scan.
+pub fn default_max_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Options for expiring snapshots of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct ExpireSnapshotsOptions {
+    /// Snapshots created before this time may be expired.
+    pub older_than: DateTime<Utc>,
+    /// Number of most recent snapshots to keep regardless of age.
+    pub retain_last: usize,
+}
+
+/// Snapshots and files removed when expiring snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpiredSnapshots {
+    /// Ids of the expired snapshots, newest first.
+    pub snapshot_ids: Vec<i64>,
+    /// Deleted files, relative to the table's root.
+    pub deleted_files: Vec<String>,
+}
+
+/// Status of a manifest entry for a file that's been removed from the table.
+const MANIFEST_ENTRY_STATUS_DELETED: i32 = 2;
+
+/// A problem found when validating a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationProblem {
+    /// A file referenced by a manifest doesn't exist.
+    MissingFile { path: String },
+    /// Record counts of the data files don't add up to the total in the
+    /// snapshot summary.
+    RecordCountMismatch { expected: i64, actual: i64 },
+}
+
+impl ValidationProblem {
+    /// Short name for the kind of problem.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MissingFile { .. } => "missing_file",
+            Self::RecordCountMismatch { .. } => "record_count_mismatch",
+        }
+    }
+
+    /// Path of the file the problem relates to, if any.
+    pub fn file_path(&self) -> Option<&str> {
+        match self {
+            Self::MissingFile { path } => Some(path),
+            Self::RecordCountMismatch { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingFile { path } => write!(f, "File does not exist: {path}"),
+            Self::RecordCountMismatch { expected, actual } => write!(
+                f,
+                "Snapshot summary has {expected} total records, data files have {actual}"
+            ),
+        }
+    }
+}
+
 /// Information about the state of the table at some table version.
 #[derive(Debug, Clone)]
 struct TableState {
@@ -87,24 +447,19 @@ struct TableState {
 
 impl TableState {
     async fn open(location: DatasourceUrl, store: Arc<dyn ObjectStore>) -> Result<TableState> {
-        // Get table version.
-        // TODO: Handle not finding a version hint.
-        let version = {
-            let path = format_object_path(&location, "metadata/version-hint.text")?;
-            let path = ObjectPath::parse(path)?;
-            let bs = store.get(&path).await?.bytes().await?;
-            let s = String::from_utf8(bs.to_vec()).map_err(|e| {
-                IcebergError::DataInvalid(format!("Expected utf-8 in version hint: {}", e))
-            })?;
-
-            s.parse::<i32>().map_err(|e| {
-                IcebergError::DataInvalid(format!("Expected version hint to be a number: {}", e))
-            })?
-        };
+        let version = read_version_hint(&location, store.as_ref()).await?;
+        let path = format_object_path(&location, format!("metadata/v{version}.metadata.json"))?;
+        Self::open_metadata(location, store, path).await
+    }
 
+    /// Open the table using the metadata file at the given path.
+    async fn open_metadata(
+        location: DatasourceUrl,
+        store: Arc<dyn ObjectStore>,
+        path: ObjectPath,
+    ) -> Result<TableState> {
         // Read metadata.
         let metadata = {
-            let path = format_object_path(&location, format!("metadata/v{version}.metadata.json"))?;
             let bs = store.get(&path).await?.bytes().await?;
             let metadata: TableMetadata = serde_json::from_slice(&bs).map_err(|e| {
                 IcebergError::DataInvalid(format!("Failed to read table metadata: {}", e))
@@ -144,7 +499,7 @@ impl TableState {
         Ok(current_snapshot)
     }
 
-    fn table_arrow_schema(&self) -> Result<ArrowSchema> {
+    fn current_schema(&self) -> Result<&Schema> {
         // v1: Read `schema`
         //
         // v2: Read `current-schema-id`, then find that correct schema in
@@ -156,8 +511,7 @@ impl TableState {
             ));
         }
 
-        let schema = self
-            .metadata
+        self.metadata
             .schemas
             .iter()
             .find(|s| s.schema_id == self.metadata.current_schema_id)
@@ -166,9 +520,42 @@ impl TableState {
                     "Missing schema for id: {}",
                     self.metadata.current_schema_id
                 ))
-            })?;
+            })
+    }
 
-        schema.to_arrow_schema()
+    fn table_arrow_schema(&self) -> Result<ArrowSchema> {
+        self.current_schema()?.to_arrow_schema()
+    }
+
+    /// Get the schema to use when reading the table.
+    ///
+    /// This is the current schema. If `merge` is set, fields from older
+    /// schemas that have since been dropped are appended as nullable fields,
+    /// unless a current field has taken their name.
+    fn read_schema(&self, merge: bool) -> Result<Schema> {
+        let mut schema = self.current_schema()?.clone();
+        if !merge {
+            return Ok(schema);
+        }
+
+        // Newest schemas first so dropped fields use their latest name.
+        let mut older: Vec<_> = self.metadata.schemas.iter().collect();
+        older.sort_by_key(|s| std::cmp::Reverse(s.schema_id));
+
+        for field in older.into_iter().flat_map(|s| s.fields.iter()) {
+            let exists = schema
+                .fields
+                .iter()
+                .any(|f| f.id == field.id || f.name == field.name);
+            if !exists {
+                schema.fields.push(StructField {
+                    required: false,
+                    ..field.clone()
+                });
+            }
+        }
+
+        Ok(schema)
     }
 
     async fn read_manifests(&self) -> Result<Vec<Manifest>> {
@@ -176,23 +563,30 @@ impl TableState {
 
         let mut manifests = Vec::new();
         for ent in list.entries {
-            let manifest_path = self.resolver.relative_path(&ent.manifest_path);
-
-            let path = format_object_path(&self.location, manifest_path)?;
-            let bs = self.store.get(&path).await?.bytes().await?;
-
-            let cursor = Cursor::new(bs);
-
-            let manifest = Manifest::from_raw_avro(cursor)?;
+            let manifest = self.read_manifest(&ent.manifest_path).await?;
             manifests.push(manifest);
         }
 
         Ok(manifests)
     }
 
+    async fn read_manifest(&self, manifest_path: &str) -> Result<Manifest> {
+        let manifest_path = self.resolver.relative_path(manifest_path);
+
+        let path = format_object_path(&self.location, manifest_path)?;
+        let bs = self.store.get(&path).await?.bytes().await?;
+
+        let cursor = Cursor::new(bs);
+        Manifest::from_raw_avro(cursor)
+    }
+
     async fn read_manifest_list(&self) -> Result<ManifestList> {
-        let current_snapshot = self.current_snapshot()?;
-        let manifest_list_path = self.resolver.relative_path(&current_snapshot.manifest_list);
+        self.read_snapshot_manifest_list(self.current_snapshot()?)
+            .await
+    }
+
+    async fn read_snapshot_manifest_list(&self, snapshot: &Snapshot) -> Result<ManifestList> {
+        let manifest_list_path = self.resolver.relative_path(&snapshot.manifest_list);
 
         let path = format_object_path(&self.location, manifest_list_path)?;
         let bs = self.store.get(&path).await?.bytes().await?;
@@ -202,6 +596,57 @@ impl TableState {
 
         Ok(list)
     }
+
+    /// Get the paths of all files reachable from a snapshot, relative to the
+    /// table's root.
+    ///
+    /// This includes the snapshot's manifest list, the manifests, and every
+    /// file referenced by those manifests.
+    async fn snapshot_files(&self, snapshot: &Snapshot) -> Result<Vec<String>> {
+        let list = self.read_snapshot_manifest_list(snapshot).await?;
+
+        let mut files = vec![self
+            .resolver
+            .relative_path(&snapshot.manifest_list)
+            .to_string()];
+        for ent in list.entries {
+            let manifest = self.read_manifest(&ent.manifest_path).await?;
+            files.push(self.resolver.relative_path(&ent.manifest_path).to_string());
+            files.extend(manifest.entries.iter().map(|ent| {
+                self.resolver
+                    .relative_path(&ent.data_file.file_path)
+                    .to_string()
+            }));
+        }
+
+        Ok(files)
+    }
+}
+
+/// Read the latest table version from the table's version hint.
+async fn read_version_hint(location: &DatasourceUrl, store: &dyn ObjectStore) -> Result<i32> {
+    // TODO: Handle not finding a version hint.
+    let path = format_object_path(location, "metadata/version-hint.text")?;
+    let bs = store.get(&path).await?.bytes().await?;
+    let s = String::from_utf8(bs.to_vec())
+        .map_err(|e| IcebergError::DataInvalid(format!("Expected utf-8 in version hint: {}", e)))?;
+
+    s.parse::<i32>().map_err(|e| {
+        IcebergError::DataInvalid(format!("Expected version hint to be a number: {}", e))
+    })
+}
+
+/// Get a path relative to a table's root.
+///
+/// Both are treated as directories when comparing, so a sibling table whose
+/// location shares a prefix with the root (e.g. `t` and `t2`) doesn't match.
+/// Paths not under the root are returned as is.
+fn relative_to_root<'a>(root: &str, path: &'a str) -> &'a str {
+    let root = format!("{}/", root.trim_end_matches('/'));
+    match path.strip_prefix(&root) {
+        Some(relative) => relative.trim_start_matches('/'),
+        None => path.trim_start_matches("./"),
+    }
 }
 
 /// Helper for resolving paths for files.
@@ -243,8 +688,13 @@ impl PathResolver {
 
 #[derive(Debug)]
 pub struct IcebergTableReader {
+    /// Arrow schema of `read_schema`.
     schema: Arc<ArrowSchema>,
+    /// Iceberg schema the table is read with.
+    read_schema: Schema,
     state: TableState,
+    /// Max number of data files read at once.
+    max_concurrency: usize,
 }
 
 #[async_trait]
@@ -272,7 +722,7 @@ impl TableProvider for IcebergTableReader {
         &self,
         ctx: &SessionState,
         projection: Option<&Vec<usize>>,
-        _filters: &[Expr],
+        filters: &[Expr],
         limit: Option<usize>,
     ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
         // Create the datafusion specific url, and register the object store.
@@ -282,10 +732,8 @@ impl TableProvider for IcebergTableReader {
             .register_store(object_url.as_ref(), self.state.store.clone());
 
         // TODO: Properly prune based on partition values. This currently skips
-        // any partition processing, and shoves everything into a single file
-        // group when passing to the parquet exec.
-        //
-        // We also miss out on parallel reading by using a single file group.
+        // any partition processing, and distributes data files across file
+        // groups without regard to partitioning.
 
         // TODO: Properly handle row-level deletes. Currently files containing
         // delete information are ignored.
@@ -295,61 +743,249 @@ impl TableProvider for IcebergTableReader {
 
         // TODO: Collect statistics and pass to exec.
 
-        let manifests = self
+        let mut manifests = self
             .state
             .read_manifests()
             .await
             .map_err(|e| DataFusionError::External(Box::new(e)))?;
 
+        // Entries for deleted files are only kept for history. They must not
+        // be read, or count towards the rows planned for a limit.
+        for manifest in &mut manifests {
+            manifest
+                .entries
+                .retain(|ent| ent.status != MANIFEST_ENTRY_STATUS_DELETED);
+        }
+
+        let manifests_read = manifests.len();
+        let data_files_considered: usize = manifests.iter().map(|m| m.entries.len()).sum();
+
         // Get only data files with "data" content.
         //
         // TODO: Handle "delete" content and also pull out partition
         // information.
-        let data_files: Vec<_> = manifests
+        //
+        // With a limit, only enough files to produce that many rows are
+        // planned. Limits aren't pushed below filters, but the limit is
+        // ignored if any are provided just in case.
+        let row_limit = limit.filter(|_| filters.is_empty());
+        let mut rows_planned = 0;
+        let limit_reached = |rows: usize| row_limit.is_some_and(|limit| rows >= limit);
+
+        // Files are grouped by the field ids of the schema of the manifest
+        // listing them, which is the schema the files were written with. Only
+        // files whose manifest schema has no fields need their footers read.
+        let mut groups: BTreeMap<Vec<(i32, String)>, Vec<PartitionedFile>> = BTreeMap::new();
+        let mut unresolved: Vec<PartitionedFile> = Vec::new();
+        let mut num_data_files = 0;
+        for manifest in manifests
             .into_iter()
             .filter(|m| matches!(m.metadata.content, ManifestContent::Data))
-            .flat_map(|m| m.entries.into_iter().map(|ent| ent.data_file))
-            .collect();
+        {
+            if limit_reached(rows_planned) {
+                break;
+            }
 
-        let partitioned_files = data_files
-            .iter()
-            .map(|f| {
+            let manifest_fields = schema_field_ids(&manifest.metadata.schema);
+            for ent in manifest.entries {
+                if limit_reached(rows_planned) {
+                    break;
+                }
+
+                let f = ent.data_file;
+                rows_planned += f.record_count.max(0) as usize;
+                num_data_files += 1;
                 let path = self.state.resolver.relative_path(&f.file_path);
                 let meta = ObjectMeta {
-                    location: format_object_path(&self.state.location, path)?,
+                    location: format_object_path(&self.state.location, path)
+                        .map_err(|e| DataFusionError::External(Box::new(e)))?,
                     last_modified: DateTime::<Utc>::MIN_UTC, // TODO: Get the actual time.
                     size: f.file_size_in_bytes as usize,
                     e_tag: None,
                 };
 
-                Ok(PartitionedFile {
+                let file = PartitionedFile {
                     object_meta: meta,
                     partition_values: Vec::new(),
                     range: None,
                     extensions: None,
-                })
-            })
-            .collect::<Result<Vec<PartitionedFile>>>()
+                };
+                if manifest_fields.is_empty() {
+                    unresolved.push(file);
+                } else {
+                    groups
+                        .entry(manifest_fields.clone())
+                        .or_default()
+                        .push(file);
+                }
+            }
+        }
+
+        let footer_fields: Vec<Vec<(i32, String)>> = stream::iter(unresolved.iter())
+            .map(|file| parquet_field_ids(self.state.store.clone(), file.object_meta.clone()))
+            .buffered(self.max_concurrency)
+            .try_collect()
+            .await
             .map_err(|e| DataFusionError::External(Box::new(e)))?;
+        for (file, fields) in unresolved.into_iter().zip(footer_fields) {
+            groups.entry(fields).or_default().push(file);
+        }
 
-        let conf = FileScanConfig {
-            object_store_url: object_url,
-            file_schema: self.schema(),
-            projection: projection.cloned(),
-            statistics: Statistics::default(),
-            file_groups: vec![partitioned_files],
-            limit,
-            table_partition_cols: Vec::new(),
-            output_ordering: Vec::new(),
-            infinite_source: false,
+        let stats = IcebergScanStats {
+            manifests_read,
+            data_files_considered,
+            data_files_pruned: data_files_considered - num_data_files,
         };
 
-        let plan = ParquetFormat::new()
-            .create_physical_plan(ctx, conf, None)
-            .await?;
+        // Still produce an (empty) exec when there's nothing to read.
+        if groups.is_empty() {
+            groups.insert(schema_field_ids(&self.read_schema), Vec::new());
+        }
+
+        let output_schema = match projection {
+            Some(projection) => Arc::new(self.schema.project(projection)?),
+            None => self.schema.clone(),
+        };
+
+        // Files within a group are read sequentially, and groups are read in
+        // parallel. Bound the number of groups across all schemas to limit the
+        // number of files being read at once.
+        let max_concurrency = (self.max_concurrency / groups.len()).max(1);
+
+        let mut plans = Vec::with_capacity(groups.len());
+        for (written_fields, files) in groups {
+            let file_schema = file_schema_for(&self.read_schema, &written_fields)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+
+            let conf = FileScanConfig {
+                object_store_url: object_url.clone(),
+                file_schema: Arc::new(file_schema),
+                projection: projection.cloned(),
+                statistics: Statistics::default(),
+                file_groups: split_file_groups(files, max_concurrency),
+                limit,
+                table_partition_cols: Vec::new(),
+                output_ordering: Vec::new(),
+                infinite_source: false,
+            };
+
+            let plan = ParquetFormat::new()
+                .create_physical_plan(ctx, conf, None)
+                .await?;
+            plans.push(rename_columns(plan, &output_schema)?);
+        }
+
+        let plan = if plans.len() == 1 {
+            plans.pop().unwrap()
+        } else {
+            Arc::new(UnionExec::new(plans))
+        };
+
+        Ok(Arc::new(IcebergTableScan::new(plan, stats)))
+    }
+}
+
+/// Build the arrow schema for reading data files containing the `written`
+/// columns as the `read` schema.
+///
+/// `written` holds the field id and name of each column in the files. Fields
+/// are matched by id, so the returned schema has the fields of `read`, named
+/// as they are in the files. Fields that don't exist in the files are given a
+/// name that can't match any column, which reads them as NULLs.
+fn file_schema_for(read: &Schema, written: &[(i32, String)]) -> Result<ArrowSchema> {
+    let fields = read
+        .fields
+        .iter()
+        .map(|field| {
+            let name = match written.iter().find(|(id, _)| *id == field.id) {
+                Some((_, name)) => name.clone(),
+                None => format!("__missing_field_{}", field.id),
+            };
+            StructField {
+                name,
+                ..field.clone()
+            }
+            .to_arrow_field()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ArrowSchema::new(fields))
+}
+
+/// Field ids and names of the top level fields in an iceberg schema.
+fn schema_field_ids(schema: &Schema) -> Vec<(i32, String)> {
+    schema
+        .fields
+        .iter()
+        .map(|f| (f.id, f.name.clone()))
+        .collect()
+}
+
+/// Read the field ids and names of the top level columns in a parquet file
+/// from its footer.
+///
+/// Columns without a field id are skipped, so this is empty for files not
+/// written with field ids.
+async fn parquet_field_ids(
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+) -> Result<Vec<(i32, String)>> {
+    let reader = ParquetObjectReader::new(store, meta);
+    let builder = ParquetRecordBatchStreamBuilder::new(reader)
+        .await
+        .map_err(DataFusionError::ParquetError)?;
+
+    let fields = builder
+        .schema()
+        .fields()
+        .iter()
+        .filter_map(|f| {
+            let id = f.metadata().get(PARQUET_FIELD_ID_META_KEY)?.parse().ok()?;
+            Some((id, f.name().clone()))
+        })
+        .collect();
+    Ok(fields)
+}
 
-        Ok(Arc::new(IcebergTableScan { parquet_scan: plan }))
+/// Rename the output columns of `plan` to the names in `schema` if they
+/// differ.
+fn rename_columns(
+    plan: Arc<dyn ExecutionPlan>,
+    schema: &ArrowSchema,
+) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+    let input_schema = plan.schema();
+    let same_names = input_schema
+        .fields()
+        .iter()
+        .zip(schema.fields())
+        .all(|(a, b)| a.name() == b.name());
+    if same_names {
+        return Ok(plan);
     }
+
+    let exprs = input_schema
+        .fields()
+        .iter()
+        .zip(schema.fields())
+        .enumerate()
+        .map(|(idx, (from, to))| {
+            (
+                Arc::new(Column::new(from.name(), idx)) as Arc<dyn PhysicalExpr>,
+                to.name().clone(),
+            )
+        })
+        .collect();
+
+    Ok(Arc::new(ProjectionExec::try_new(exprs, plan)?))
+}
+
+/// Distribute files round-robin into at most `max_groups` non-empty groups.
+fn split_file_groups<T>(files: Vec<T>, max_groups: usize) -> Vec<Vec<T>> {
+    let num_groups = files.len().min(max_groups).max(1);
+    let mut groups: Vec<Vec<T>> = (0..num_groups).map(|_| Vec::new()).collect();
+    for (idx, file) in files.into_iter().enumerate() {
+        groups[idx % num_groups].push(file);
+    }
+    groups
 }
 
 /// Creates a datafusion object store url from the provided data source url.
@@ -367,9 +1003,49 @@ fn datasource_url_to_unique_url(url: &DatasourceUrl) -> ObjectStoreUrl {
     .unwrap()
 }
 
+/// Counts of the metadata read when planning a scan, and how many data files
+/// were skipped as a result.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IcebergScanStats {
+    /// Number of manifests read for the snapshot.
+    pub manifests_read: usize,
+    /// Number of files listed across all read manifests.
+    pub data_files_considered: usize,
+    /// Number of listed files that will not be scanned.
+    pub data_files_pruned: usize,
+}
+
 #[derive(Debug)]
 pub struct IcebergTableScan {
     parquet_scan: Arc<dyn ExecutionPlan>,
+    stats: IcebergScanStats,
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl IcebergTableScan {
+    fn new(parquet_scan: Arc<dyn ExecutionPlan>, stats: IcebergScanStats) -> Self {
+        let metrics = ExecutionPlanMetricsSet::new();
+        MetricBuilder::new(&metrics)
+            .global_counter("manifests_read")
+            .add(stats.manifests_read);
+        MetricBuilder::new(&metrics)
+            .global_counter("data_files_considered")
+            .add(stats.data_files_considered);
+        MetricBuilder::new(&metrics)
+            .global_counter("data_files_pruned")
+            .add(stats.data_files_pruned);
+
+        IcebergTableScan {
+            parquet_scan,
+            stats,
+            metrics,
+        }
+    }
+
+    /// Get the stats collected when planning this scan.
+    pub fn stats(&self) -> IcebergScanStats {
+        self.stats
+    }
 }
 
 impl ExecutionPlan for IcebergTableScan {
@@ -393,11 +1069,31 @@ impl ExecutionPlan for IcebergTableScan {
         vec![self.parquet_scan.clone()]
     }
 
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        // Files are already split into at most `max_concurrency` groups.
+        // Repartitioning the file scan would read more files at once.
+        vec![false]
+    }
+
     fn with_new_children(
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
-        ExecutionPlan::with_new_children(self.parquet_scan.clone(), children)
+        let parquet_scan = match <[_; 1]>::try_from(children) {
+            Ok([child]) => child,
+            Err(children) => {
+                return Err(DataFusionError::Internal(format!(
+                    "IcebergTableScan expects one child, got {}",
+                    children.len()
+                )))
+            }
+        };
+
+        Ok(Arc::new(IcebergTableScan {
+            parquet_scan,
+            stats: self.stats,
+            metrics: self.metrics.clone(),
+        }))
     }
 
     fn execute(
@@ -411,11 +1107,21 @@ impl ExecutionPlan for IcebergTableScan {
     fn statistics(&self) -> Statistics {
         Statistics::default()
     }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
 }
 
 impl DisplayAs for IcebergTableScan {
     fn fmt_as(&self, t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "IcebergTableScan(")?;
+        write!(
+            f,
+            "IcebergTableScan: manifests_read={}, data_files_considered={}, data_files_pruned={} (",
+            self.stats.manifests_read,
+            self.stats.data_files_considered,
+            self.stats.data_files_pruned,
+        )?;
         self.parquet_scan.fmt_as(t, f)?;
         write!(f, ")")
     }
@@ -444,6 +1150,31 @@ fn format_object_path(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use datafusion::arrow::record_batch::RecordBatch;
+
+    /// Copy a table from the test data into a temp dir so that tests can
+    /// modify it.
+    fn copy_test_table(name: &str) -> tempfile::TempDir {
+        fn copy_dir(from: &std::path::Path, to: &std::path::Path) {
+            std::fs::create_dir_all(to).unwrap();
+            for ent in std::fs::read_dir(from).unwrap() {
+                let ent = ent.unwrap();
+                let dest = to.join(ent.file_name());
+                if ent.file_type().unwrap().is_dir() {
+                    copy_dir(&ent.path(), &dest);
+                } else {
+                    std::fs::copy(ent.path(), dest).unwrap();
+                }
+            }
+        }
+
+        let src = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../testdata/iceberg/tables")
+            .join(name);
+        let tmp = tempfile::tempdir().unwrap();
+        copy_dir(&src, tmp.path());
+        tmp
+    }
 
     #[test]
     fn test_path_resolve() {
@@ -494,4 +1225,640 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn metadata_location_relative_to_root() {
+        let root = "s3://bucket/tables/t";
+        for (input, expected) in [
+            ("metadata/v1.metadata.json", "metadata/v1.metadata.json"),
+            ("./metadata/v1.metadata.json", "metadata/v1.metadata.json"),
+            (
+                "s3://bucket/tables/t/metadata/v1.metadata.json",
+                "metadata/v1.metadata.json",
+            ),
+            // Sibling table sharing a prefix.
+            (
+                "s3://bucket/tables/t2/metadata/v1.metadata.json",
+                "s3://bucket/tables/t2/metadata/v1.metadata.json",
+            ),
+        ] {
+            assert_eq!(expected, relative_to_root(root, input), "input: {input}");
+            assert_eq!(
+                expected,
+                relative_to_root(&format!("{root}/"), input),
+                "input: {input}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_reports_stats() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../testdata/iceberg/tables/lineitem_simple"
+        );
+        let location = DatasourceUrl::try_new(path).unwrap();
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let reader = table.table_reader().await.unwrap();
+
+        let ctx = datafusion::prelude::SessionContext::new();
+        let plan = reader.scan(&ctx.state(), None, &[], None).await.unwrap();
+        let scan = plan.as_any().downcast_ref::<IcebergTableScan>().unwrap();
+
+        let stats = scan.stats();
+        assert_eq!(1, stats.manifests_read);
+        assert_eq!(1, stats.data_files_considered);
+        assert_eq!(0, stats.data_files_pruned);
+
+        let metrics = scan.metrics().unwrap();
+        assert_eq!(
+            Some(1),
+            metrics.sum_by_name("manifests_read").map(|v| v.as_usize())
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_manifests_matches_read() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../testdata/iceberg/tables/lineitem_versioned"
+        );
+        let location = DatasourceUrl::try_new(path).unwrap();
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let read = table.read_manifests().await.unwrap();
+        let streamed: Vec<_> = table.stream_manifests().try_collect().await.unwrap();
+
+        assert_eq!(2, streamed.len());
+        assert_eq!(
+            read.iter().map(|m| m.entries.len()).collect::<Vec<_>>(),
+            streamed.iter().map(|m| m.entries.len()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn split_file_groups_caps_groups() {
+        let groups = split_file_groups((0..10).collect(), 3);
+        assert_eq!(3, groups.len());
+        assert_eq!(vec![0, 3, 6, 9], groups[0]);
+        assert_eq!(10, groups.iter().map(|g| g.len()).sum::<usize>());
+
+        // Never more groups than files.
+        assert_eq!(2, split_file_groups(vec![0, 1], 8).len());
+
+        // Always at least one group so the exec has a partition.
+        assert_eq!(vec![Vec::<i32>::new()], split_file_groups(Vec::new(), 8));
+    }
+
+    #[tokio::test]
+    async fn scan_respects_max_concurrency() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../testdata/iceberg/tables/lineitem_partitioned"
+        );
+        let location = DatasourceUrl::try_new(path).unwrap();
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let ctx = datafusion::prelude::SessionContext::new();
+
+        let reader = table
+            .table_reader_with_options(IcebergReadOptions {
+                max_concurrency: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let plan = reader.scan(&ctx.state(), None, &[], None).await.unwrap();
+        assert_eq!(1, plan.output_partitioning().partition_count());
+
+        let reader = table
+            .table_reader_with_options(IcebergReadOptions {
+                max_concurrency: 2,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let plan = reader.scan(&ctx.state(), None, &[], None).await.unwrap();
+        assert_eq!(2, plan.output_partitioning().partition_count());
+
+        // The optimizer must not split the file groups further when planning
+        // through SQL.
+        let config = datafusion::prelude::SessionConfig::new()
+            .with_target_partitions(8)
+            .with_repartition_file_scans(true)
+            .with_repartition_file_min_size(0);
+        let ctx = datafusion::prelude::SessionContext::new_with_config(config);
+        ctx.register_table("lineitem", reader).unwrap();
+        let plan = ctx
+            .sql("SELECT * FROM lineitem")
+            .await
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        let mut found = None;
+        datafusion::physical_plan::accept(&plan, &mut FindIcebergScan(&mut found)).unwrap();
+        assert_eq!(Some(2), found.map(|(_, partitions)| partitions));
+    }
+
+    #[tokio::test]
+    async fn scan_with_limit_reads_fewer_files() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../testdata/iceberg/tables/lineitem_partitioned"
+        );
+        let location = DatasourceUrl::try_new(path).unwrap();
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let reader = table.table_reader().await.unwrap();
+        let ctx = datafusion::prelude::SessionContext::new();
+
+        let plan = reader.scan(&ctx.state(), None, &[], None).await.unwrap();
+        let scan = plan.as_any().downcast_ref::<IcebergTableScan>().unwrap();
+        let stats = scan.stats();
+        assert_eq!(7, stats.data_files_considered);
+        assert_eq!(0, stats.data_files_pruned);
+
+        // Every data file has more than 10 rows, so a single file is enough.
+        let plan = reader
+            .scan(&ctx.state(), None, &[], Some(10))
+            .await
+            .unwrap();
+        let scan = plan.as_any().downcast_ref::<IcebergTableScan>().unwrap();
+        let stats = scan.stats();
+        assert_eq!(7, stats.data_files_considered);
+        assert_eq!(6, stats.data_files_pruned);
+
+        let batches = datafusion::physical_plan::collect(plan, ctx.task_ctx())
+            .await
+            .unwrap();
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert!(rows >= 10, "expected at least 10 rows, got {rows}");
+
+        // Limits planned through SQL reach the scan.
+        ctx.register_table("lineitem", reader).unwrap();
+        let plan = ctx
+            .sql("SELECT * FROM lineitem LIMIT 10")
+            .await
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        let mut found = None;
+        datafusion::physical_plan::accept(&plan, &mut FindIcebergScan(&mut found)).unwrap();
+        assert_eq!(Some(6), found.map(|(stats, _)| stats.data_files_pruned));
+    }
+
+    /// Visitor recording the stats and number of partitions of an iceberg
+    /// scan in a plan.
+    struct FindIcebergScan<'a>(&'a mut Option<(IcebergScanStats, usize)>);
+
+    impl datafusion::physical_plan::ExecutionPlanVisitor for FindIcebergScan<'_> {
+        type Error = DataFusionError;
+
+        fn pre_visit(&mut self, plan: &dyn ExecutionPlan) -> DataFusionResult<bool> {
+            if let Some(scan) = plan.as_any().downcast_ref::<IcebergTableScan>() {
+                let partitions = scan.output_partitioning().partition_count();
+                *self.0 = Some((scan.stats(), partitions));
+            }
+            Ok(true)
+        }
+    }
+
+    /// Copy the simple lineitem table into a temp dir, adding a table version
+    /// with an evolved schema.
+    ///
+    /// The new schema renames `l_comment` to `l_note`, drops `l_shipmode`,
+    /// and adds a new `l_added` column. The data files are all written with
+    /// the original schema.
+    fn evolved_table() -> tempfile::TempDir {
+        let tmp = copy_test_table("lineitem_simple");
+
+        let metadata_dir = tmp.path().join("metadata");
+        let mut metadata: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(metadata_dir.join("v1.metadata.json")).unwrap())
+                .unwrap();
+
+        let mut schema = metadata["schemas"][0].clone();
+        schema["schema-id"] = 1.into();
+        let fields = schema["fields"].as_array_mut().unwrap();
+        fields.retain(|f| f["name"] != "l_shipmode");
+        for f in fields.iter_mut() {
+            if f["name"] == "l_comment" {
+                f["name"] = "l_note".into();
+            }
+        }
+        fields.push(serde_json::json!({
+            "id": 17,
+            "name": "l_added",
+            "required": false,
+            "type": "string",
+        }));
+
+        metadata["schemas"].as_array_mut().unwrap().push(schema);
+        metadata["current-schema-id"] = 1.into();
+        metadata["last-column-id"] = 17.into();
+
+        std::fs::write(
+            metadata_dir.join("v2.metadata.json"),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(metadata_dir.join("version-hint.text"), "2").unwrap();
+
+        tmp
+    }
+
+    async fn read_evolved(merge_schemas: bool) -> Vec<RecordBatch> {
+        let dir = evolved_table();
+        let location = DatasourceUrl::try_new(dir.path().to_str().unwrap()).unwrap();
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let reader = table
+            .table_reader_with_options(IcebergReadOptions {
+                merge_schemas,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let ctx = datafusion::prelude::SessionContext::new();
+        ctx.read_table(reader).unwrap().collect().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn scan_evolved_schema() {
+        let batches = read_evolved(false).await;
+        let schema = batches[0].schema();
+
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert!(names.contains(&"l_note"));
+        assert!(names.contains(&"l_added"));
+        assert!(!names.contains(&"l_comment"));
+        assert!(!names.contains(&"l_shipmode"));
+
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(1000, num_rows);
+
+        for batch in &batches {
+            // Renamed column is read using the field id.
+            let note = batch.column(schema.index_of("l_note").unwrap());
+            assert_eq!(0, note.null_count());
+
+            // Added column is all NULLs for the older data files.
+            let added = batch.column(schema.index_of("l_added").unwrap());
+            assert_eq!(batch.num_rows(), added.null_count());
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_evolved_schema_merged() {
+        let batches = read_evolved(true).await;
+        let schema = batches[0].schema();
+
+        // Dropped column is included at the end when merging schemas.
+        assert_eq!(
+            "l_shipmode",
+            schema.fields().last().unwrap().name().as_str()
+        );
+        for batch in &batches {
+            let shipmode = batch.column(schema.index_of("l_shipmode").unwrap());
+            assert_eq!(0, shipmode.null_count());
+        }
+    }
+
+    #[test]
+    fn file_schema_maps_field_ids() {
+        let field = |id: i32, name: &str| StructField {
+            id,
+            name: name.to_string(),
+            required: false,
+            r#type: serde_json::from_str(r#""string""#).unwrap(),
+            doc: None,
+            initial_default: None,
+            write_default: None,
+        };
+        let read = Schema {
+            schema_id: 0,
+            identifier_field_ids: None,
+            fields: vec![field(1, "renamed"), field(3, "b")],
+        };
+
+        // Field 1 renamed, field 2 dropped and its name reused by field 3.
+        let written = vec![(1, "a".to_string()), (2, "b".to_string())];
+
+        let file_schema = file_schema_for(&read, &written).unwrap();
+        let names: Vec<_> = file_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+        assert_eq!(vec!["a", "__missing_field_3"], names);
+    }
+
+    #[tokio::test]
+    async fn parquet_field_ids_read_from_footer() {
+        let tmp = copy_test_table("lineitem_simple");
+        let data_dir = tmp.path().join("data");
+        let file = std::fs::read_dir(&data_dir)
+            .unwrap()
+            .map(|ent| ent.unwrap().path())
+            .find(|p| p.extension().is_some_and(|ext| ext == "parquet"))
+            .unwrap();
+
+        let store = Arc::new(object_store::local::LocalFileSystem::new());
+        let location = ObjectPath::from_filesystem_path(&file).unwrap();
+        let meta = store.head(&location).await.unwrap();
+
+        // Ids come from the file, not from any table metadata.
+        let fields = parquet_field_ids(store, meta).await.unwrap();
+        assert_eq!(16, fields.len());
+        assert_eq!((1, "l_orderkey".to_string()), fields[0]);
+    }
+
+    /// Store that never completes reads of parquet files once `hang` is set,
+    /// tracking the number of reads in progress.
+    #[derive(Debug)]
+    struct HangingStore {
+        inner: object_store::local::LocalFileSystem,
+        hang: Arc<std::sync::atomic::AtomicBool>,
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    /// Decrements the in-flight count when the read future is dropped.
+    struct InFlightGuard(Arc<std::sync::atomic::AtomicUsize>);
+
+    impl Drop for InFlightGuard {
+        fn drop(&mut self) {
+            self.0.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    impl HangingStore {
+        async fn maybe_hang(&self, location: &ObjectPath) {
+            if self.hang.load(std::sync::atomic::Ordering::SeqCst)
+                && location.as_ref().ends_with(".parquet")
+            {
+                self.in_flight
+                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let _guard = InFlightGuard(self.in_flight.clone());
+                futures::future::pending::<()>().await;
+            }
+        }
+    }
+
+    impl std::fmt::Display for HangingStore {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "HangingStore")
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for HangingStore {
+        async fn put(
+            &self,
+            location: &ObjectPath,
+            bytes: bytes::Bytes,
+        ) -> object_store::Result<()> {
+            self.inner.put(location, bytes).await
+        }
+
+        async fn put_multipart(
+            &self,
+            location: &ObjectPath,
+        ) -> object_store::Result<(
+            object_store::MultipartId,
+            Box<dyn tokio::io::AsyncWrite + Unpin + Send>,
+        )> {
+            self.inner.put_multipart(location).await
+        }
+
+        async fn abort_multipart(
+            &self,
+            location: &ObjectPath,
+            multipart_id: &object_store::MultipartId,
+        ) -> object_store::Result<()> {
+            self.inner.abort_multipart(location, multipart_id).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &ObjectPath,
+            options: object_store::GetOptions,
+        ) -> object_store::Result<object_store::GetResult> {
+            self.maybe_hang(location).await;
+            self.inner.get_opts(location, options).await
+        }
+
+        async fn get_range(
+            &self,
+            location: &ObjectPath,
+            range: std::ops::Range<usize>,
+        ) -> object_store::Result<bytes::Bytes> {
+            self.maybe_hang(location).await;
+            self.inner.get_range(location, range).await
+        }
+
+        async fn head(&self, location: &ObjectPath) -> object_store::Result<ObjectMeta> {
+            self.inner.head(location).await
+        }
+
+        async fn delete(&self, location: &ObjectPath) -> object_store::Result<()> {
+            self.inner.delete(location).await
+        }
+
+        async fn list(
+            &self,
+            prefix: Option<&ObjectPath>,
+        ) -> object_store::Result<futures::stream::BoxStream<'_, object_store::Result<ObjectMeta>>>
+        {
+            self.inner.list(prefix).await
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            prefix: Option<&ObjectPath>,
+        ) -> object_store::Result<object_store::ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &ObjectPath, to: &ObjectPath) -> object_store::Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(
+            &self,
+            from: &ObjectPath,
+            to: &ObjectPath,
+        ) -> object_store::Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_scan_cancels_reads() {
+        use futures::StreamExt;
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../testdata/iceberg/tables/lineitem_partitioned"
+        );
+        let location = DatasourceUrl::try_new(path).unwrap();
+        let hang = Arc::new(AtomicBool::new(false));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let store = Arc::new(HangingStore {
+            inner: object_store::local::LocalFileSystem::new(),
+            hang: hang.clone(),
+            in_flight: in_flight.clone(),
+        });
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let reader = table.table_reader().await.unwrap();
+
+        let ctx = datafusion::prelude::SessionContext::new();
+        // Planning must not read any data files, so reads can hang from the
+        // start.
+        hang.store(true, Ordering::SeqCst);
+        let plan = reader.scan(&ctx.state(), None, &[], None).await.unwrap();
+        let mut stream = plan.execute(0, ctx.task_ctx()).unwrap();
+
+        // The read never completes, so neither does the stream.
+        let res = tokio::time::timeout(std::time::Duration::from_millis(100), stream.next()).await;
+        assert!(res.is_err());
+        assert!(in_flight.load(Ordering::SeqCst) > 0);
+
+        // Dropping the stream (e.g. on query cancellation) must abort the
+        // outstanding reads.
+        drop(stream);
+        assert_eq!(0, in_flight.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn validate_reports_missing_files() {
+        let tmp = copy_test_table("lineitem_simple");
+        let table_dir = tmp.path();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::local::LocalFileSystem::new());
+        let location = DatasourceUrl::try_new(table_dir.to_str().unwrap()).unwrap();
+
+        let table = IcebergTable::open(location.clone(), store.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            Vec::<ValidationProblem>::new(),
+            table.validate().await.unwrap()
+        );
+
+        for ent in std::fs::read_dir(table_dir.join("data")).unwrap() {
+            std::fs::remove_file(ent.unwrap().path()).unwrap();
+        }
+
+        let problems = table.validate().await.unwrap();
+        assert_eq!(1, problems.len());
+        assert_eq!("missing_file", problems[0].kind());
+    }
+
+    #[tokio::test]
+    async fn expire_snapshots_removes_unreferenced_files() {
+        let tmp = copy_test_table("lineitem_versioned");
+        let table_dir = tmp.path();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::local::LocalFileSystem::new());
+        let location = DatasourceUrl::try_new(table_dir.to_str().unwrap()).unwrap();
+
+        let table = IcebergTable::open(location.clone(), store.clone())
+            .await
+            .unwrap();
+        assert_eq!(2, table.metadata().snapshots.len());
+
+        // Both snapshots are recent enough to keep.
+        let expired = table
+            .expire_snapshots(ExpireSnapshotsOptions {
+                older_than: DateTime::<Utc>::MIN_UTC,
+                retain_last: 0,
+            })
+            .await
+            .unwrap();
+        assert_eq!(ExpiredSnapshots::default(), expired);
+
+        // Both snapshots are old, but the most recent is retained.
+        let expired = table
+            .expire_snapshots(ExpireSnapshotsOptions {
+                older_than: Utc::now(),
+                retain_last: 1,
+            })
+            .await
+            .unwrap();
+        assert_eq!(vec![4808627676923931467], expired.snapshot_ids);
+
+        // The second snapshot's manifest list carries over the manifest and
+        // data file from the first, so only the first manifest list is
+        // unreferenced.
+        let manifest_list =
+            "metadata/snap-4808627676923931467-1-da7b607a-4cb2-4b2f-811c-4368319f5266.avro";
+        assert_eq!(vec![manifest_list.to_string()], expired.deleted_files);
+        assert!(!table_dir.join(manifest_list).exists());
+
+        let table = IcebergTable::open(location, store).await.unwrap();
+        let snapshots: Vec<_> = table
+            .metadata()
+            .snapshots
+            .iter()
+            .map(|s| s.snapshot_id)
+            .collect();
+        assert_eq!(vec![2290745669989949290], snapshots);
+        assert_eq!(
+            Vec::<ValidationProblem>::new(),
+            table.validate().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn expire_snapshots_aborts_on_conflict() {
+        let tmp = copy_test_table("lineitem_versioned");
+        let table_dir = tmp.path();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::local::LocalFileSystem::new());
+        let location = DatasourceUrl::try_new(table_dir.to_str().unwrap()).unwrap();
+        let table = IcebergTable::open(location, store).await.unwrap();
+
+        // Another writer commits the next version first.
+        let metadata_dir = table_dir.join("metadata");
+        std::fs::copy(
+            metadata_dir.join("v2.metadata.json"),
+            metadata_dir.join("v3.metadata.json"),
+        )
+        .unwrap();
+        let before: Vec<_> = std::fs::read_dir(&metadata_dir)
+            .unwrap()
+            .map(|ent| ent.unwrap().file_name())
+            .collect();
+
+        let err = table
+            .expire_snapshots(ExpireSnapshotsOptions {
+                older_than: Utc::now(),
+                retain_last: 1,
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IcebergError::CommitConflict(3)), "{err:?}");
+
+        // Nothing was deleted or left behind, and the hint is untouched.
+        let after: Vec<_> = std::fs::read_dir(&metadata_dir)
+            .unwrap()
+            .map(|ent| ent.unwrap().file_name())
+            .collect();
+        assert_eq!(before.len(), after.len());
+        assert_eq!(
+            "2",
+            std::fs::read_to_string(metadata_dir.join("version-hint.text")).unwrap()
+        );
+    }
 }
@@ -3,14 +3,17 @@
 pub mod delta;
 pub mod iceberg;
 
+use moka::sync::Cache;
 use object_store::aws::{AmazonS3Builder, AmazonS3ConfigKey};
 use object_store::azure::{AzureConfigKey, MicrosoftAzureBuilder};
 use object_store::gcp::{GoogleCloudStorageBuilder, GoogleConfigKey};
 use object_store::local::LocalFileSystem;
-use object_store::ObjectStore;
+use object_store::{ObjectStore, RetryConfig};
+use once_cell::sync::Lazy;
 use protogen::metastore::types::options::StorageOptions;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::common::url::{DatasourceUrl, DatasourceUrlType};
 
@@ -27,55 +30,64 @@ pub enum LakeStorageOptionsError {
 
     #[error("Unsupported object store for url: {0:?}")]
     UnsupportedObjectStore(DatasourceUrl),
+
+    #[error("Invalid value for storage option '{key}': {value}")]
+    InvalidOption { key: &'static str, value: String },
 }
 
+/// Storage option for the max number of times a failed request is retried.
+pub const RETRIES_OPTION: &str = "retries";
+
+/// Storage option for the backoff (in milliseconds) before the first retry.
+pub const RETRY_BACKOFF_MS_OPTION: &str = "retry_backoff_ms";
+
 /// Create an object store from the provided storage options.
+///
+/// Requests against cloud stores are retried on transient errors according to
+/// the retry options (see `RETRIES_OPTION`).
 pub fn storage_options_into_object_store(
     url: &DatasourceUrl,
     opts: &StorageOptions,
+) -> Result<Arc<dyn ObjectStore>, LakeStorageOptionsError> {
+    object_store_from_options(url, opts, false)
+}
+
+/// Create an object store from the storage options.
+///
+/// If `allow_ambient_credentials` is set, stores for cloud providers are
+/// initialized from the environment first, with any explicitly provided
+/// options taking precedence. When no options are provided, the store falls
+/// back to the provider's default credential chain (environment variables,
+/// profiles, instance metadata). These are the server's credentials, so this
+/// should only be allowed when the server is configured for it.
+fn object_store_from_options(
+    url: &DatasourceUrl,
+    opts: &StorageOptions,
+    allow_ambient_credentials: bool,
 ) -> Result<Arc<dyn ObjectStore>, LakeStorageOptionsError> {
     match url.datasource_url_type() {
         DatasourceUrlType::S3 => {
             let bucket = url
                 .host()
                 .ok_or_else(|| LakeStorageOptionsError::MissingHost(url.clone()))?;
-
-            let mut store = AmazonS3Builder::new().with_bucket_name(bucket);
-
-            for (key, value) in &opts.inner {
-                if let Ok(s3_key) = AmazonS3ConfigKey::from_str(key) {
-                    store = store.with_config(s3_key, value);
-                }
-            }
+            let store = s3_builder(bucket, opts, allow_ambient_credentials)
+                .with_retry(retry_config_from_options(opts)?);
             Ok(Arc::new(store.build()?))
         }
         DatasourceUrlType::Gcs => {
             let bucket = url
                 .host()
                 .ok_or_else(|| LakeStorageOptionsError::MissingHost(url.clone()))?;
-
-            let mut store = GoogleCloudStorageBuilder::new().with_bucket_name(bucket);
-
-            for (key, value) in &opts.inner {
-                if let Ok(gcp_key) = GoogleConfigKey::from_str(key) {
-                    store = store.with_config(gcp_key, value);
-                }
-            }
+            let store = gcs_builder(bucket, opts, allow_ambient_credentials)
+                .with_retry(retry_config_from_options(opts)?);
             Ok(Arc::new(store.build()?))
         }
         DatasourceUrlType::Azure => {
             let bucket = url
                 .host()
                 .ok_or_else(|| LakeStorageOptionsError::MissingHost(url.clone()))?;
-
-            let mut store = MicrosoftAzureBuilder::new().with_container_name(bucket);
-
-            for (key, value) in &opts.inner {
-                if let Ok(azure_key) = AzureConfigKey::from_str(key) {
-                    store = store.with_config(azure_key, value);
-                }
-            }
-
+            let store = azure_builder(bucket, opts, allow_ambient_credentials)
+                .with_retry(retry_config_from_options(opts)?);
             Ok(Arc::new(store.build()?))
         }
         DatasourceUrlType::File => {
@@ -87,3 +99,349 @@ pub fn storage_options_into_object_store(
         }
     }
 }
+
+/// Parse config keys from storage options.
+fn options_config<K: FromStr>(opts: &StorageOptions) -> impl Iterator<Item = (K, &String)> {
+    opts.inner
+        .iter()
+        .filter_map(|(key, value)| Some((K::from_str(key).ok()?, value)))
+}
+
+fn s3_builder(
+    bucket: &str,
+    opts: &StorageOptions,
+    allow_ambient_credentials: bool,
+) -> AmazonS3Builder {
+    let mut store = if allow_ambient_credentials {
+        AmazonS3Builder::from_env()
+    } else {
+        AmazonS3Builder::new()
+    };
+    for (key, value) in options_config::<AmazonS3ConfigKey>(opts) {
+        store = store.with_config(key, value);
+    }
+    store.with_bucket_name(bucket)
+}
+
+fn gcs_builder(
+    bucket: &str,
+    opts: &StorageOptions,
+    allow_ambient_credentials: bool,
+) -> GoogleCloudStorageBuilder {
+    let mut store = if allow_ambient_credentials {
+        GoogleCloudStorageBuilder::from_env()
+    } else {
+        GoogleCloudStorageBuilder::new()
+    };
+    for (key, value) in options_config::<GoogleConfigKey>(opts) {
+        store = store.with_config(key, value);
+    }
+    store.with_bucket_name(bucket)
+}
+
+fn azure_builder(
+    container: &str,
+    opts: &StorageOptions,
+    allow_ambient_credentials: bool,
+) -> MicrosoftAzureBuilder {
+    let mut store = if allow_ambient_credentials {
+        MicrosoftAzureBuilder::from_env()
+    } else {
+        MicrosoftAzureBuilder::new()
+    };
+    for (key, value) in options_config::<AzureConfigKey>(opts) {
+        store = store.with_config(key, value);
+    }
+    store.with_container_name(container)
+}
+
+/// Key for cached object stores.
+///
+/// Stores are cached per bucket, so the key includes the url type and host
+/// along with all storage options. Options include credentials, so only a
+/// digest of them is kept in the key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ObjectStoreCacheKey {
+    url_type: String,
+    host: Option<String>,
+    opts_digest: [u8; 32],
+}
+
+impl ObjectStoreCacheKey {
+    fn new(url: &DatasourceUrl, opts: &StorageOptions) -> Self {
+        let mut digest = ring::digest::Context::new(&ring::digest::SHA256);
+        for (key, value) in &opts.inner {
+            // Length prefixed so that different options can't produce the
+            // same input.
+            for s in [key, value] {
+                digest.update(&(s.len() as u64).to_le_bytes());
+                digest.update(s.as_bytes());
+            }
+        }
+        let mut opts_digest = [0; 32];
+        opts_digest.copy_from_slice(digest.finish().as_ref());
+
+        ObjectStoreCacheKey {
+            url_type: url.datasource_url_type().to_string(),
+            host: url.host().map(|h| h.to_string()),
+            opts_digest,
+        }
+    }
+}
+
+/// Max number of object stores kept in the cache.
+const OBJECT_STORE_CACHE_CAPACITY: u64 = 128;
+
+/// How long a cached object store may go unused before it's dropped.
+const OBJECT_STORE_CACHE_IDLE: Duration = Duration::from_secs(15 * 60);
+
+/// Process wide cache of object stores created from storage options.
+///
+/// Bounded in size, and stores that haven't been used for a while are
+/// dropped, so that stores (and the credentials they hold) for one-off
+/// queries aren't kept around forever.
+static OBJECT_STORE_CACHE: Lazy<Cache<ObjectStoreCacheKey, Arc<dyn ObjectStore>>> =
+    Lazy::new(|| {
+        Cache::builder()
+            .max_capacity(OBJECT_STORE_CACHE_CAPACITY)
+            .time_to_idle(OBJECT_STORE_CACHE_IDLE)
+            .build()
+    });
+
+/// Get an object store for the provided storage options, reusing a previously
+/// created store for the same bucket and options if there is one.
+///
+/// Reusing stores avoids setting up new connection pools and fetching
+/// credentials for every query.
+///
+/// Stores that may use ambient credentials (see `object_store_from_options`)
+/// are never cached since they're initialized from the environment, and
+/// shouldn't outlive changes to it.
+pub fn cached_object_store(
+    url: &DatasourceUrl,
+    opts: &StorageOptions,
+    allow_ambient_credentials: bool,
+) -> Result<Arc<dyn ObjectStore>, LakeStorageOptionsError> {
+    if allow_ambient_credentials {
+        return object_store_from_options(url, opts, true);
+    }
+
+    let key = ObjectStoreCacheKey::new(url, opts);
+    if let Some(store) = OBJECT_STORE_CACHE.get(&key) {
+        return Ok(store);
+    }
+
+    // Build outside of the lock, building may need to read from the
+    // filesystem.
+    let store = object_store_from_options(url, opts, false)?;
+
+    // Another caller may have built a store for the same key in the meantime,
+    // prefer the one that's already being shared.
+    Ok(OBJECT_STORE_CACHE.entry(key).or_insert(store).into_value())
+}
+
+/// Get the config for retrying failed requests from the storage options.
+fn retry_config_from_options(
+    opts: &StorageOptions,
+) -> Result<RetryConfig, LakeStorageOptionsError> {
+    fn parse_opt(
+        opts: &StorageOptions,
+        key: &'static str,
+    ) -> Result<Option<u64>, LakeStorageOptionsError> {
+        opts.inner
+            .get(key)
+            .map(|v| {
+                v.parse::<u64>()
+                    .map_err(|_| LakeStorageOptionsError::InvalidOption {
+                        key,
+                        value: v.clone(),
+                    })
+            })
+            .transpose()
+    }
+
+    let mut config = RetryConfig::default();
+    if let Some(retries) = parse_opt(opts, RETRIES_OPTION)? {
+        config.max_retries = retries as usize;
+    }
+    if let Some(backoff) = parse_opt(opts, RETRY_BACKOFF_MS_OPTION)? {
+        config.backoff.init_backoff = Duration::from_millis(backoff);
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Set the environment used by the tests relying on ambient credentials.
+    ///
+    /// Every test sets the same values, so tests running concurrently don't
+    /// interfere with each other.
+    fn set_test_env() {
+        // Azure requires an account name to build a store, which is only
+        // provided through the environment here.
+        std::env::set_var("AZURE_STORAGE_ACCOUNT_NAME", "glaredbtest");
+        std::env::set_var("AZURE_STORAGE_ACCOUNT_KEY", "ZmFrZWtleQ==");
+        std::env::set_var("AWS_REGION", "eu-west-1");
+    }
+
+    #[test]
+    fn store_from_env_without_options() {
+        set_test_env();
+        let opts = StorageOptions::default();
+
+        let builder = azure_builder("container", &opts, true);
+        assert_eq!(
+            Some("glaredbtest".to_string()),
+            builder.get_config_value(&AzureConfigKey::AccountName)
+        );
+
+        let url = DatasourceUrl::try_new("azure://container/path/to/table").unwrap();
+        let store = object_store_from_options(&url, &opts, true).unwrap();
+        assert!(store.to_string().contains("MicrosoftAzure"), "{store}");
+
+        let builder = s3_builder("bucket", &opts, true);
+        assert_eq!(
+            Some("eu-west-1".to_string()),
+            builder.get_config_value(&AmazonS3ConfigKey::Region)
+        );
+
+        let url = DatasourceUrl::try_new("s3://bucket/path/to/table").unwrap();
+        let store = object_store_from_options(&url, &opts, true).unwrap();
+        assert!(store.to_string().contains("AmazonS3(bucket)"), "{store}");
+    }
+
+    #[test]
+    fn env_ignored_without_ambient_credentials() {
+        set_test_env();
+        let opts = StorageOptions::default();
+
+        let builder = azure_builder("container", &opts, false);
+        assert_eq!(None, builder.get_config_value(&AzureConfigKey::AccountName));
+
+        let builder = s3_builder("bucket", &opts, false);
+        assert_ne!(
+            Some("eu-west-1".to_string()),
+            builder.get_config_value(&AmazonS3ConfigKey::Region)
+        );
+
+        let url = DatasourceUrl::try_new("azure://container/path/to/table").unwrap();
+        storage_options_into_object_store(&url, &opts).unwrap_err();
+    }
+
+    #[test]
+    fn options_override_env() {
+        set_test_env();
+        let mut opts = StorageOptions::default();
+        opts.inner.insert(
+            AmazonS3ConfigKey::Region.as_ref().to_string(),
+            "us-east-1".to_string(),
+        );
+
+        let builder = s3_builder("bucket", &opts, true);
+        assert_eq!(
+            Some("us-east-1".to_string()),
+            builder.get_config_value(&AmazonS3ConfigKey::Region)
+        );
+    }
+
+    #[test]
+    fn cached_store_reused_for_same_options() {
+        let url = DatasourceUrl::try_new("s3://cache-test-bucket/path/to/table").unwrap();
+        let mut opts = StorageOptions::default();
+        opts.inner.insert(
+            AmazonS3ConfigKey::Region.as_ref().to_string(),
+            "us-east-1".to_string(),
+        );
+
+        let a = cached_object_store(&url, &opts, false).unwrap();
+        let b = cached_object_store(&url, &opts, false).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // Different path within the same bucket shares the store.
+        let other_path = DatasourceUrl::try_new("s3://cache-test-bucket/other").unwrap();
+        let c = cached_object_store(&other_path, &opts, false).unwrap();
+        assert!(Arc::ptr_eq(&a, &c));
+
+        // Different options get a different store.
+        opts.inner.insert(
+            AmazonS3ConfigKey::Region.as_ref().to_string(),
+            "us-west-2".to_string(),
+        );
+        let d = cached_object_store(&url, &opts, false).unwrap();
+        assert!(!Arc::ptr_eq(&a, &d));
+
+        // As does a different bucket.
+        let other_bucket = DatasourceUrl::try_new("s3://cache-test-bucket-2/path").unwrap();
+        let e = cached_object_store(&other_bucket, &opts, false).unwrap();
+        assert!(!Arc::ptr_eq(&d, &e));
+
+        // Stores using ambient credentials aren't cached.
+        let f = cached_object_store(&url, &opts, true).unwrap();
+        let g = cached_object_store(&url, &opts, true).unwrap();
+        assert!(!Arc::ptr_eq(&f, &g));
+        assert!(!Arc::ptr_eq(&d, &f));
+    }
+
+    #[test]
+    fn cache_key_excludes_credentials() {
+        let url = DatasourceUrl::try_new("s3://bucket/path/to/table").unwrap();
+        let mut opts = StorageOptions::default();
+        opts.inner.insert(
+            AmazonS3ConfigKey::SecretAccessKey.as_ref().to_string(),
+            "supersecret".to_string(),
+        );
+
+        let key = ObjectStoreCacheKey::new(&url, &opts);
+        assert!(!format!("{key:?}").contains("supersecret"));
+        assert_eq!(key, ObjectStoreCacheKey::new(&url, &opts));
+
+        // Moving characters between keys and values changes the key.
+        let mut other = StorageOptions::default();
+        other.inner.insert(
+            format!("{}s", AmazonS3ConfigKey::SecretAccessKey.as_ref()),
+            "upersecret".to_string(),
+        );
+        assert_ne!(key, ObjectStoreCacheKey::new(&url, &other));
+    }
+
+    #[test]
+    fn cached_stores_bounded() {
+        let opts = StorageOptions::default();
+        for idx in 0..(OBJECT_STORE_CACHE_CAPACITY * 2) {
+            let url = DatasourceUrl::try_new(format!("s3://bounded-cache-{idx}/table")).unwrap();
+            cached_object_store(&url, &opts, false).unwrap();
+        }
+
+        OBJECT_STORE_CACHE.run_pending_tasks();
+        assert!(OBJECT_STORE_CACHE.entry_count() <= OBJECT_STORE_CACHE_CAPACITY);
+    }
+
+    #[test]
+    fn retry_config_options() {
+        let config = retry_config_from_options(&StorageOptions::default()).unwrap();
+        let default = RetryConfig::default();
+        assert_eq!(default.max_retries, config.max_retries);
+        assert_eq!(default.backoff.init_backoff, config.backoff.init_backoff);
+
+        let mut opts = StorageOptions::default();
+        opts.inner
+            .insert(RETRIES_OPTION.to_string(), "5".to_string());
+        opts.inner
+            .insert(RETRY_BACKOFF_MS_OPTION.to_string(), "250".to_string());
+        let config = retry_config_from_options(&opts).unwrap();
+        assert_eq!(5, config.max_retries);
+        assert_eq!(Duration::from_millis(250), config.backoff.init_backoff);
+
+        let mut opts = StorageOptions::default();
+        opts.inner
+            .insert(RETRIES_OPTION.to_string(), "many".to_string());
+        retry_config_from_options(&opts).unwrap_err();
+
+        // Invalid options fail building the store.
+        let url = DatasourceUrl::try_new("s3://bucket/path/to/table").unwrap();
+        storage_options_into_object_store(&url, &opts).unwrap_err();
+    }
+}
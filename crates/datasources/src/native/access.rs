@@ -1,7 +1,7 @@
 use crate::native::errors::{NativeError, Result};
 use crate::native::insert::NativeTableInsertExec;
 use async_trait::async_trait;
-use datafusion::arrow::datatypes::{DataType, Schema as ArrowSchema, TimeUnit};
+use datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema, TimeUnit};
 use datafusion::datasource::TableProvider;
 use datafusion::error::Result as DataFusionResult;
 use datafusion::execution::context::SessionState;
@@ -9,13 +9,16 @@ use datafusion::execution::context::SessionState;
 use datafusion::logical_expr::{LogicalPlan, TableProviderFilterPushDown, TableType};
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::{ExecutionPlan, Statistics};
-use datafusion::prelude::Expr;
+use datafusion::prelude::{DataFrame, Expr};
 use datafusion_ext::metrics::ReadOnlyDataSourceMetricsExecAdapter;
 use deltalake::operations::create::CreateBuilder;
 use deltalake::operations::delete::DeleteBuilder;
+use deltalake::operations::merge::MergeBuilder;
+use deltalake::operations::transaction::commit;
 use deltalake::operations::update::UpdateBuilder;
+use deltalake::protocol::{Action, DeltaOperation, MetaData, Protocol};
 use deltalake::storage::DeltaObjectStore;
-use deltalake::{DeltaTable, DeltaTableConfig};
+use deltalake::{DeltaTable, DeltaTableConfig, DeltaTableMetaData, Schema as DeltaSchema};
 use futures::StreamExt;
 use object_store::path::Path as ObjectStorePath;
 use object_store::prefix::PrefixStore;
@@ -27,6 +30,7 @@ use protogen::metastore::types::options::{
 };
 use std::any::Any;
 use std::sync::Arc;
+use tracing::warn;
 use url::Url;
 use uuid::Uuid;
 
@@ -102,14 +106,7 @@ impl NativeTableStorage {
                 .with_save_mode(save_mode);
 
             for col in &opts.columns {
-                let column = match &col.arrow_type {
-                    DataType::Timestamp(_, tz) => InternalColumnDefinition {
-                        name: col.name.clone(),
-                        nullable: col.nullable,
-                        arrow_type: DataType::Timestamp(TimeUnit::Microsecond, tz.clone()),
-                    },
-                    _ => col.to_owned(),
-                };
+                let column = normalize_column(col);
                 builder = builder.with_column(
                     column.name.clone(),
                     (&column.arrow_type).try_into()?,
@@ -204,6 +201,118 @@ impl NativeTableStorage {
         }
     }
 
+    /// Remove all rows from a table, keeping its schema.
+    ///
+    /// This commits a new table version with every data file removed, so
+    /// unlike `delete_rows_where` no data needs to be scanned.
+    pub async fn truncate_table(&self, table_entry: &TableEntry) -> Result<()> {
+        let table = self.load_table(table_entry).await?;
+        DeleteBuilder::new(table.delta.object_store(), table.delta.state).await?;
+        Ok(())
+    }
+
+    /// Replace the schema of an existing table with `columns`, returning the
+    /// table metadata as it was before the change.
+    ///
+    /// Existing data files are left untouched. Columns missing from older
+    /// files are read back as nulls, and columns no longer in the schema are
+    /// ignored on read. Since columns are matched by name, a dropped column
+    /// can't be added back, otherwise the values still in older files would
+    /// reappear.
+    pub async fn update_table_schema(
+        &self,
+        table_entry: &TableEntry,
+        columns: &[InternalColumnDefinition],
+    ) -> Result<DeltaTableMetaData> {
+        let table = self.load_table(table_entry).await?;
+        let previous = table.delta.get_metadata()?.clone();
+
+        let mut dropped = dropped_columns(&previous)?;
+        if let Some(col) = columns.iter().find(|col| dropped.contains(&col.name)) {
+            return Err(NativeError::ColumnPreviouslyDropped(col.name.clone()));
+        }
+        for field in previous.schema.get_fields() {
+            if !columns.iter().any(|col| col.name == *field.get_name()) {
+                dropped.push(field.get_name().to_string());
+            }
+        }
+
+        let fields = columns
+            .iter()
+            .map(|col| {
+                let col = normalize_column(col);
+                Field::new(col.name, col.arrow_type, col.nullable)
+            })
+            .collect::<Vec<_>>();
+
+        let mut metadata = previous.clone();
+        metadata.schema = DeltaSchema::try_from(&ArrowSchema::new(fields))?;
+        metadata.configuration.insert(
+            DROPPED_COLUMNS_CONFIG_KEY.to_string(),
+            Some(serde_json::to_string(&dropped)?),
+        );
+
+        commit_metadata(&table, metadata).await?;
+
+        Ok(previous)
+    }
+
+    /// Replace the schema of an existing table with `columns`, filling every
+    /// existing row of the added `column` with `value`.
+    ///
+    /// Returns the table metadata as it was before the change, see
+    /// `update_table_schema`. The table's schema is restored if filling the
+    /// rows fails.
+    pub async fn update_table_schema_with_backfill(
+        &self,
+        table_entry: &TableEntry,
+        columns: &[InternalColumnDefinition],
+        column: &str,
+        value: Expr,
+    ) -> Result<DeltaTableMetaData> {
+        // Existing rows don't have a value for the column until they're
+        // filled, so the column starts out nullable.
+        let nullable_columns = columns
+            .iter()
+            .map(|col| InternalColumnDefinition {
+                nullable: col.nullable || col.name == column,
+                ..col.clone()
+            })
+            .collect::<Vec<_>>();
+        let previous = self
+            .update_table_schema(table_entry, &nullable_columns)
+            .await?;
+
+        let result = async {
+            self.update_rows_where(table_entry, vec![(column.to_string(), value)], None)
+                .await?;
+            if nullable_columns != columns {
+                self.update_table_schema(table_entry, columns).await?;
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            if let Err(e) = self.restore_table_metadata(table_entry, previous).await {
+                warn!(%e, "failed to restore table schema");
+            }
+            return Err(e);
+        }
+
+        Ok(previous)
+    }
+
+    /// Restore table metadata returned from `update_table_schema`.
+    pub async fn restore_table_metadata(
+        &self,
+        table_entry: &TableEntry,
+        metadata: DeltaTableMetaData,
+    ) -> Result<()> {
+        let table = self.load_table(table_entry).await?;
+        commit_metadata(&table, metadata).await
+    }
+
     pub async fn update_rows_where(
         &self,
         table: &TableEntry,
@@ -221,6 +330,138 @@ impl NativeTableStorage {
         let updated_rows = builder.await?.1.num_updated_rows;
         Ok(updated_rows)
     }
+
+    /// Merge rows from `source` into a table.
+    ///
+    /// Expressions reference the target and source columns qualified by their
+    /// aliases. For each row, the first clause whose predicate matches is
+    /// applied. Returns the total number of rows inserted, updated and
+    /// deleted.
+    pub async fn merge_into(
+        &self,
+        table: &TableEntry,
+        source: DataFrame,
+        target_alias: &str,
+        source_alias: &str,
+        on: Expr,
+        clauses: Vec<MergeIntoClause>,
+    ) -> Result<usize> {
+        let table = self.load_table(table).await?;
+        let mut builder =
+            MergeBuilder::new(table.delta.object_store(), table.delta.state, on, source)
+                .with_target_alias(target_alias)
+                .with_source_alias(source_alias);
+
+        for clause in clauses {
+            builder = match clause {
+                MergeIntoClause::MatchedUpdate {
+                    predicate,
+                    assignments,
+                } => builder.when_matched_update(|mut update| {
+                    for (column, expr) in assignments {
+                        update = update.update(column.as_str(), expr);
+                    }
+                    match predicate {
+                        Some(predicate) => update.predicate(predicate),
+                        None => update,
+                    }
+                })?,
+                MergeIntoClause::MatchedDelete { predicate } => {
+                    builder.when_matched_delete(|delete| match predicate {
+                        Some(predicate) => delete.predicate(predicate),
+                        None => delete,
+                    })?
+                }
+                MergeIntoClause::NotMatchedInsert { predicate, values } => builder
+                    .when_not_matched_insert(|mut insert| {
+                        for (column, expr) in values {
+                            insert = insert.set(column.as_str(), expr);
+                        }
+                        match predicate {
+                            Some(predicate) => insert.predicate(predicate),
+                            None => insert,
+                        }
+                    })?,
+            };
+        }
+
+        let metrics = builder.await?.1;
+        Ok(metrics.num_target_rows_inserted
+            + metrics.num_target_rows_updated
+            + metrics.num_target_rows_deleted)
+    }
+}
+
+/// A WHEN clause of a merge.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeIntoClause {
+    /// Update matched target rows with the given `(column, expr)` assignments.
+    MatchedUpdate {
+        predicate: Option<Expr>,
+        assignments: Vec<(String, Expr)>,
+    },
+    /// Delete matched target rows.
+    MatchedDelete { predicate: Option<Expr> },
+    /// Insert source rows that didn't match, setting each column to the
+    /// paired expression.
+    NotMatchedInsert {
+        predicate: Option<Expr>,
+        values: Vec<(String, Expr)>,
+    },
+}
+
+/// Table configuration key holding the names of columns dropped from the
+/// table, encoded as a json array.
+const DROPPED_COLUMNS_CONFIG_KEY: &str = "glaredb.droppedColumns";
+
+fn dropped_columns(metadata: &DeltaTableMetaData) -> Result<Vec<String>> {
+    match metadata.configuration.get(DROPPED_COLUMNS_CONFIG_KEY) {
+        Some(Some(names)) => Ok(serde_json::from_str(names)?),
+        _ => Ok(Vec::new()),
+    }
+}
+
+async fn commit_metadata(table: &NativeTable, metadata: DeltaTableMetaData) -> Result<()> {
+    let state = &table.delta.state;
+    let protocol = Protocol {
+        min_reader_version: state.min_reader_version(),
+        min_writer_version: state.min_writer_version(),
+        reader_features: state.reader_features().cloned(),
+        writer_features: state.writer_features().cloned(),
+    };
+
+    let actions = vec![Action::metaData(MetaData::try_from(metadata.clone())?)];
+    // There's no operation for only changing a table's schema, so record it
+    // as replacing the table's metadata. No data files are added or removed.
+    let operation = DeltaOperation::Create {
+        mode: SaveMode::Overwrite,
+        location: table.delta.table_uri(),
+        protocol,
+        metadata,
+    };
+
+    commit(
+        table.delta.object_store().as_ref(),
+        &actions,
+        operation,
+        state,
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Delta only supports microsecond timestamps, normalize the column to match.
+fn normalize_column(col: &InternalColumnDefinition) -> InternalColumnDefinition {
+    match &col.arrow_type {
+        DataType::Timestamp(_, tz) => InternalColumnDefinition {
+            name: col.name.clone(),
+            nullable: col.nullable,
+            arrow_type: DataType::Timestamp(TimeUnit::Microsecond, tz.clone()),
+        },
+        _ => col.to_owned(),
+    }
 }
 
 #[derive(Debug)]
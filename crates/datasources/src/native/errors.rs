@@ -18,6 +18,12 @@ pub enum NativeError {
     #[error(transparent)]
     UrlParse(#[from] url::ParseError),
 
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+
+    #[error("Column '{0}' was previously dropped from the table and can't be added back")]
+    ColumnPreviouslyDropped(String),
+
     #[error("Table entry not a native table: {0}")]
     NotNative(protogen::metastore::types::catalog::TableEntry),
 
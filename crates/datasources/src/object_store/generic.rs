@@ -2,7 +2,7 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use crate::common::url::DatasourceUrl;
-use crate::lake::storage_options_into_object_store;
+use crate::lake::cached_object_store;
 use crate::object_store::errors::ObjectStoreSourceError;
 use datafusion::execution::object_store::ObjectStoreUrl;
 use object_store::path::Path as ObjectStorePath;
@@ -54,7 +54,7 @@ impl ObjStoreAccess for GenericStoreAccess {
     fn create_store(&self) -> Result<Arc<dyn ObjectStore>> {
         let datasource_url = DatasourceUrl::try_new(&self.base_url)
             .map_err(|_| ObjectStoreSourceError::Static("Couldn't parse base url"))?;
-        let store = storage_options_into_object_store(&datasource_url, &self.storage_options)
+        let store = cached_object_store(&datasource_url, &self.storage_options, false)
             .map_err(|_| ObjectStoreSourceError::Static("Couldn't create a object store"))?;
         Ok(store)
     }
@@ -69,6 +69,25 @@ pub struct ServerArgs {
     #[clap(long, hide = true, value_parser)]
     pub disable_rpc_auth: bool,
 
+    /// Allow table functions to fall back to the server's own cloud
+    /// credentials (environment, profiles, instance metadata) when a query
+    /// doesn't provide any.
+    ///
+    /// Only enable this if everyone connecting to the server may use those
+    /// credentials.
+    #[clap(long, value_parser)]
+    pub allow_ambient_credentials: bool,
+
+    /// Reject statements that modify the database.
+    #[clap(long, value_parser)]
+    pub read_only: bool,
+
+    /// Max number of rows a single query may return.
+    ///
+    /// Queries returning more rows than this fail. Unlimited if not set.
+    #[clap(long, value_parser)]
+    pub max_result_rows: Option<usize>,
+
     /// API key for segment.
     ///
     /// (Internal)
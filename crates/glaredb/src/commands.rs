@@ -89,6 +89,9 @@ impl RunCommand for ServerArgs {
             spill_path,
             ignore_pg_auth,
             disable_rpc_auth,
+            allow_ambient_credentials,
+            read_only,
+            max_result_rows,
             segment_key,
         } = self;
 
@@ -120,6 +123,9 @@ impl RunCommand for ServerArgs {
                 spill_path,
                 /* integration_testing = */ false,
                 disable_rpc_auth,
+                allow_ambient_credentials,
+                read_only,
+                max_result_rows,
             )
             .await?;
             server.serve(conf).await
@@ -12,6 +12,7 @@ use datafusion::arrow::json::writer::{
 };
 use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::physical_plan::SendableRecordBatchStream;
+use datafusion::variable::VarType;
 use futures::StreamExt;
 use pgrepr::format::Format;
 use reedline::{FileBackedHistory, Reedline, Signal};
@@ -97,8 +98,11 @@ impl LocalSession {
 
             sess
         } else {
+            // Running locally, so the user's own cloud credentials may be
+            // used by table functions.
+            let vars = SessionVars::default().with_allow_ambient_credentials(true, VarType::System);
             engine
-                .new_local_session_context(SessionVars::default(), SessionStorageConfig::default())
+                .new_local_session_context(vars, SessionStorageConfig::default())
                 .await?
         };
 
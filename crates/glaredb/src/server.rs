@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use metastore::util::MetastoreClientMode;
+use metastore::util::{MetastoreClientMode, MetastoreKeepalive};
 use pgsrv::auth::LocalAuthenticator;
 use pgsrv::handler::{ProtocolHandler, ProtocolHandlerConfig};
 use protogen::gen::rpcsrv::service::execution_service_server::ExecutionServiceServer;
@@ -50,6 +50,9 @@ impl ComputeServer {
         spill_path: Option<PathBuf>,
         integration_testing: bool,
         disable_rpc_auth: bool,
+        allow_ambient_credentials: bool,
+        read_only: bool,
+        max_result_rows: Option<usize>,
     ) -> Result<Self> {
         // Our bare container image doesn't have a '/tmp' dir on startup (nor
         // does it specify an alternate dir to use via `TMPDIR`).
@@ -85,7 +88,10 @@ impl ComputeServer {
                         "Only one of metastore address or metastore path may be provided."
                     ))
                 }
-                (Some(addr), None) => MetastoreClientMode::Remote { addr },
+                (Some(addr), None) => MetastoreClientMode::Remote {
+                    addr,
+                    keepalive: MetastoreKeepalive::default(),
+                },
                 _ => MetastoreClientMode::new_local(data_dir.clone()),
             };
             let metastore_client = mode.into_client().await?;
@@ -130,6 +136,9 @@ impl ComputeServer {
             // between the proxy and GlareDB.
             ssl_conf: None,
             integration_testing,
+            allow_ambient_credentials,
+            read_only,
+            max_result_rows,
         };
         Ok(ComputeServer {
             integration_testing,
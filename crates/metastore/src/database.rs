@@ -9,7 +9,7 @@ use protogen::metastore::types::catalog::{
     TableEntry, TunnelEntry, ViewEntry,
 };
 use protogen::metastore::types::options::{
-    DatabaseOptions, DatabaseOptionsInternal, TableOptions, TunnelOptions,
+    DatabaseOptions, DatabaseOptionsInternal, InternalColumnDefinition, TableOptions, TunnelOptions,
 };
 use protogen::metastore::types::service::{AlterDatabaseOperation, AlterTableOperation, Mutation};
 use protogen::metastore::types::storage::{ExtraState, PersistedCatalog};
@@ -153,6 +153,48 @@ impl DatabaseCatalog {
         Ok(updated)
     }
 
+    /// Export the latest persisted catalog, excluding builtins.
+    pub async fn export(&self) -> Result<PersistedCatalog> {
+        self.load_latest().await?;
+
+        let state = self.cached.lock().await;
+        Ok(state.to_persisted())
+    }
+
+    /// Replace the catalog with a previously exported one.
+    ///
+    /// The imported catalog is written as the next version of the current
+    /// catalog, preserving monotonic versions for existing clients.
+    pub async fn import(&self, mut catalog: PersistedCatalog) -> Result<CatalogState> {
+        debug!(db_id = %self.db_id, from_version = %catalog.state.version, "importing catalog");
+
+        self.load_latest().await?;
+
+        let mut state = self.cached.lock().await;
+        let old_version = state.version;
+        catalog.state.version = old_version + 1;
+
+        // Builds name maps and validates the imported entries.
+        let mut imported = State::from_persisted(catalog)?;
+        // Clients may still hold oids handed out by the current catalog, never
+        // reuse them.
+        imported.oid_counter = imported.oid_counter.max(state.oid_counter);
+        let persist = imported.to_persisted();
+
+        if let Err(e) = self
+            .storage
+            .write_catalog(self.db_id, old_version, persist)
+            .await
+        {
+            self.require_full_load.store(true, Ordering::Relaxed);
+            return Err(e.into());
+        }
+
+        *state = imported;
+
+        Ok(self.serializable_state(state))
+    }
+
     /// Return the serializable state of the catalog at this version.
     fn serializable_state(&self, guard: MutexGuard<State>) -> CatalogState {
         CatalogState {
@@ -790,11 +832,7 @@ impl State {
                     columns: create_view.columns,
                 };
 
-                let policy = if create_view.or_replace {
-                    CreatePolicy::CreateOrReplace
-                } else {
-                    CreatePolicy::Create
-                };
+                let policy = CreatePolicy::new(create_view.if_not_exists, create_view.or_replace)?;
 
                 self.try_insert_table_namespace(CatalogEntry::View(ent), schema_id, oid, policy)?;
             }
@@ -885,7 +923,7 @@ impl State {
                 match alter_table.operation {
                     AlterTableOperation::RenameTable { new_name } => {
                         validate_object_name(&new_name)?;
-                        if self.schema_names.contains_key(&new_name) {
+                        if objs.tables.contains_key(&new_name) {
                             return Err(MetastoreError::DuplicateName(new_name));
                         }
 
@@ -931,6 +969,44 @@ impl State {
                             other => unreachable!("unexpected entry type: {:?}", other),
                         };
                     }
+                    AlterTableOperation::AddColumn { column } => {
+                        validate_object_name(&column.name)?;
+                        let columns = Self::native_table_columns_mut(
+                            &mut self.entries,
+                            objs,
+                            &alter_table.schema,
+                            &alter_table.name,
+                        )?;
+
+                        if columns.iter().any(|col| col.name == column.name) {
+                            return Err(MetastoreError::DuplicateName(column.name));
+                        }
+                        columns.push(column);
+                    }
+                    AlterTableOperation::DropColumn { name, if_exists } => {
+                        let columns = Self::native_table_columns_mut(
+                            &mut self.entries,
+                            objs,
+                            &alter_table.schema,
+                            &alter_table.name,
+                        )?;
+
+                        match columns.iter().position(|col| col.name == name) {
+                            Some(_) if columns.len() == 1 => {
+                                return Err(MetastoreError::CannotDropLastColumn(alter_table.name))
+                            }
+                            Some(idx) => {
+                                columns.remove(idx);
+                            }
+                            None if if_exists => (),
+                            None => {
+                                return Err(MetastoreError::MissingColumn {
+                                    table: alter_table.name,
+                                    column: name,
+                                })
+                            }
+                        }
+                    }
                 };
             }
             Mutation::AlterDatabase(alter_database) => {
@@ -1004,6 +1080,35 @@ impl State {
         Ok(())
     }
 
+    /// Get a mutable reference to the column definitions of a native table.
+    ///
+    /// Errors if the table doesn't exist or isn't a native table.
+    fn native_table_columns_mut<'a>(
+        entries: &'a mut DatabaseEntries,
+        objs: &SchemaObjects,
+        schema: &str,
+        name: &str,
+    ) -> Result<&'a mut Vec<InternalColumnDefinition>> {
+        let oid = match objs.tables.get(name) {
+            None => {
+                return Err(MetastoreError::MissingNamedObject {
+                    schema: schema.to_string(),
+                    name: name.to_string(),
+                })
+            }
+            Some(id) => id,
+        };
+
+        match entries.get_mut(oid)?.unwrap() {
+            CatalogEntry::Table(TableEntry {
+                options: TableOptions::Internal(opts),
+                ..
+            }) => Ok(&mut opts.columns),
+            CatalogEntry::Table(_) => Err(MetastoreError::TableNotNative(name.to_string())),
+            other => unreachable!("unexpected entry type: {:?}", other),
+        }
+    }
+
     /// Try to insert an entry for a schema within the "table" namespace.
     ///
     /// Errors depending on the create policy.
@@ -1331,13 +1436,17 @@ impl BuiltinCatalog {
 mod tests {
     use super::*;
     use crate::storage::persist::Storage;
+    use datafusion::arrow::datatypes::DataType;
     use object_store::memory::InMemory;
     use protogen::metastore::types::options::DatabaseOptionsDebug;
     use protogen::metastore::types::options::TableOptionsDebug;
+    use protogen::metastore::types::options::TableOptionsInternal;
     use protogen::metastore::types::service::AlterDatabase;
+    use protogen::metastore::types::service::AlterTable;
     use protogen::metastore::types::service::DropDatabase;
     use protogen::metastore::types::service::{
-        CreateExternalDatabase, CreateExternalTable, CreateSchema, CreateView, DropSchema,
+        CreateExternalDatabase, CreateExternalTable, CreateSchema, CreateTable, CreateView,
+        DropSchema,
     };
     use sqlbuiltins::builtins::DEFAULT_CATALOG;
     use std::collections::HashSet;
@@ -1414,6 +1523,7 @@ mod tests {
                     sql: format!("select {i}"),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })
             })
             .collect();
@@ -1434,6 +1544,43 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn import_keeps_oid_counter() {
+        let db = new_catalog().await;
+        let exported = db.export().await.unwrap();
+
+        db.try_mutate(
+            version(&db).await,
+            vec![Mutation::CreateSchema(CreateSchema {
+                name: "before_import".to_string(),
+                if_not_exists: false,
+            })],
+        )
+        .await
+        .unwrap();
+        let used = db.get_state().await.unwrap().entries;
+
+        // Importing the older catalog must not hand out oids used since.
+        db.import(exported).await.unwrap();
+        db.try_mutate(
+            version(&db).await,
+            vec![Mutation::CreateSchema(CreateSchema {
+                name: "after_import".to_string(),
+                if_not_exists: false,
+            })],
+        )
+        .await
+        .unwrap();
+
+        let state = db.get_state().await.unwrap();
+        let (oid, _) = state
+            .entries
+            .iter()
+            .find(|(_, ent)| ent.get_meta().name == "after_import")
+            .unwrap();
+        assert!(!used.contains_key(oid));
+    }
+
     #[tokio::test]
     async fn duplicate_schema_names() {
         let db = new_catalog().await;
@@ -1508,6 +1655,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })],
             )
             .await
@@ -1550,6 +1698,7 @@ mod tests {
                 sql: "select 1".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                if_not_exists: false,
             })],
         )
         .await
@@ -1564,6 +1713,7 @@ mod tests {
                 sql: "select 2".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                if_not_exists: false,
             })],
         )
         .await
@@ -1583,6 +1733,7 @@ mod tests {
                 sql: "select 1".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                if_not_exists: false,
             })],
         )
         .await
@@ -1597,6 +1748,7 @@ mod tests {
                 sql: "select 2".to_string(),
                 or_replace: false,
                 columns: Vec::new(),
+                if_not_exists: false,
             })],
         )
         .await
@@ -1611,12 +1763,209 @@ mod tests {
                 sql: "select 3".to_string(),
                 or_replace: true,
                 columns: Vec::new(),
+                if_not_exists: false,
             })],
         )
         .await
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn rename_table() {
+        let db = new_catalog().await;
+
+        let create_table = |name: &str| {
+            Mutation::CreateExternalTable(CreateExternalTable {
+                schema: "public".to_string(),
+                name: name.to_string(),
+                options: TableOptions::Debug(TableOptionsDebug {
+                    table_type: String::new(),
+                }),
+                if_not_exists: false,
+                or_replace: false,
+                tunnel: None,
+            })
+        };
+        let rename_table = |name: &str, new_name: &str| {
+            Mutation::AlterTable(AlterTable {
+                schema: "public".to_string(),
+                name: name.to_string(),
+                operation: AlterTableOperation::RenameTable {
+                    new_name: new_name.to_string(),
+                },
+            })
+        };
+
+        db.try_mutate(
+            version(&db).await,
+            vec![create_table("goomba"), create_table("koopa")],
+        )
+        .await
+        .unwrap();
+
+        // Can't rename to an existing table.
+        db.try_mutate(version(&db).await, vec![rename_table("goomba", "koopa")])
+            .await
+            .unwrap_err();
+
+        // Names only need to be unique within a schema's tables.
+        let state = db
+            .try_mutate(version(&db).await, vec![rename_table("goomba", "public")])
+            .await
+            .unwrap();
+
+        let names: HashSet<_> = state
+            .entries
+            .values()
+            .filter_map(|ent| match ent {
+                CatalogEntry::Table(table) if !table.meta.builtin => Some(table.meta.name.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            HashSet::from(["public".to_string(), "koopa".to_string()]),
+            names
+        );
+    }
+
+    #[tokio::test]
+    async fn alter_table_columns() {
+        let db = new_catalog().await;
+
+        let alter_table = |operation: AlterTableOperation| {
+            Mutation::AlterTable(AlterTable {
+                schema: "public".to_string(),
+                name: "bowser".to_string(),
+                operation,
+            })
+        };
+        let add_column = |name: &str| {
+            alter_table(AlterTableOperation::AddColumn {
+                column: InternalColumnDefinition {
+                    name: name.to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Int64,
+                },
+            })
+        };
+        let drop_column = |name: &str, if_exists: bool| {
+            alter_table(AlterTableOperation::DropColumn {
+                name: name.to_string(),
+                if_exists,
+            })
+        };
+        let column_names = |state: &CatalogState| -> Vec<String> {
+            state
+                .entries
+                .values()
+                .find_map(|ent| match ent {
+                    CatalogEntry::Table(TableEntry {
+                        meta,
+                        options: TableOptions::Internal(opts),
+                        ..
+                    }) if meta.name == "bowser" => {
+                        Some(opts.columns.iter().map(|c| c.name.clone()).collect())
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+
+        db.try_mutate(
+            version(&db).await,
+            vec![Mutation::CreateTable(CreateTable {
+                schema: "public".to_string(),
+                name: "bowser".to_string(),
+                options: TableOptionsInternal {
+                    columns: InternalColumnDefinition::from_tuples([("a", DataType::Utf8, true)]),
+                },
+                if_not_exists: false,
+                or_replace: false,
+            })],
+        )
+        .await
+        .unwrap();
+
+        let state = db
+            .try_mutate(version(&db).await, vec![add_column("b")])
+            .await
+            .unwrap();
+        assert_eq!(vec!["a", "b"], column_names(&state));
+
+        // Column names must be unique.
+        db.try_mutate(version(&db).await, vec![add_column("a")])
+            .await
+            .unwrap_err();
+
+        let state = db
+            .try_mutate(version(&db).await, vec![drop_column("a", false)])
+            .await
+            .unwrap();
+        assert_eq!(vec!["b"], column_names(&state));
+
+        // Missing columns only error without IF EXISTS.
+        db.try_mutate(version(&db).await, vec![drop_column("a", false)])
+            .await
+            .unwrap_err();
+        db.try_mutate(version(&db).await, vec![drop_column("a", true)])
+            .await
+            .unwrap();
+
+        // Can't leave a table without columns.
+        db.try_mutate(version(&db).await, vec![drop_column("b", false)])
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn view_if_not_exists() {
+        let db = new_catalog().await;
+
+        let create_view = |sql: &str, if_not_exists: bool, or_replace: bool| {
+            Mutation::CreateView(CreateView {
+                schema: "public".to_string(),
+                name: "waluigi".to_string(),
+                sql: sql.to_string(),
+                or_replace,
+                columns: Vec::new(),
+                if_not_exists,
+            })
+        };
+
+        db.try_mutate(
+            version(&db).await,
+            vec![create_view("select 1", false, false)],
+        )
+        .await
+        .unwrap();
+
+        // Existing view is kept.
+        let state = db
+            .try_mutate(
+                version(&db).await,
+                vec![create_view("select 2", true, false)],
+            )
+            .await
+            .unwrap();
+        let sql = state
+            .entries
+            .values()
+            .find_map(|ent| match ent {
+                CatalogEntry::View(view) if view.meta.name == "waluigi" => Some(view.sql.clone()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!("select 1", sql);
+
+        // Can't specify both.
+        db.try_mutate(
+            version(&db).await,
+            vec![create_view("select 3", true, true)],
+        )
+        .await
+        .unwrap_err();
+    }
+
     #[tokio::test]
     async fn duplicate_names_no_persist_failures() {
         // <https://github.com/GlareDB/glaredb/issues/577>
@@ -1641,6 +1990,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })],
             )
             .await
@@ -1656,6 +2006,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })],
             )
             .await
@@ -1671,6 +2022,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })],
             )
             .await
@@ -26,6 +26,9 @@ pub enum MetastoreError {
     #[error("Missing database: {0}")]
     MissingDatabase(String),
 
+    #[error("No metastore route for database: {0}")]
+    MissingRoute(uuid::Uuid),
+
     #[error("Missing tunnel: {0}")]
     MissingTunnel(String),
 
@@ -91,8 +94,26 @@ pub enum MetastoreError {
     #[error(transparent)]
     TonicTransportError(#[from] tonic::transport::Error),
 
+    #[error(transparent)]
+    TonicStatus(#[from] tonic::Status),
+
+    #[error("Invalid catalog snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    #[error("Unsupported catalog snapshot format version: {have}, expected: {expected}")]
+    UnsupportedSnapshotVersion { have: u32, expected: u32 },
+
     #[error("Cannot specify both 'IF NOT EXISTS' and 'OR REPLACE'")]
     InvalidCreatePolicy,
+
+    #[error("Cannot alter columns of non-native table: {0}")]
+    TableNotNative(String),
+
+    #[error("Missing column '{column}' in table '{table}'")]
+    MissingColumn { table: String, column: String },
+
+    #[error("Cannot drop the only column of table '{0}'")]
+    CannotDropLastColumn(String),
 }
 
 pub type Result<T, E = MetastoreError> = std::result::Result<T, E>;
@@ -1,6 +1,8 @@
 //! The metastore crate defines the service for managing database catalogs.
 pub mod errors;
 pub mod local;
+pub mod routing;
+pub mod snapshot;
 pub mod srv;
 
 mod database;
@@ -15,6 +15,18 @@ pub async fn start_inprocess_inmemory() -> Result<MetastoreServiceClient<Channel
     start_inprocess(Arc::new(InMemory::new())).await
 }
 
+/// Starts an in-process, in-memory metastore, returning the backing object
+/// store alongside the client.
+///
+/// The store can be used to inspect what the metastore has persisted.
+pub async fn start_inprocess_inmemory_with_store(
+) -> Result<(MetastoreServiceClient<Channel>, Arc<dyn ObjectStore>)> {
+    info!("Starting in-memory metastore");
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    let client = start_inprocess(store.clone()).await?;
+    Ok((client, store))
+}
+
 /// Starts an in-process, local persistent metastore.
 pub async fn start_inprocess_local(
     path: impl AsRef<Path>,
@@ -64,3 +76,75 @@ pub async fn start_inprocess(
 
     Ok(MetastoreServiceClient::new(channel))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use object_store::path::Path as ObjectPath;
+    use protogen::gen::metastore::service::{FetchCatalogRequest, MutateRequest};
+    use protogen::metastore::types::service::{CreateSchema, Mutation};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn persisted_catalog_objects() {
+        let (mut client, store) = start_inprocess_inmemory_with_store().await.unwrap();
+        let db_id = Uuid::new_v4();
+
+        let version = client
+            .fetch_catalog(FetchCatalogRequest {
+                db_id: db_id.into_bytes().to_vec(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .catalog
+            .unwrap()
+            .version;
+
+        client
+            .mutate_catalog(MutateRequest {
+                db_id: db_id.into_bytes().to_vec(),
+                catalog_version: version,
+                mutations: vec![Mutation::CreateSchema(CreateSchema {
+                    name: "persisted".to_string(),
+                    if_not_exists: false,
+                })
+                .try_into()
+                .unwrap()],
+            })
+            .await
+            .unwrap();
+
+        let prefix = ObjectPath::from(format!("databases/{db_id}/visible"));
+        let mut names: Vec<_> = store
+            .list(Some(&prefix))
+            .await
+            .unwrap()
+            .map_ok(|meta| meta.location.filename().unwrap().to_string())
+            .try_collect()
+            .await
+            .unwrap();
+        names.sort();
+
+        // Initial catalog, the catalog after the mutation, and the metadata
+        // pointing to the latest version.
+        assert!(names.contains(&"catalog.0".to_string()), "{names:?}");
+        assert!(
+            names.contains(&format!("catalog.{}", version + 1)),
+            "{names:?}"
+        );
+        assert!(names.contains(&"metadata".to_string()), "{names:?}");
+
+        // Nothing should be left in the temp directory.
+        let tmp = ObjectPath::from(format!("databases/{db_id}/tmp"));
+        let tmp_objects: Vec<_> = store
+            .list(Some(&tmp))
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(tmp_objects.is_empty(), "{tmp_objects:?}");
+    }
+}
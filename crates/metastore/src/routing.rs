@@ -0,0 +1,162 @@
+use crate::errors::{MetastoreError, Result};
+use crate::util::{remote_endpoint, MetastoreKeepalive};
+use protogen::gen::metastore::service::metastore_service_client::MetastoreServiceClient;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tonic::transport::Channel;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Routes metastore requests for different databases to different metastore
+/// endpoints.
+///
+/// Each database id is mapped to an endpoint, and a single channel is shared
+/// by all databases routed to the same endpoint. Channels to endpoints are
+/// created lazily on first use.
+#[derive(Debug)]
+pub struct RoutingMetastoreClient {
+    /// Database id to endpoint address.
+    routes: HashMap<Uuid, String>,
+    /// Keepalive settings used when connecting to an endpoint.
+    keepalive: MetastoreKeepalive,
+    /// Clients keyed by endpoint address.
+    clients: Mutex<HashMap<String, MetastoreServiceClient<Channel>>>,
+}
+
+impl RoutingMetastoreClient {
+    /// Create a new router from a map of database ids to endpoint addresses.
+    pub fn new(routes: HashMap<Uuid, String>) -> Self {
+        RoutingMetastoreClient {
+            routes,
+            keepalive: MetastoreKeepalive::default(),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_keepalive(mut self, keepalive: MetastoreKeepalive) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    /// Use an already connected client for an endpoint instead of creating a
+    /// new channel.
+    ///
+    /// Useful for routing to in-process metastores.
+    pub fn with_client(
+        self,
+        endpoint: impl Into<String>,
+        client: MetastoreServiceClient<Channel>,
+    ) -> Self {
+        self.clients.lock().unwrap().insert(endpoint.into(), client);
+        self
+    }
+
+    /// Get the client for the metastore that's responsible for the given
+    /// database.
+    ///
+    /// Errors if there's no route for the database.
+    pub fn client_for(&self, db_id: &Uuid) -> Result<MetastoreServiceClient<Channel>> {
+        let endpoint = self
+            .routes
+            .get(db_id)
+            .ok_or(MetastoreError::MissingRoute(*db_id))?;
+
+        let mut clients = self.clients.lock().unwrap();
+        if let Some(client) = clients.get(endpoint) {
+            return Ok(client.clone());
+        }
+
+        debug!(%endpoint, %db_id, "creating channel for metastore endpoint");
+        let channel = remote_endpoint(endpoint.clone(), self.keepalive)?.connect_lazy();
+        let client = MetastoreServiceClient::new(channel);
+        clients.insert(endpoint.clone(), client.clone());
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local::start_inprocess_inmemory;
+    use protogen::gen::metastore::service::{FetchCatalogRequest, MutateRequest};
+    use protogen::metastore::types::catalog::CatalogState;
+    use protogen::metastore::types::service::{CreateSchema, Mutation};
+
+    async fn schema_names(
+        client: &mut MetastoreServiceClient<Channel>,
+        db_id: Uuid,
+    ) -> Vec<String> {
+        let resp = client
+            .fetch_catalog(FetchCatalogRequest {
+                db_id: db_id.into_bytes().to_vec(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        let state: CatalogState = resp.catalog.unwrap().try_into().unwrap();
+
+        state
+            .entries
+            .into_values()
+            .filter(|ent| !ent.get_meta().builtin)
+            .map(|ent| ent.get_meta().name.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn routes_to_endpoints() {
+        let db1 = Uuid::new_v4();
+        let db2 = Uuid::new_v4();
+
+        let mut first = start_inprocess_inmemory().await.unwrap();
+        let mut second = start_inprocess_inmemory().await.unwrap();
+
+        let router = RoutingMetastoreClient::new(HashMap::from([
+            (db1, "first".to_string()),
+            (db2, "second".to_string()),
+        ]))
+        .with_client("first", first.clone())
+        .with_client("second", second.clone());
+
+        // Create a schema in each database through the router.
+        for (db_id, schema) in [(db1, "one"), (db2, "two")] {
+            let mut client = router.client_for(&db_id).unwrap();
+            let version = client
+                .fetch_catalog(FetchCatalogRequest {
+                    db_id: db_id.into_bytes().to_vec(),
+                })
+                .await
+                .unwrap()
+                .into_inner()
+                .catalog
+                .unwrap()
+                .version;
+            client
+                .mutate_catalog(MutateRequest {
+                    db_id: db_id.into_bytes().to_vec(),
+                    catalog_version: version,
+                    mutations: vec![Mutation::CreateSchema(CreateSchema {
+                        name: schema.to_string(),
+                        if_not_exists: false,
+                    })
+                    .try_into()
+                    .unwrap()],
+                })
+                .await
+                .unwrap();
+        }
+
+        // Each mutation should only have landed on the routed metastore.
+        assert_eq!(vec!["one".to_string()], schema_names(&mut first, db1).await);
+        assert!(schema_names(&mut second, db1).await.is_empty());
+        assert_eq!(
+            vec!["two".to_string()],
+            schema_names(&mut second, db2).await
+        );
+        assert!(schema_names(&mut first, db2).await.is_empty());
+
+        // Missing routes error.
+        router.client_for(&Uuid::new_v4()).unwrap_err();
+    }
+}
@@ -1,12 +1,14 @@
 use crate::database::DatabaseCatalog;
 use crate::errors::MetastoreError;
+use crate::snapshot::{decode_snapshot, encode_snapshot};
 use crate::storage::persist::Storage;
 use async_trait::async_trait;
 use dashmap::DashMap;
 use object_store::ObjectStore;
 use protogen::gen::metastore::service::metastore_service_server::MetastoreService;
 use protogen::gen::metastore::service::{
-    self, FetchCatalogRequest, FetchCatalogResponse, MutateRequest, MutateResponse,
+    self, ExportCatalogRequest, ExportCatalogResponse, FetchCatalogRequest, FetchCatalogResponse,
+    ImportCatalogRequest, ImportCatalogResponse, MutateRequest, MutateResponse,
 };
 use protogen::metastore::types::service::Mutation;
 use std::sync::Arc;
@@ -110,12 +112,48 @@ impl MetastoreService for Service {
             catalog: Some(updated.try_into().map_err(MetastoreError::from)?),
         }))
     }
+
+    async fn export_catalog(
+        &self,
+        request: Request<ExportCatalogRequest>,
+    ) -> Result<Response<ExportCatalogResponse>, Status> {
+        let req = request.into_inner();
+        debug!(?req, "export catalog");
+        let id = Uuid::from_slice(&req.db_id)
+            .map_err(|_| MetastoreError::InvalidDatabaseId(req.db_id))?;
+
+        let catalog = self.get_or_load_catalog(id).await?;
+        let persisted = catalog.export().await?;
+
+        Ok(Response::new(ExportCatalogResponse {
+            snapshot: encode_snapshot(persisted)?,
+        }))
+    }
+
+    async fn import_catalog(
+        &self,
+        request: Request<ImportCatalogRequest>,
+    ) -> Result<Response<ImportCatalogResponse>, Status> {
+        let req = request.into_inner();
+        info!(db_id = ?req.db_id, snapshot_len = %req.snapshot.len(), "import catalog");
+        let id = Uuid::from_slice(&req.db_id)
+            .map_err(|_| MetastoreError::InvalidDatabaseId(req.db_id))?;
+
+        let persisted = decode_snapshot(&req.snapshot)?;
+        let catalog = self.get_or_load_catalog(id).await?;
+        let updated = catalog.import(persisted).await?;
+
+        Ok(Response::new(ImportCatalogResponse {
+            catalog: Some(updated.try_into().map_err(MetastoreError::from)?),
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use object_store::memory::InMemory;
+    use protogen::metastore::strategy::{ResolveErrorStrategy, RESOLVE_ERROR_STRATEGY_META};
     use protogen::metastore::types::catalog::{CatalogEntry, CatalogState};
     use protogen::metastore::types::service::{CreateSchema, Mutation};
 
@@ -181,4 +219,91 @@ mod tests {
             .unwrap();
         assert!(matches!(ent, CatalogEntry::Schema(_)));
     }
+
+    #[tokio::test]
+    async fn racing_mutations_conflict() {
+        let svc = Arc::new(new_service());
+        let id_bs = Uuid::new_v4().into_bytes().to_vec();
+
+        let version = svc
+            .fetch_catalog(Request::new(FetchCatalogRequest {
+                db_id: id_bs.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .catalog
+            .unwrap()
+            .version;
+
+        // Both clients have witnessed the same catalog version.
+        let create_schema = |name: &str| {
+            let svc = svc.clone();
+            let req = MutateRequest {
+                db_id: id_bs.clone(),
+                catalog_version: version,
+                mutations: vec![Mutation::CreateSchema(CreateSchema {
+                    name: name.to_string(),
+                    if_not_exists: false,
+                })
+                .try_into()
+                .unwrap()],
+            };
+            async move { svc.mutate_catalog(Request::new(req)).await }
+        };
+
+        let (a, b) = tokio::join!(
+            tokio::spawn(create_schema("schema_a")),
+            tokio::spawn(create_schema("schema_b")),
+        );
+        let results = [a.unwrap(), b.unwrap()];
+
+        // Exactly one should be applied, the other rejected with a hint to
+        // refetch and retry.
+        assert_eq!(1, results.iter().filter(|r| r.is_ok()).count());
+        let status = results.into_iter().find_map(|r| r.err()).unwrap();
+        let strat = status
+            .metadata()
+            .get(RESOLVE_ERROR_STRATEGY_META)
+            .map(|v| ResolveErrorStrategy::from_bytes(v.as_bytes()))
+            .unwrap();
+        assert_eq!(ResolveErrorStrategy::FetchCatalogAndRetry, strat);
+
+        // Retrying against the refreshed catalog succeeds.
+        let state: CatalogState = svc
+            .fetch_catalog(Request::new(FetchCatalogRequest {
+                db_id: id_bs.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .catalog
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(version + 1, state.version);
+
+        let missing = ["schema_a", "schema_b"]
+            .into_iter()
+            .find(|name| {
+                !state
+                    .entries
+                    .values()
+                    .any(|ent| ent.get_meta().name == *name)
+            })
+            .unwrap();
+
+        svc.mutate_catalog(Request::new(MutateRequest {
+            db_id: id_bs,
+            catalog_version: state.version,
+            mutations: vec![Mutation::CreateSchema(CreateSchema {
+                name: missing.to_string(),
+                if_not_exists: false,
+            })
+            .try_into()
+            .unwrap()],
+        }))
+        .await
+        .unwrap();
+    }
 }
@@ -6,11 +6,46 @@ use std::{fs, time::Duration};
 use tonic::transport::{Channel, Endpoint};
 use tracing::info;
 
+/// HTTP/2 keepalive settings for connections to a remote metastore.
+///
+/// Pings keep long-idle connections from being silently dropped by load
+/// balancers, and let the client notice a dead connection and reconnect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetastoreKeepalive {
+    /// Interval between HTTP/2 pings.
+    pub interval: Duration,
+    /// How long to wait for a ping to be acknowledged before the connection
+    /// is closed.
+    pub timeout: Duration,
+}
+
+impl Default for MetastoreKeepalive {
+    fn default() -> Self {
+        MetastoreKeepalive {
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(20),
+        }
+    }
+}
+
+/// Create an endpoint for connecting to a remote metastore.
+pub(crate) fn remote_endpoint(addr: String, keepalive: MetastoreKeepalive) -> Result<Endpoint> {
+    Ok(Endpoint::new(addr)?
+        .tcp_keepalive(Some(Duration::from_secs(600)))
+        .tcp_nodelay(true)
+        .http2_keep_alive_interval(keepalive.interval)
+        .keep_alive_timeout(keepalive.timeout)
+        .keep_alive_while_idle(true))
+}
+
 /// Determine how to connect to metastore.
 #[derive(Debug)]
 pub enum MetastoreClientMode {
     /// Connect to a remote metastore.
-    Remote { addr: String },
+    Remote {
+        addr: String,
+        keepalive: MetastoreKeepalive,
+    },
     /// Start an in process metastore backed by files at some path.
     LocalDisk { path: PathBuf },
     /// Start an in process metastore that persists nothing.
@@ -28,14 +63,9 @@ impl MetastoreClientMode {
     /// Create a new metastore client.
     pub async fn into_client(self) -> Result<MetastoreServiceClient<Channel>> {
         match self {
-            MetastoreClientMode::Remote { addr } => {
-                info!(%addr, "connecting to remote metastore");
-                let channel = Endpoint::new(addr)?
-                    .tcp_keepalive(Some(Duration::from_secs(600)))
-                    .tcp_nodelay(true)
-                    .keep_alive_while_idle(true)
-                    .connect()
-                    .await?;
+            MetastoreClientMode::Remote { addr, keepalive } => {
+                info!(%addr, ?keepalive, "connecting to remote metastore");
+                let channel = remote_endpoint(addr, keepalive)?.connect().await?;
                 Ok(MetastoreServiceClient::new(channel))
             }
             Self::LocalDisk { path } => {
@@ -59,3 +89,73 @@ impl MetastoreClientMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    /// HTTP/2 PING frame type.
+    const PING_FRAME: u8 = 0x6;
+
+    #[tokio::test]
+    async fn remote_endpoint_keepalive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let keepalive = MetastoreKeepalive {
+            interval: Duration::from_millis(200),
+            timeout: Duration::from_millis(200),
+        };
+        let endpoint = remote_endpoint(format!("http://{addr}"), keepalive).unwrap();
+
+        let start = Instant::now();
+        let (channel, accepted) = tokio::join!(endpoint.connect(), listener.accept());
+        let _channel = channel.unwrap();
+        let (mut socket, _) = accepted.unwrap();
+
+        let mut preface = [0; 24];
+        socket.read_exact(&mut preface).await.unwrap();
+
+        // Read frames until the client pings the idle connection. The ping is
+        // never acknowledged.
+        let read_ping = async {
+            loop {
+                let mut header = [0; 9];
+                socket.read_exact(&mut header).await.unwrap();
+                let len = u32::from_be_bytes([0, header[0], header[1], header[2]]) as usize;
+                let mut payload = vec![0; len];
+                socket.read_exact(&mut payload).await.unwrap();
+                if header[3] == PING_FRAME {
+                    break;
+                }
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(10), read_ping)
+            .await
+            .expect("client should ping the idle connection");
+        let pinged = start.elapsed();
+        assert!(
+            pinged >= keepalive.interval,
+            "pinged after {pinged:?}, interval is {:?}",
+            keepalive.interval
+        );
+
+        // Without an acknowledgement, the client closes the connection once
+        // the timeout passes.
+        let mut rest = Vec::new();
+        tokio::time::timeout(Duration::from_secs(10), socket.read_to_end(&mut rest))
+            .await
+            .expect("client should close the connection after the keepalive timeout")
+            .ok();
+        let closed = start.elapsed();
+        assert!(
+            closed >= pinged + keepalive.timeout / 2,
+            "closed {:?} after the ping, timeout is {:?}",
+            closed - pinged,
+            keepalive.timeout
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use object_store::{
+    path::Path, GetOptions, GetResult, ListResult, MultipartId, ObjectMeta, ObjectStore, Result,
+};
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
+
+/// Running totals of the data passing through a `CountingObjectStore`.
+#[derive(Debug, Default)]
+pub struct ObjectStoreCounts {
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+    objects_written: AtomicU64,
+}
+
+impl ObjectStoreCounts {
+    /// Bytes returned from reads so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read.load(Ordering::Relaxed)
+    }
+
+    /// Bytes accepted by writes so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Number of objects that have been started being written so far.
+    pub fn objects_written(&self) -> u64 {
+        self.objects_written.load(Ordering::Relaxed)
+    }
+
+    fn add_read(&self, bytes: usize) {
+        self.bytes_read.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn add_written(&self, bytes: usize) {
+        self.bytes_written
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    fn add_object(&self) {
+        self.objects_written.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Object store wrapper that counts the bytes read and written, and the
+/// objects written through it.
+///
+/// Counts are shared, so the same counts may be used across multiple stores.
+#[derive(Debug, Clone)]
+pub struct CountingObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    counts: Arc<ObjectStoreCounts>,
+}
+
+impl CountingObjectStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, counts: Arc<ObjectStoreCounts>) -> Self {
+        CountingObjectStore { inner, counts }
+    }
+}
+
+impl fmt::Display for CountingObjectStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CountingObjectStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for CountingObjectStore {
+    async fn put(&self, location: &Path, bytes: Bytes) -> Result<()> {
+        let len = bytes.len();
+        self.inner.put(location, bytes).await?;
+        self.counts.add_object();
+        self.counts.add_written(len);
+        Ok(())
+    }
+
+    async fn put_multipart(
+        &self,
+        location: &Path,
+    ) -> Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
+        let (id, inner) = self.inner.put_multipart(location).await?;
+        self.counts.add_object();
+        let writer = CountingWriter {
+            inner,
+            counts: self.counts.clone(),
+        };
+        Ok((id, Box::new(writer)))
+    }
+
+    async fn abort_multipart(&self, location: &Path, multipart_id: &MultipartId) -> Result<()> {
+        self.inner.abort_multipart(location, multipart_id).await
+    }
+
+    async fn get(&self, location: &Path) -> Result<GetResult> {
+        let result = self.inner.get(location).await?;
+        self.counts.add_read(result.range.len());
+        Ok(result)
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        let result = self.inner.get_opts(location, options).await?;
+        self.counts.add_read(result.range.len());
+        Ok(result)
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> Result<Bytes> {
+        let bytes = self.inner.get_range(location, range).await?;
+        self.counts.add_read(bytes.len());
+        Ok(bytes)
+    }
+
+    async fn get_ranges(&self, location: &Path, ranges: &[Range<usize>]) -> Result<Vec<Bytes>> {
+        let ranges = self.inner.get_ranges(location, ranges).await?;
+        self.counts
+            .add_read(ranges.iter().map(|bytes| bytes.len()).sum());
+        Ok(ranges)
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.inner.delete(location).await
+    }
+
+    async fn list(&self, prefix: Option<&Path>) -> Result<BoxStream<'_, Result<ObjectMeta>>> {
+        self.inner.list(prefix).await
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.rename(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.rename_if_not_exists(from, to).await
+    }
+}
+
+/// Writer for multipart uploads that counts the bytes accepted by the inner
+/// writer.
+struct CountingWriter {
+    inner: Box<dyn AsyncWrite + Unpin + Send>,
+    counts: Arc<ObjectStoreCounts>,
+}
+
+impl AsyncWrite for CountingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = &poll {
+            self.counts.add_written(*n);
+        }
+        poll
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use tokio::io::AsyncWriteExt;
+
+    #[tokio::test]
+    async fn counts_reads_and_writes() {
+        let counts = Arc::new(ObjectStoreCounts::default());
+        let store = CountingObjectStore::new(Arc::new(InMemory::new()), counts.clone());
+
+        store
+            .put(&Path::from("a"), Bytes::from_static(b"hello"))
+            .await
+            .unwrap();
+
+        let (_id, mut writer) = store.put_multipart(&Path::from("b")).await.unwrap();
+        writer.write_all(b"hello ").await.unwrap();
+        writer.write_all(b"world").await.unwrap();
+        writer.shutdown().await.unwrap();
+
+        assert_eq!(2, counts.objects_written());
+        assert_eq!(16, counts.bytes_written());
+        assert_eq!(0, counts.bytes_read());
+
+        store.get(&Path::from("b")).await.unwrap();
+        store.get_range(&Path::from("a"), 1..3).await.unwrap();
+
+        assert_eq!(13, counts.bytes_read());
+    }
+}
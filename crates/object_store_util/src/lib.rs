@@ -1,4 +1,6 @@
 //! Utilities for the object store crate.
 pub mod conf;
+pub mod counting;
+pub mod retry;
 pub mod shared;
 pub mod temp;
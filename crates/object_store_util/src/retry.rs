@@ -0,0 +1,77 @@
+//! Helpers for inspecting failed object store requests, e.g. to decide if a
+//! request might succeed when tried again.
+//!
+//! Retrying failed requests is left to the object stores themselves (see
+//! `object_store::RetryConfig`).
+use reqwest::StatusCode;
+
+/// Get the HTTP status of the failed request that caused an error, if any.
+pub fn http_status(err: &(dyn std::error::Error + 'static)) -> Option<StatusCode> {
+    error_chain(err)
+        .filter_map(|e| e.downcast_ref::<reqwest::Error>())
+        .find_map(|e| e.status())
+}
+
+/// Check if an error was caused by a request timing out.
+pub fn is_timeout(err: &(dyn std::error::Error + 'static)) -> bool {
+    error_chain(err).any(|e| {
+        e.downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_timeout())
+            || e.downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == std::io::ErrorKind::TimedOut)
+    })
+}
+
+fn error_chain<'a>(
+    err: &'a (dyn std::error::Error + 'static),
+) -> impl Iterator<Item = &'a (dyn std::error::Error + 'static)> {
+    std::iter::successors(Some(err), |e| e.source())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::Error as ObjectStoreError;
+
+    /// Error for a request failing with the given HTTP status.
+    fn http_error(status: u16) -> ObjectStoreError {
+        let resp = http::Response::builder().status(status).body("").unwrap();
+        let err = reqwest::Response::from(resp)
+            .error_for_status()
+            .unwrap_err();
+        ObjectStoreError::Generic {
+            store: "test",
+            source: Box::new(err),
+        }
+    }
+
+    #[test]
+    fn status_found_in_error_chain() {
+        assert_eq!(
+            Some(StatusCode::SERVICE_UNAVAILABLE),
+            http_status(&http_error(503))
+        );
+        assert_eq!(Some(StatusCode::FORBIDDEN), http_status(&http_error(403)));
+
+        // Errors without a status.
+        let other = ObjectStoreError::Generic {
+            store: "test",
+            source: "something went wrong".into(),
+        };
+        assert_eq!(None, http_status(&other));
+        assert!(!is_timeout(&other));
+    }
+
+    #[test]
+    fn timeouts_found_in_error_chain() {
+        let timed_out = ObjectStoreError::Generic {
+            store: "test",
+            source: Box::new(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out",
+            )),
+        };
+        assert!(is_timeout(&timed_out));
+        assert!(!is_timeout(&http_error(500)));
+    }
+}
@@ -3,7 +3,7 @@ use crate::codec::server::{FramedConn, PgCodec};
 use crate::errors::{PgSrvError, Result};
 use crate::messages::{
     BackendMessage, DescribeObjectType, ErrorResponse, FieldDescriptionBuilder, FrontendMessage,
-    SqlState, StartupMessage, TransactionStatus,
+    NoticeResponse, SqlState, StartupMessage, TransactionStatus,
 };
 use crate::proxy::{
     ProxyKey, GLAREDB_DATABASE_ID_KEY, GLAREDB_GCS_STORAGE_BUCKET_KEY,
@@ -21,6 +21,8 @@ use pgrepr::format::Format;
 use pgrepr::scalar::Scalar;
 use sqlexec::context::local::{OutputFields, Portal, PreparedStatement};
 use sqlexec::engine::SessionStorageConfig;
+use sqlexec::errors::ExecError;
+use sqlexec::notice::Notice;
 use sqlexec::{
     engine::Engine,
     parser::{self, StatementWithExtensions},
@@ -31,6 +33,7 @@ use std::collections::VecDeque;
 use std::ops::DerefMut;
 use std::sync::Arc;
 use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
 use tokio_postgres::types::Type as PgType;
 use tracing::{debug, debug_span, warn, Instrument};
 use uuid::Uuid;
@@ -43,6 +46,14 @@ pub struct ProtocolHandlerConfig {
     /// If the server should be configured for integration tests. This is only
     /// applicable for local databases.
     pub integration_testing: bool,
+    /// If table functions may fall back to the server's own cloud credentials
+    /// (environment, profiles, instance metadata) when a query doesn't
+    /// provide any.
+    pub allow_ambient_credentials: bool,
+    /// If sessions should reject statements that modify the database.
+    pub read_only: bool,
+    /// Max number of rows a single query may return. Unlimited if not set.
+    pub max_result_rows: Option<usize>,
 }
 
 /// A wrapper around a SQL engine that implements the Postgres frontend/backend
@@ -250,7 +261,12 @@ impl ProtocolHandler {
             .with_memory_limit_bytes(memory_limit_bytes, VarType::System)
             .with_max_tunnel_count(max_tunnel_count, VarType::System)
             .with_max_credentials_count(max_credentials_count, VarType::System)
-            .with_is_cloud_instance(is_cloud_instance, VarType::System);
+            .with_is_cloud_instance(is_cloud_instance, VarType::System)
+            .with_allow_ambient_credentials(self.conf.allow_ambient_credentials, VarType::System)
+            .with_read_only(self.conf.read_only, VarType::System);
+        if let Some(max_result_rows) = self.conf.max_result_rows {
+            vars = vars.with_max_result_rows(max_result_rows, VarType::System);
+        }
 
         // Set other params provided on startup. Note that these are all set as
         // the "user" since these include values set in options.
@@ -319,6 +335,8 @@ impl ProtocolHandler {
 struct ClientSession<C, S> {
     conn: FramedConn<C>,
     session: S,
+    /// Notices raised by the session during execution.
+    notices: mpsc::Receiver<Notice>,
 }
 
 /// This helper macro is used so we can call some `get_*` methods on the
@@ -344,7 +362,12 @@ where
     S: DerefMut<Target = Session>,
 {
     fn new(session: S, conn: FramedConn<C>) -> Self {
-        ClientSession { session, conn }
+        let notices = session.subscribe_notices();
+        ClientSession {
+            session,
+            conn,
+            notices,
+        }
     }
 
     async fn run(mut self) -> Result<()> {
@@ -441,6 +464,7 @@ where
     async fn query(&mut self, sql: String) -> Result<()> {
         let session = &mut self.session;
         let conn = &mut self.conn;
+        let notices = &mut self.notices;
 
         let stmts = match parse_sql(session.get_session_vars(), &sql) {
             Ok(stmts) => stmts,
@@ -490,7 +514,7 @@ where
             }
 
             // Execute...
-            let stream = match session.execute_portal(&UNNAMED, 0).await {
+            let stream = match Self::execute_portal(conn, notices, session, &UNNAMED, 0).await? {
                 Ok(stream) => stream,
                 Err(e) => {
                     self.send_error(e.into()).await?;
@@ -651,7 +675,8 @@ where
 
         let conn = &mut self.conn;
         let session = &mut self.session;
-        let stream = match session.execute_portal(&portal, max_rows).await {
+        let notices = &mut self.notices;
+        let stream = match Self::execute_portal(conn, notices, session, &portal, max_rows).await? {
             Ok(r) => r,
             Err(e) => return self.send_error(e.into()).await,
         };
@@ -684,6 +709,44 @@ where
         Ok(())
     }
 
+    /// Execute a portal, sending notices raised during execution to the
+    /// client.
+    ///
+    /// Only errors related to the underlying connection are returned in the
+    /// outer result.
+    async fn execute_portal(
+        conn: &mut FramedConn<C>,
+        notices: &mut mpsc::Receiver<Notice>,
+        session: &mut S,
+        portal: &str,
+        max_rows: i32,
+    ) -> Result<Result<ExecutionResult, ExecError>> {
+        let result = {
+            let exec = session.execute_portal(portal, max_rows);
+            tokio::pin!(exec);
+            loop {
+                tokio::select! {
+                    result = &mut exec => break result,
+                    Some(notice) = notices.recv() => Self::send_notice(conn, notice).await?,
+                }
+            }
+        };
+
+        // Notices raised right before execution completed.
+        while let Ok(notice) = notices.try_recv() {
+            Self::send_notice(conn, notice).await?;
+        }
+
+        Ok(result)
+    }
+
+    async fn send_notice(conn: &mut FramedConn<C>, notice: Notice) -> Result<()> {
+        conn.send(NoticeResponse::info(notice.message).into())
+            .await?;
+        conn.flush().await?;
+        Ok(())
+    }
+
     async fn send_result(
         conn: &mut FramedConn<C>,
         stream: ExecutionResult,
@@ -712,6 +775,9 @@ where
             ExecutionResult::UpdateSuccess { updated_rows } => {
                 Self::command_complete(conn, format!("UPDATE {}", updated_rows)).await?
             }
+            ExecutionResult::MergeSuccess { merged_rows } => {
+                Self::command_complete(conn, format!("MERGE {}", merged_rows)).await?
+            }
             ExecutionResult::CreateTable => Self::command_complete(conn, "CREATE TABLE").await?,
             ExecutionResult::CreateDatabase => {
                 Self::command_complete(conn, "CREATE DATABASE").await?
@@ -738,6 +804,9 @@ where
             ExecutionResult::DropCredentials => {
                 Self::command_complete(conn, "DROP CREDENTIALS").await?
             }
+            ExecutionResult::TruncateTables => {
+                Self::command_complete(conn, "TRUNCATE TABLE").await?
+            }
         };
         Ok(())
     }
@@ -1,6 +1,7 @@
 use super::catalog::SourceAccessMode;
 use super::options::{
-    CredentialsOptions, DatabaseOptions, TableOptions, TableOptionsInternal, TunnelOptions,
+    CredentialsOptions, DatabaseOptions, InternalColumnDefinition, TableOptions,
+    TableOptionsInternal, TunnelOptions,
 };
 use crate::gen::metastore::service;
 use crate::{FromOptionalField, ProtoConvError};
@@ -86,7 +87,7 @@ impl TryFrom<Mutation> for service::mutation::Mutation {
             Mutation::CreateExternalDatabase(v) => {
                 service::mutation::Mutation::CreateExternalDatabase(v.into())
             }
-            Mutation::AlterTable(v) => service::mutation::Mutation::AlterTable(v.into()),
+            Mutation::AlterTable(v) => service::mutation::Mutation::AlterTable(v.try_into()?),
             Mutation::AlterDatabase(v) => service::mutation::Mutation::AlterDatabase(v.into()),
             Mutation::CreateTunnel(v) => service::mutation::Mutation::CreateTunnel(v.into()),
             Mutation::DropTunnel(v) => service::mutation::Mutation::DropTunnel(v.into()),
@@ -229,6 +230,7 @@ pub struct CreateView {
     pub sql: String,
     pub or_replace: bool,
     pub columns: Vec<String>,
+    pub if_not_exists: bool,
 }
 
 impl TryFrom<service::CreateView> for CreateView {
@@ -241,6 +243,7 @@ impl TryFrom<service::CreateView> for CreateView {
             sql: value.sql,
             or_replace: value.or_replace,
             columns: value.columns,
+            if_not_exists: value.if_not_exists,
         })
     }
 }
@@ -253,6 +256,7 @@ impl From<CreateView> for service::CreateView {
             sql: value.sql,
             or_replace: value.or_replace,
             columns: value.columns,
+            if_not_exists: value.if_not_exists,
         }
     }
 }
@@ -367,6 +371,8 @@ impl From<CreateExternalDatabase> for service::CreateExternalDatabase {
 pub enum AlterTableOperation {
     RenameTable { new_name: String },
     SetAccessMode { access_mode: SourceAccessMode },
+    AddColumn { column: InternalColumnDefinition },
+    DropColumn { name: String, if_exists: bool },
 }
 
 impl TryFrom<service::alter_table_operation::Operation> for AlterTableOperation {
@@ -381,13 +387,22 @@ impl TryFrom<service::alter_table_operation::Operation> for AlterTableOperation
             ) => Self::SetAccessMode {
                 access_mode: access_mode.try_into()?,
             },
+            service::alter_table_operation::Operation::AlterTableOperationAddColumn(
+                service::AlterTableOperationAddColumn { column },
+            ) => Self::AddColumn {
+                column: column.required("column")?,
+            },
+            service::alter_table_operation::Operation::AlterTableOperationDropColumn(
+                service::AlterTableOperationDropColumn { name, if_exists },
+            ) => Self::DropColumn { name, if_exists },
         })
     }
 }
 
-impl From<AlterTableOperation> for service::alter_table_operation::Operation {
-    fn from(value: AlterTableOperation) -> Self {
-        match value {
+impl TryFrom<AlterTableOperation> for service::alter_table_operation::Operation {
+    type Error = ProtoConvError;
+    fn try_from(value: AlterTableOperation) -> Result<Self, Self::Error> {
+        Ok(match value {
             AlterTableOperation::RenameTable { new_name } => {
                 service::alter_table_operation::Operation::AlterTableOperationRename(
                     service::AlterTableOperationRename { new_name },
@@ -400,7 +415,19 @@ impl From<AlterTableOperation> for service::alter_table_operation::Operation {
                     },
                 )
             }
-        }
+            AlterTableOperation::AddColumn { column } => {
+                service::alter_table_operation::Operation::AlterTableOperationAddColumn(
+                    service::AlterTableOperationAddColumn {
+                        column: Some(column.try_into()?),
+                    },
+                )
+            }
+            AlterTableOperation::DropColumn { name, if_exists } => {
+                service::alter_table_operation::Operation::AlterTableOperationDropColumn(
+                    service::AlterTableOperationDropColumn { name, if_exists },
+                )
+            }
+        })
     }
 }
 
@@ -411,11 +438,12 @@ impl TryFrom<service::AlterTableOperation> for AlterTableOperation {
     }
 }
 
-impl From<AlterTableOperation> for service::AlterTableOperation {
-    fn from(value: AlterTableOperation) -> Self {
-        Self {
-            operation: Some(value.into()),
-        }
+impl TryFrom<AlterTableOperation> for service::AlterTableOperation {
+    type Error = ProtoConvError;
+    fn try_from(value: AlterTableOperation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operation: Some(value.try_into()?),
+        })
     }
 }
 
@@ -437,13 +465,14 @@ impl TryFrom<service::AlterTable> for AlterTable {
     }
 }
 
-impl From<AlterTable> for service::AlterTable {
-    fn from(value: AlterTable) -> Self {
-        service::AlterTable {
+impl TryFrom<AlterTable> for service::AlterTable {
+    type Error = ProtoConvError;
+    fn try_from(value: AlterTable) -> Result<Self, Self::Error> {
+        Ok(service::AlterTable {
             schema: value.schema,
             name: value.name,
-            operation: Some(value.operation.into()),
-        }
+            operation: Some(value.operation.try_into()?),
+        })
     }
 }
 
@@ -1,7 +1,8 @@
 mod copy_to;
 use crate::{
     gen::metastore::{
-        options::TableOptions,
+        catalog::TableEntry,
+        options::{StorageOptions, TableOptions},
         service::{
             AlterDatabase, AlterTable, AlterTunnelRotateKeys, CreateCredentials,
             CreateExternalDatabase, CreateTunnel,
@@ -12,7 +13,7 @@ use crate::{
 };
 
 pub use copy_to::*;
-use datafusion_proto::protobuf::{DfSchema, LogicalPlanNode};
+use datafusion_proto::protobuf::{DfSchema, LogicalExprNode, LogicalPlanNode};
 use prost::{Message, Oneof};
 
 #[derive(Clone, PartialEq, Message)]
@@ -99,6 +100,8 @@ pub struct CreateView {
     pub columns: Vec<String>,
     #[prost(bool, tag = "4")]
     pub or_replace: bool,
+    #[prost(bool, tag = "5")]
+    pub if_not_exists: bool,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -125,6 +128,12 @@ pub struct DropViews {
     pub if_exists: bool,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct TruncateTables {
+    #[prost(message, repeated, tag = "1")]
+    pub references: Vec<FullObjectReference>,
+}
+
 #[derive(Clone, PartialEq, Message)]
 pub struct SetVariable {
     #[prost(string, tag = "1")]
@@ -148,12 +157,86 @@ pub struct Delete {}
 #[derive(Clone, PartialEq, Message)]
 pub struct Insert {}
 
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeInto {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(message, optional, tag = "2")]
+    pub source: Option<LogicalPlanNode>,
+    #[prost(string, tag = "3")]
+    pub target_alias: String,
+    #[prost(string, tag = "4")]
+    pub source_alias: String,
+    #[prost(message, optional, tag = "5")]
+    pub on: Option<LogicalExprNode>,
+    #[prost(message, repeated, tag = "6")]
+    pub clauses: Vec<MergeIntoClause>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoAssignment {
+    #[prost(string, tag = "1")]
+    pub column: String,
+    #[prost(message, optional, tag = "2")]
+    pub expr: Option<LogicalExprNode>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoClause {
+    #[prost(oneof = "MergeIntoClauseType", tags = "1, 2, 3")]
+    pub clause: Option<MergeIntoClauseType>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum MergeIntoClauseType {
+    #[prost(message, tag = "1")]
+    MatchedUpdate(MergeIntoMatchedUpdate),
+    #[prost(message, tag = "2")]
+    MatchedDelete(MergeIntoMatchedDelete),
+    #[prost(message, tag = "3")]
+    NotMatchedInsert(MergeIntoNotMatchedInsert),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoMatchedUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub predicate: Option<LogicalExprNode>,
+    #[prost(message, repeated, tag = "2")]
+    pub assignments: Vec<MergeIntoAssignment>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoMatchedDelete {
+    #[prost(message, optional, tag = "1")]
+    pub predicate: Option<LogicalExprNode>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoNotMatchedInsert {
+    #[prost(message, optional, tag = "1")]
+    pub predicate: Option<LogicalExprNode>,
+    #[prost(message, repeated, tag = "2")]
+    pub values: Vec<MergeIntoAssignment>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CopyFrom {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(string, tag = "2")]
+    pub location: String,
+    #[prost(string, tag = "3")]
+    pub file_type: String,
+    #[prost(message, tag = "4")]
+    pub storage_options: Option<StorageOptions>,
+}
+
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, Message)]
 pub struct LogicalPlanExtension {
     #[prost(
         oneof = "LogicalPlanExtensionType",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22"
     )]
     pub inner: Option<LogicalPlanExtensionType>,
 }
@@ -200,4 +283,11 @@ pub enum LogicalPlanExtensionType {
     SetVariable(SetVariable),
     #[prost(message, tag = "19")]
     CopyTo(CopyTo),
+    #[prost(message, tag = "20")]
+    TruncateTables(TruncateTables),
+    // DML
+    #[prost(message, tag = "21")]
+    MergeInto(MergeInto),
+    #[prost(message, tag = "22")]
+    CopyFrom(CopyFrom),
 }
@@ -1,13 +1,13 @@
 mod postgres;
 pub use postgres::*;
 
-use crate::gen::metastore::catalog::TableEntry;
+use crate::gen::metastore::{catalog::TableEntry, options::StorageOptions};
 use datafusion_proto::protobuf::{LogicalExprNode, Schema};
 use prost::{Message, Oneof};
 
 use super::{
     common::{FullObjectReference, FullSchemaReference},
-    logical_plan::{CopyToDestinationOptions, CopyToFormatOptions},
+    logical_plan::{CopyToDestinationOptions, CopyToFormatOptions, MergeIntoClause},
 };
 
 #[derive(Clone, PartialEq, Message)]
@@ -78,6 +78,8 @@ pub struct AlterTableExec {
     pub name: String,
     #[prost(message, tag = "4")]
     pub operation: Option<crate::gen::metastore::service::AlterTableOperation>,
+    #[prost(message, optional, tag = "5")]
+    pub backfill: Option<LogicalExprNode>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -216,6 +218,8 @@ pub struct CreateViewExec {
     pub columns: Vec<String>,
     #[prost(bool, tag = "5")]
     pub or_replace: bool,
+    #[prost(bool, tag = "6")]
+    pub if_not_exists: bool,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -278,6 +282,38 @@ pub struct DeleteExec {
     pub where_expr: Option<LogicalExprNode>,
 }
 
+#[derive(Clone, PartialEq, Message)]
+pub struct MergeIntoExec {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(string, tag = "2")]
+    pub target_alias: String,
+    #[prost(string, tag = "3")]
+    pub source_alias: String,
+    #[prost(message, optional, tag = "4")]
+    pub on: Option<LogicalExprNode>,
+    #[prost(message, repeated, tag = "5")]
+    pub clauses: Vec<MergeIntoClause>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CopyFromExec {
+    #[prost(message, tag = "1")]
+    pub table: Option<TableEntry>,
+    #[prost(string, tag = "2")]
+    pub location: String,
+    #[prost(string, tag = "3")]
+    pub file_type: String,
+    #[prost(message, tag = "4")]
+    pub storage_options: Option<StorageOptions>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct TruncateTablesExec {
+    #[prost(message, repeated, tag = "1")]
+    pub tables: Vec<TableEntry>,
+}
+
 #[derive(Clone, PartialEq, Message)]
 pub struct InsertExec {
     #[prost(bytes, tag = "1")]
@@ -326,7 +362,7 @@ pub struct AnalyzeExec {
 pub struct ExecutionPlanExtension {
     #[prost(
         oneof = "ExecutionPlanExtensionType",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34"
     )]
     pub inner: Option<ExecutionPlanExtensionType>,
 }
@@ -400,4 +436,10 @@ pub enum ExecutionPlanExtensionType {
     DataSourceMetricsExecAdapter(DataSourceMetricsExecAdapter),
     #[prost(message, tag = "31")]
     DescribeTable(DescribeTableExec),
+    #[prost(message, tag = "32")]
+    TruncateTablesExec(TruncateTablesExec),
+    #[prost(message, tag = "33")]
+    MergeIntoExec(MergeIntoExec),
+    #[prost(message, tag = "34")]
+    CopyFromExec(CopyFromExec),
 }
@@ -22,6 +22,10 @@ impl TableFunc for ReadBigQuery {
     fn name(&self) -> &str {
         "read_bigquery"
     }
+
+    fn description(&self) -> &str {
+        "Read a BigQuery table."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             4,
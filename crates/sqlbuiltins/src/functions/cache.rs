@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use datafusion::datasource::TableProvider;
+use datafusion::logical_expr::Signature;
+use datafusion_ext::errors::Result;
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, TableFuncParams,
+};
+use protogen::metastore::types::catalog::RuntimePreference;
+use tracing::debug;
+
+/// Wrapper around a table function that caches the providers it creates.
+///
+/// Providers are keyed on the session and function arguments, and reused
+/// until the TTL expires, skipping any work done in `create_provider`. This
+/// should only be used for functions producing small, slowly changing results
+/// (e.g. metadata functions returning a `MemTable`) where slightly stale
+/// results are acceptable.
+pub struct CachedTableFunc {
+    inner: Arc<dyn TableFunc>,
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+struct CacheEntry {
+    created_at: Instant,
+    provider: Arc<dyn TableProvider>,
+}
+
+impl CachedTableFunc {
+    pub fn new(inner: Arc<dyn TableFunc>, ttl: Duration) -> Self {
+        CachedTableFunc {
+            inner,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Build the cache key for a set of arguments.
+    ///
+    /// Providers are never shared across sessions. Credentials referenced by
+    /// the arguments are resolved so that replacing a credentials object with
+    /// different secrets doesn't reuse a provider built with the old ones.
+    /// Named arguments are sorted so that ordering doesn't matter.
+    fn cache_key(
+        ctx: &dyn TableFuncContextProvider,
+        args: &[FuncParamValue],
+        opts: &HashMap<String, FuncParamValue>,
+    ) -> String {
+        let session = ctx.get_session_vars().connection_id();
+        let creds: Vec<_> = args
+            .iter()
+            .filter_map(|arg| match arg {
+                FuncParamValue::Ident(name) => ctx.get_credentials_entry(name),
+                _ => None,
+            })
+            .map(|ent| &ent.options)
+            .collect();
+        let opts: BTreeMap<_, _> = opts.iter().collect();
+        format!("{session}{args:?}{opts:?}{creds:?}")
+    }
+}
+
+#[async_trait]
+impl TableFunc for CachedTableFunc {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn runtime_preference(&self) -> RuntimePreference {
+        self.inner.runtime_preference()
+    }
+
+    fn detect_runtime(
+        &self,
+        args: &[FuncParamValue],
+        parent: RuntimePreference,
+    ) -> Result<RuntimePreference> {
+        self.inner.detect_runtime(args, parent)
+    }
+
+    async fn create_provider(
+        &self,
+        ctx: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let key = Self::cache_key(ctx, &args, &opts);
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.retain(|_, ent| ent.created_at.elapsed() < self.ttl);
+            if let Some(ent) = entries.get(&key) {
+                debug!(func = %self.name(), "using cached table function provider");
+                return Ok(ent.provider.clone());
+            }
+        }
+
+        let provider = self.inner.create_provider(ctx, args, opts).await?;
+
+        self.entries.lock().unwrap().insert(
+            key,
+            CacheEntry {
+                created_at: Instant::now(),
+                provider: provider.clone(),
+            },
+        );
+
+        Ok(provider)
+    }
+
+    fn signature(&self) -> Option<Signature> {
+        self.inner.signature()
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        self.inner.params()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::iceberg::IcebergSnapshots;
+    use crate::functions::testutil::{copy_iceberg_test_table, utf8, TestContext};
+    use datafusion::variable::VarType;
+    use datafusion_ext::vars::SessionVars;
+    use uuid::Uuid;
+
+    fn session(conn_id: Uuid) -> TestContext {
+        TestContext::with_vars(SessionVars::default().with_connection_id(conn_id, VarType::System))
+    }
+
+    #[tokio::test]
+    async fn cached_iceberg_snapshots() {
+        let dir = copy_iceberg_test_table("lineitem_simple");
+        let args = || vec![utf8(&dir.path().to_string_lossy())];
+        let ctx = session(Uuid::new_v4());
+
+        let cached = CachedTableFunc::new(Arc::new(IcebergSnapshots), Duration::from_secs(60));
+        let uncached = CachedTableFunc::new(Arc::new(IcebergSnapshots), Duration::ZERO);
+
+        let first = cached
+            .create_provider(&ctx, args(), HashMap::new())
+            .await
+            .unwrap();
+        uncached
+            .create_provider(&ctx, args(), HashMap::new())
+            .await
+            .unwrap();
+
+        // Remove the table. The cached function should not need to touch
+        // storage again.
+        std::fs::remove_dir_all(dir.path()).unwrap();
+
+        let second = cached
+            .create_provider(&ctx, args(), HashMap::new())
+            .await
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // Providers aren't shared with other sessions.
+        cached
+            .create_provider(&session(Uuid::new_v4()), args(), HashMap::new())
+            .await
+            .unwrap_err();
+
+        // Expired entries are recomputed.
+        uncached
+            .create_provider(&ctx, args(), HashMap::new())
+            .await
+            .unwrap_err();
+    }
+
+    #[test]
+    fn cache_key_ignores_option_order() {
+        let ctx = session(Uuid::new_v4());
+        let args = vec![FuncParamValue::Ident("a".to_string())];
+
+        let mut opts1 = HashMap::new();
+        opts1.insert("x".to_string(), utf8("1"));
+        opts1.insert("y".to_string(), utf8("2"));
+        let mut opts2 = HashMap::new();
+        opts2.insert("y".to_string(), utf8("2"));
+        opts2.insert("x".to_string(), utf8("1"));
+
+        assert_eq!(
+            CachedTableFunc::cache_key(&ctx, &args, &opts1),
+            CachedTableFunc::cache_key(&ctx, &args, &opts2)
+        );
+        assert_ne!(
+            CachedTableFunc::cache_key(&ctx, &args, &opts1),
+            CachedTableFunc::cache_key(&ctx, &args, &HashMap::new())
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::functions::table_location_and_opts;
+use crate::functions::{table_location_and_opts, LOCATION_PARAMS};
 use async_trait::async_trait;
 use datafusion::datasource::TableProvider;
 use datafusion_ext::errors::{ExtensionError, Result};
-use datafusion_ext::functions::{FuncParamValue, TableFunc, TableFuncContextProvider};
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, TableFuncParams,
+};
 use datasources::lake::delta::access::load_table_direct;
 use protogen::metastore::types::catalog::RuntimePreference;
 
@@ -31,6 +33,14 @@ impl TableFunc for DeltaScan {
         "delta_scan"
     }
 
+    fn description(&self) -> &str {
+        "Scan a Delta Lake table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
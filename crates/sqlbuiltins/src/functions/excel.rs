@@ -4,13 +4,15 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use datafusion::datasource::TableProvider;
 use datafusion_ext::errors::{ExtensionError, Result};
-use datafusion_ext::functions::{FuncParamValue, TableFunc, TableFuncContextProvider};
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, TableFuncParams,
+};
 use datasources::common::url::DatasourceUrl;
 use datasources::excel::read_excel_impl;
 use ioutil::resolve_path;
 use protogen::metastore::types::catalog::RuntimePreference;
 
-use super::table_location_and_opts;
+use super::{table_location_and_opts, LOCATION_PARAMS};
 
 #[derive(Debug, Clone, Copy)]
 pub struct ExcelScan;
@@ -25,6 +27,14 @@ impl TableFunc for ExcelScan {
         "read_excel"
     }
 
+    fn description(&self) -> &str {
+        "Read a sheet from an Excel file."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
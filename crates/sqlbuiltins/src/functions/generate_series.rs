@@ -47,6 +47,10 @@ impl TableFunc for GenerateSeries {
         "generate_series"
     }
 
+    fn description(&self) -> &str {
+        "Generate a series of values from start to stop, with an optional step."
+    }
+
     async fn create_provider(
         &self,
         _: &dyn TableFuncContextProvider,
@@ -1,17 +1,34 @@
 use std::collections::HashMap;
+use std::io;
 use std::sync::Arc;
 
-use crate::functions::table_location_and_opts;
+use crate::functions::{table_location_and_opts, LOCATION_PARAMS};
 use async_trait::async_trait;
-use datafusion::arrow::array::{Int32Builder, Int64Builder, StringBuilder, UInt64Builder};
+use chrono::{Duration, Utc};
+use datafusion::arrow::array::{
+    Int32Builder, Int64Array, Int64Builder, StringBuilder, UInt64Builder,
+};
 use datafusion::arrow::datatypes::{DataType, Field, Schema};
 use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::streaming::StreamingTable;
 use datafusion::datasource::{MemTable, TableProvider};
+use datafusion::error::DataFusionError;
+use datafusion::execution::TaskContext;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use datafusion::physical_plan::SendableRecordBatchStream;
 use datafusion_ext::errors::{ExtensionError, Result};
-use datafusion_ext::functions::{FuncParamValue, TableFunc, TableFuncContextProvider};
-use datasources::lake::iceberg::table::IcebergTable;
-use datasources::lake::storage_options_into_object_store;
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, TableFuncParams,
+};
+use datasources::lake::cached_object_store;
+use datasources::lake::iceberg::errors::IcebergError;
+use datasources::lake::iceberg::spec::Manifest;
+use datasources::lake::iceberg::table::{ExpireSnapshotsOptions, IcebergReadOptions, IcebergTable};
+use futures::StreamExt;
+use object_store_util::retry::{http_status, is_timeout};
 use protogen::metastore::types::catalog::RuntimePreference;
+use reqwest::StatusCode;
 
 /// Scan an iceberg table.
 #[derive(Debug, Clone, Copy)]
@@ -28,20 +45,39 @@ impl TableFunc for IcebergScan {
         "iceberg_scan"
     }
 
+    fn description(&self) -> &str {
+        "Scan an Iceberg table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
         args: Vec<FuncParamValue>,
         mut opts: HashMap<String, FuncParamValue>,
     ) -> Result<Arc<dyn TableProvider>> {
-        // TODO: Reduce duplication
-        let (loc, opts) = table_location_and_opts(ctx, args, &mut opts)?;
+        let mut read_opts = IcebergReadOptions::default();
+        if let Some(v) = opts.remove(MAX_CONCURRENCY_OPTION) {
+            let n: i64 = v.param_into()?;
+            if n < 1 {
+                return Err(ExtensionError::String(format!(
+                    "{MAX_CONCURRENCY_OPTION} must be at least 1, got {n}"
+                )));
+            }
+            read_opts.max_concurrency = n as usize;
+        }
+        if let Some(v) = opts.remove(MERGE_SCHEMAS_OPTION) {
+            read_opts.merge_schemas = v.param_into()?;
+        }
 
-        let store = storage_options_into_object_store(&loc, &opts).map_err(box_err)?;
-        let table = IcebergTable::open(loc.clone(), store)
+        let table = open_table(ctx, args, &mut opts).await?;
+        let reader = table
+            .table_reader_with_options(read_opts)
             .await
             .map_err(box_err)?;
-        let reader = table.table_reader().await.map_err(box_err)?;
 
         Ok(reader)
     }
@@ -61,16 +97,21 @@ impl TableFunc for IcebergSnapshots {
         "iceberg_snapshots"
     }
 
+    fn description(&self) -> &str {
+        "List snapshots of an Iceberg table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
         args: Vec<FuncParamValue>,
         mut opts: HashMap<String, FuncParamValue>,
     ) -> Result<Arc<dyn TableProvider>> {
-        let (loc, opts) = table_location_and_opts(ctx, args, &mut opts)?;
-
-        let store = storage_options_into_object_store(&loc, &opts).map_err(box_err)?;
-        let table = IcebergTable::open(loc, store).await.map_err(box_err)?;
+        let table = open_table(ctx, args, &mut opts).await?;
 
         let snapshots = &table.metadata().snapshots;
 
@@ -109,6 +150,88 @@ impl TableFunc for IcebergSnapshots {
     }
 }
 
+/// Get row count and file statistics for the current snapshot of an iceberg
+/// table using only the snapshot summary. Will not attempt to read manifests
+/// or data files.
+#[derive(Debug, Clone, Copy)]
+pub struct IcebergRowCount;
+
+#[async_trait]
+impl TableFunc for IcebergRowCount {
+    fn runtime_preference(&self) -> RuntimePreference {
+        RuntimePreference::Remote
+    }
+    fn name(&self) -> &str {
+        "iceberg_row_count"
+    }
+
+    fn description(&self) -> &str {
+        "Get row and file counts for the current snapshot of an Iceberg table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
+    async fn create_provider(
+        &self,
+        ctx: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        mut opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let table = open_table(ctx, args, &mut opts).await?;
+
+        let snapshot = table.current_snapshot().map_err(box_err)?;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("snapshot_id", DataType::Int64, false),
+            Field::new("total_records", DataType::Int64, true),
+            Field::new("total_data_files", DataType::Int64, true),
+            Field::new("total_file_size", DataType::Int64, true),
+        ]));
+
+        // Summary fields are optional per the spec, so missing values are
+        // returned as nulls.
+        let summary_value = |key: &str| -> Result<Option<i64>> {
+            snapshot
+                .summary
+                .get(key)
+                .map(|v| {
+                    v.parse::<i64>().map_err(|e| {
+                        ExtensionError::String(format!(
+                            "Invalid value for '{key}' in snapshot summary: {e}"
+                        ))
+                    })
+                })
+                .transpose()
+        };
+
+        let mut snapshot_id = Int64Builder::new();
+        let mut total_records = Int64Builder::new();
+        let mut total_data_files = Int64Builder::new();
+        let mut total_file_size = Int64Builder::new();
+
+        snapshot_id.append_value(snapshot.snapshot_id);
+        total_records.append_option(summary_value("total-records")?);
+        total_data_files.append_option(summary_value("total-data-files")?);
+        total_file_size.append_option(summary_value("total-files-size")?);
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(snapshot_id.finish()),
+                Arc::new(total_records.finish()),
+                Arc::new(total_data_files.finish()),
+                Arc::new(total_file_size.finish()),
+            ],
+        )?;
+
+        Ok(Arc::new(
+            MemTable::try_new(schema, vec![vec![batch]]).unwrap(),
+        ))
+    }
+}
+
 /// Scan data file metadata for the current snapshot of an iceberg table. Will
 /// not attempt to read data files.
 #[derive(Debug, Clone, Copy)]
@@ -123,70 +246,212 @@ impl TableFunc for IcebergDataFiles {
         "iceberg_data_files"
     }
 
+    fn description(&self) -> &str {
+        "List data files for the current snapshot of an Iceberg table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
         args: Vec<FuncParamValue>,
         mut opts: HashMap<String, FuncParamValue>,
     ) -> Result<Arc<dyn TableProvider>> {
-        let (loc, opts) = table_location_and_opts(ctx, args, &mut opts)?;
+        let streaming: bool = opts
+            .remove(STREAMING_OPTION)
+            .map(|v| v.param_into())
+            .transpose()?
+            .unwrap_or(false);
 
-        let store = storage_options_into_object_store(&loc, &opts).map_err(box_err)?;
-        let table = IcebergTable::open(loc, store).await.map_err(box_err)?;
+        let table = open_table(ctx, args, &mut opts).await?;
+
+        if streaming {
+            let partition = DataFilesPartition {
+                schema: data_files_schema(),
+                table: Arc::new(table),
+            };
+            let table =
+                StreamingTable::try_new(partition.schema.clone(), vec![Arc::new(partition)])?;
+            return Ok(Arc::new(table));
+        }
 
         let manifests = table.read_manifests().await.map_err(box_err)?;
 
+        let mut builder = DataFilesBuilder::default();
+        for (idx, manifest) in manifests.into_iter().enumerate() {
+            builder.append_manifest(idx, manifest)?;
+        }
+
+        let schema = data_files_schema();
+        let batch = builder.finish(schema.clone())?;
+
+        Ok(Arc::new(
+            MemTable::try_new(schema, vec![vec![batch]]).unwrap(),
+        ))
+    }
+}
+
+fn data_files_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("manifest_index", DataType::UInt64, false),
+        Field::new("manifest_content", DataType::Utf8, false),
+        Field::new("snapshot_id", DataType::Int64, true),
+        Field::new("sequence_number", DataType::Int64, true),
+        Field::new("file_sequence_number", DataType::Int64, true),
+        Field::new("file_path", DataType::Utf8, false),
+        Field::new("file_format", DataType::Utf8, false),
+        Field::new("record_count", DataType::Int64, false),
+        Field::new("file_size_bytes", DataType::Int64, false),
+        Field::new("data_file_content", DataType::Utf8, false),
+    ]))
+}
+
+/// Builds `iceberg_data_files` output from manifest entries.
+#[derive(Default)]
+struct DataFilesBuilder {
+    manifest_index: UInt64Builder,
+    manifest_content: StringBuilder,
+    snapshot_id: Int64Builder,
+    sequence_number: Int64Builder,
+    file_sequence_number: Int64Builder,
+    file_path: StringBuilder,
+    file_format: StringBuilder,
+    record_count: Int64Builder,
+    file_size_bytes: Int64Builder,
+    data_file_content: StringBuilder,
+}
+
+impl DataFilesBuilder {
+    fn append_manifest(&mut self, idx: usize, manifest: Manifest) -> Result<()> {
+        for entry in manifest.entries {
+            // Manifest metadata
+            self.manifest_index.append_value(idx as u64);
+            self.manifest_content
+                .append_value(manifest.metadata.content.to_string());
+
+            // Entry data
+            self.snapshot_id.append_option(entry.snapshot_id);
+            self.sequence_number.append_option(entry.sequence_number);
+            self.file_sequence_number
+                .append_option(entry.file_sequence_number);
+            self.file_path.append_value(&entry.data_file.file_path);
+            self.file_format.append_value(&entry.data_file.file_format);
+            self.record_count.append_value(entry.data_file.record_count);
+            self.file_size_bytes
+                .append_value(entry.data_file.file_size_in_bytes);
+            self.data_file_content
+                .append_value(entry.data_file.content_type().map_err(box_err)?.to_string());
+        }
+        Ok(())
+    }
+
+    fn finish(mut self, schema: Arc<Schema>) -> Result<RecordBatch> {
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(self.manifest_index.finish()),
+                Arc::new(self.manifest_content.finish()),
+                Arc::new(self.snapshot_id.finish()),
+                Arc::new(self.sequence_number.finish()),
+                Arc::new(self.file_sequence_number.finish()),
+                Arc::new(self.file_path.finish()),
+                Arc::new(self.file_format.finish()),
+                Arc::new(self.record_count.finish()),
+                Arc::new(self.file_size_bytes.finish()),
+                Arc::new(self.data_file_content.finish()),
+            ],
+        )?;
+        Ok(batch)
+    }
+}
+
+/// Partition for `iceberg_data_files` that reads manifests as the output is
+/// consumed, producing one batch per manifest.
+struct DataFilesPartition {
+    schema: Arc<Schema>,
+    table: Arc<IcebergTable>,
+}
+
+impl PartitionStream for DataFilesPartition {
+    fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let schema = self.schema.clone();
+        let stream = self
+            .table
+            .stream_manifests()
+            .enumerate()
+            .map(move |(idx, manifest)| {
+                let manifest = manifest.map_err(|e| DataFusionError::External(Box::new(e)))?;
+                let mut builder = DataFilesBuilder::default();
+                builder
+                    .append_manifest(idx, manifest)
+                    .and_then(|_| builder.finish(schema.clone()))
+                    .map_err(|e| DataFusionError::External(Box::new(e)))
+            });
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
+
+/// Check the integrity of the current snapshot of an iceberg table. Returns a
+/// row for each problem found, and no rows if the table is valid.
+#[derive(Debug, Clone, Copy)]
+pub struct IcebergValidate;
+
+#[async_trait]
+impl TableFunc for IcebergValidate {
+    fn runtime_preference(&self) -> RuntimePreference {
+        RuntimePreference::Remote
+    }
+    fn name(&self) -> &str {
+        "iceberg_validate"
+    }
+
+    fn description(&self) -> &str {
+        "Check that the files referenced by an Iceberg table exist and match their manifests."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
+    async fn create_provider(
+        &self,
+        ctx: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        mut opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let table = open_table(ctx, args, &mut opts).await?;
+
+        let problems = table.validate().await.map_err(box_err)?;
+
         let schema = Arc::new(Schema::new(vec![
-            Field::new("manifest_index", DataType::UInt64, false),
-            Field::new("manifest_content", DataType::Utf8, false),
-            Field::new("snapshot_id", DataType::Int64, true),
-            Field::new("sequence_number", DataType::Int64, true),
-            Field::new("file_sequence_number", DataType::Int64, true),
-            Field::new("file_path", DataType::Utf8, false),
-            Field::new("file_format", DataType::Utf8, false),
-            Field::new("record_count", DataType::Int64, false),
-            Field::new("file_size_bytes", DataType::Int64, false),
+            Field::new("problem", DataType::Utf8, false),
+            Field::new("file_path", DataType::Utf8, true),
+            Field::new("detail", DataType::Utf8, false),
         ]));
 
-        let mut manifest_index = UInt64Builder::new();
-        let mut manifest_content = StringBuilder::new();
-        let mut snapshot_id = Int64Builder::new();
-        let mut sequence_number = Int64Builder::new();
-        let mut file_sequence_number = Int64Builder::new();
+        let mut problem = StringBuilder::new();
         let mut file_path = StringBuilder::new();
-        let mut file_format = StringBuilder::new();
-        let mut record_count = Int64Builder::new();
-        let mut file_size_bytes = Int64Builder::new();
+        let mut detail = StringBuilder::new();
 
-        for (idx, manifest) in manifests.into_iter().enumerate() {
-            for entry in manifest.entries {
-                // Manifest metadata
-                manifest_index.append_value(idx as u64);
-                manifest_content.append_value(manifest.metadata.content.to_string());
-
-                // Entry data
-                snapshot_id.append_option(entry.snapshot_id);
-                sequence_number.append_option(entry.sequence_number);
-                file_sequence_number.append_option(entry.file_sequence_number);
-                file_path.append_value(&entry.data_file.file_path);
-                file_format.append_value(&entry.data_file.file_format);
-                record_count.append_value(entry.data_file.record_count);
-                file_size_bytes.append_value(entry.data_file.file_size_in_bytes);
-            }
+        for p in problems {
+            problem.append_value(p.kind());
+            file_path.append_option(p.file_path());
+            detail.append_value(p.to_string());
         }
 
         let batch = RecordBatch::try_new(
             schema.clone(),
             vec![
-                Arc::new(manifest_index.finish()),
-                Arc::new(manifest_content.finish()),
-                Arc::new(snapshot_id.finish()),
-                Arc::new(sequence_number.finish()),
-                Arc::new(file_sequence_number.finish()),
+                Arc::new(problem.finish()),
                 Arc::new(file_path.finish()),
-                Arc::new(file_format.finish()),
-                Arc::new(record_count.finish()),
-                Arc::new(file_size_bytes.finish()),
+                Arc::new(detail.finish()),
             ],
         )?;
 
@@ -196,9 +461,505 @@ impl TableFunc for IcebergDataFiles {
     }
 }
 
+/// Expire old snapshots of an iceberg table, deleting files no longer
+/// referenced by the remaining snapshots.
+///
+/// Creating the provider only opens the table and validates the options.
+/// Snapshots are expired by `ExpireSnapshotsPartition` when the query is
+/// executed, so planning (e.g. EXPLAIN) does not modify the table.
+#[derive(Debug, Clone, Copy)]
+pub struct IcebergExpireSnapshots;
+
+#[async_trait]
+impl TableFunc for IcebergExpireSnapshots {
+    fn runtime_preference(&self) -> RuntimePreference {
+        RuntimePreference::Remote
+    }
+    fn name(&self) -> &str {
+        "iceberg_expire_snapshots"
+    }
+
+    fn description(&self) -> &str {
+        "Remove old snapshots and unreferenced files from an Iceberg table."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(LOCATION_PARAMS)
+    }
+
+    async fn create_provider(
+        &self,
+        ctx: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        mut opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let older_than = match opts.remove(OLDER_THAN_OPTION) {
+            Some(v) => parse_age(&v.param_into::<String>()?)?,
+            None => Duration::days(DEFAULT_EXPIRE_AGE_DAYS),
+        };
+        let retain_last = match opts.remove(RETAIN_LAST_OPTION) {
+            Some(v) => {
+                let n: i64 = v.param_into()?;
+                if n < 1 {
+                    return Err(ExtensionError::String(format!(
+                        "{RETAIN_LAST_OPTION} must be at least 1, got {n}"
+                    )));
+                }
+                n as usize
+            }
+            None => 1,
+        };
+
+        if ctx.get_session_vars().read_only() {
+            return Err(ExtensionError::String(
+                "Cannot expire snapshots, database is in read-only mode".to_string(),
+            ));
+        }
+
+        let table = open_table(ctx, args, &mut opts).await?;
+        let partition = ExpireSnapshotsPartition {
+            schema: Arc::new(Schema::new(vec![
+                Field::new("expired_snapshots", DataType::Int64, false),
+                Field::new("deleted_files", DataType::Int64, false),
+            ])),
+            table: Arc::new(table),
+            opts: ExpireSnapshotsOptions {
+                older_than: Utc::now() - older_than,
+                retain_last,
+            },
+        };
+
+        let table = StreamingTable::try_new(partition.schema.clone(), vec![Arc::new(partition)])?;
+        Ok(Arc::new(table))
+    }
+}
+
+/// Partition for `iceberg_expire_snapshots` that expires snapshots when the
+/// query is executed rather than when it's planned.
+struct ExpireSnapshotsPartition {
+    schema: Arc<Schema>,
+    table: Arc<IcebergTable>,
+    opts: ExpireSnapshotsOptions,
+}
+
+impl PartitionStream for ExpireSnapshotsPartition {
+    fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let schema = self.schema.clone();
+        let table = self.table.clone();
+        let opts = self.opts;
+        let stream = futures::stream::once(async move {
+            let expired = table
+                .expire_snapshots(opts)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(box_err(e))))?;
+
+            let batch = RecordBatch::try_new(
+                schema,
+                vec![
+                    Arc::new(Int64Array::from(vec![expired.snapshot_ids.len() as i64])),
+                    Arc::new(Int64Array::from(vec![expired.deleted_files.len() as i64])),
+                ],
+            )?;
+            Ok(batch)
+        });
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
+
+/// Parse an age such as '7d' or '12h' into a duration.
+///
+/// Accepted units are seconds (s), minutes (m), hours (h), days (d), and
+/// weeks (w).
+fn parse_age(s: &str) -> Result<Duration> {
+    let invalid = || {
+        ExtensionError::String(format!(
+            "Invalid value for {OLDER_THAN_OPTION}: '{s}', expected a number followed by one of s, m, h, d, or w"
+        ))
+    };
+
+    let s = s.trim();
+    let (unit_idx, _) = s.char_indices().last().ok_or_else(invalid)?;
+    let (num, unit) = s.split_at(unit_idx);
+    let num: i64 = num.trim().parse().map_err(|_| invalid())?;
+    if num < 0 {
+        return Err(invalid());
+    }
+
+    match unit {
+        "s" => Ok(Duration::seconds(num)),
+        "m" => Ok(Duration::minutes(num)),
+        "h" => Ok(Duration::hours(num)),
+        "d" => Ok(Duration::days(num)),
+        "w" => Ok(Duration::weeks(num)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Option for the max number of data files read concurrently by a scan.
+const MAX_CONCURRENCY_OPTION: &str = "max_concurrency";
+
+/// Option for including columns that have been dropped from the table.
+const MERGE_SCHEMAS_OPTION: &str = "merge_schemas";
+
+/// Option for reading manifests as the output is consumed rather than
+/// buffering all entries up front.
+const STREAMING_OPTION: &str = "streaming";
+
+/// Option for reading an exact metadata file instead of the table's latest
+/// version.
+const METADATA_LOCATION_OPTION: &str = "metadata_location";
+
+/// Option for the minimum age of snapshots to expire.
+const OLDER_THAN_OPTION: &str = "older_than";
+
+/// Option for the number of most recent snapshots to always keep.
+const RETAIN_LAST_OPTION: &str = "retain_last";
+
+/// Snapshots older than this many days are expired if `older_than` isn't
+/// provided.
+const DEFAULT_EXPIRE_AGE_DAYS: i64 = 5;
+
+/// Open the iceberg table referenced by the function arguments.
+async fn open_table(
+    ctx: &dyn TableFuncContextProvider,
+    args: Vec<FuncParamValue>,
+    opts: &mut HashMap<String, FuncParamValue>,
+) -> Result<IcebergTable> {
+    let metadata_location: Option<String> = opts
+        .remove(METADATA_LOCATION_OPTION)
+        .map(|v| v.param_into())
+        .transpose()?;
+
+    let (loc, opts) = table_location_and_opts(ctx, args, opts)?;
+    let allow_ambient_credentials = ctx.get_session_vars().allow_ambient_credentials();
+    let store = cached_object_store(&loc, &opts, allow_ambient_credentials).map_err(box_err)?;
+
+    let table = match metadata_location {
+        Some(metadata_location) => {
+            IcebergTable::open_with_metadata_location(loc, store, &metadata_location).await
+        }
+        None => IcebergTable::open(loc, store).await,
+    };
+
+    table.map_err(box_err)
+}
+
+/// Convert an error from accessing a table, keeping whether the table wasn't
+/// found, access was denied, or the failure was transient.
 fn box_err<E>(err: E) -> ExtensionError
 where
     E: std::error::Error + Send + Sync + 'static,
 {
-    ExtensionError::Access(Box::new(err))
+    match access_error_kind(&err) {
+        Some(io::ErrorKind::NotFound) => ExtensionError::NotFound(Box::new(err)),
+        Some(io::ErrorKind::PermissionDenied) => ExtensionError::PermissionDenied(Box::new(err)),
+        Some(_) => ExtensionError::Transient(Box::new(err)),
+        None => ExtensionError::Access(Box::new(err)),
+    }
+}
+
+/// Find the kind of the underlying access error, if any.
+///
+/// Only returns not found, permission denied, or kinds for failures that may
+/// succeed when retried.
+fn access_error_kind(err: &(dyn std::error::Error + 'static)) -> Option<io::ErrorKind> {
+    // Transparent errors forward `source` to the wrapped error's source,
+    // skipping the wrapped error itself.
+    let mut curr = match err.downcast_ref::<IcebergError>() {
+        Some(IcebergError::ObjectStore(e)) => Some(e as &(dyn std::error::Error + 'static)),
+        _ => Some(err),
+    };
+
+    while let Some(err) = curr {
+        if let Some(err) = err.downcast_ref::<object_store::Error>() {
+            if matches!(err, object_store::Error::NotFound { .. }) {
+                return Some(io::ErrorKind::NotFound);
+            }
+
+            // Cloud stores report most failures as generic errors, so use
+            // the status of the failed request.
+            if is_timeout(err) {
+                return Some(io::ErrorKind::TimedOut);
+            }
+            match http_status(err) {
+                Some(StatusCode::NOT_FOUND) => return Some(io::ErrorKind::NotFound),
+                Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+                    return Some(io::ErrorKind::PermissionDenied)
+                }
+                Some(status)
+                    if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() =>
+                {
+                    return Some(io::ErrorKind::Interrupted)
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(err) = err.downcast_ref::<io::Error>() {
+            match err.kind() {
+                kind @ (io::ErrorKind::NotFound
+                | io::ErrorKind::PermissionDenied
+                | io::ErrorKind::ConnectionRefused
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::TimedOut
+                | io::ErrorKind::Interrupted
+                | io::ErrorKind::UnexpectedEof) => return Some(kind),
+                _ => (),
+            }
+        }
+
+        curr = err.source();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::testutil::{
+        copy_iceberg_test_table, iceberg_test_table, utf8, TestContext,
+    };
+    use datafusion::physical_plan::collect;
+    use datafusion::prelude::SessionContext;
+    use datafusion::scalar::ScalarValue;
+    use datafusion::variable::VarType;
+    use datafusion_ext::vars::SessionVars;
+
+    fn streaming_opts(streaming: bool) -> HashMap<String, FuncParamValue> {
+        let mut opts = HashMap::new();
+        opts.insert(
+            STREAMING_OPTION.to_string(),
+            FuncParamValue::Scalar(ScalarValue::Boolean(Some(streaming))),
+        );
+        opts
+    }
+
+    async fn data_file_batches(streaming: bool) -> Vec<RecordBatch> {
+        let path = iceberg_test_table("lineitem_versioned");
+        let args = vec![utf8(&path.to_string_lossy())];
+
+        let provider = IcebergDataFiles
+            .create_provider(&TestContext::default(), args, streaming_opts(streaming))
+            .await
+            .unwrap();
+
+        let ctx = SessionContext::new();
+        let plan = provider.scan(&ctx.state(), None, &[], None).await.unwrap();
+        collect(plan, ctx.task_ctx()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn streaming_data_files_batch_per_manifest() {
+        let eager = data_file_batches(false).await;
+        let streamed = data_file_batches(true).await;
+
+        // The table has two manifests, each with a single data file.
+        assert_eq!(1, eager.len());
+        assert_eq!(2, streamed.len());
+        for batch in &streamed {
+            assert_eq!(1, batch.num_rows());
+        }
+
+        let rows = |batches: &[RecordBatch]| batches.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(rows(&eager), rows(&streamed));
+    }
+
+    #[tokio::test]
+    async fn streaming_data_files_reads_manifests_lazily() {
+        let dir = copy_iceberg_test_table("lineitem_versioned");
+        let args = vec![utf8(&dir.path().to_string_lossy())];
+
+        let provider = IcebergDataFiles
+            .create_provider(&TestContext::default(), args, streaming_opts(true))
+            .await
+            .unwrap();
+
+        let ctx = SessionContext::new();
+        let plan = provider.scan(&ctx.state(), None, &[], None).await.unwrap();
+        let mut stream = plan.execute(0, ctx.task_ctx()).unwrap();
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(1, first.num_rows());
+
+        // Remove all manifests. The remaining manifest shouldn't have been
+        // read yet, so the next batch fails.
+        for ent in std::fs::read_dir(dir.path().join("metadata")).unwrap() {
+            let path = ent.unwrap().path();
+            if path.to_string_lossy().ends_with("-m0.avro") {
+                std::fs::remove_file(path).unwrap();
+            }
+        }
+
+        stream.next().await.unwrap().unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn expire_snapshots_runs_on_execute() {
+        async fn snapshot_count(args: Vec<FuncParamValue>) -> usize {
+            let table = open_table(&TestContext::default(), args, &mut HashMap::new())
+                .await
+                .unwrap();
+            table.metadata().snapshots.len()
+        }
+
+        let dir = copy_iceberg_test_table("lineitem_versioned");
+        let args = || vec![utf8(&dir.path().to_string_lossy())];
+
+        let mut opts = HashMap::new();
+        opts.insert(OLDER_THAN_OPTION.to_string(), utf8("0s"));
+        let provider = IcebergExpireSnapshots
+            .create_provider(&TestContext::default(), args(), opts)
+            .await
+            .unwrap();
+
+        // Planning doesn't modify the table.
+        assert_eq!(2, snapshot_count(args()).await);
+
+        let ctx = SessionContext::new();
+        let plan = provider.scan(&ctx.state(), None, &[], None).await.unwrap();
+        let batches = collect(plan, ctx.task_ctx()).await.unwrap();
+        let expired = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(1, expired.value(0));
+        assert_eq!(1, snapshot_count(args()).await);
+    }
+
+    #[tokio::test]
+    async fn expire_snapshots_rejected_when_read_only() {
+        let path = iceberg_test_table("lineitem_versioned");
+        let ctx =
+            TestContext::with_vars(SessionVars::default().with_read_only(true, VarType::System));
+
+        let err = IcebergExpireSnapshots
+            .create_provider(&ctx, vec![utf8(&path.to_string_lossy())], HashMap::new())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("read-only"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn missing_table_is_not_found() {
+        let path = iceberg_test_table("does_not_exist");
+        let args = vec![utf8(&path.to_string_lossy())];
+
+        let err = IcebergSnapshots
+            .create_provider(&TestContext::default(), args, HashMap::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ExtensionError::NotFound(_)), "{err:?}");
+    }
+
+    #[test]
+    fn access_errors_classified() {
+        let store_err = |kind| {
+            IcebergError::ObjectStore(object_store::Error::Generic {
+                store: "S3",
+                source: Box::new(io::Error::new(kind, "failed")),
+            })
+        };
+
+        let err = box_err(store_err(io::ErrorKind::PermissionDenied));
+        assert!(
+            matches!(err, ExtensionError::PermissionDenied(_)),
+            "{err:?}"
+        );
+
+        let err = box_err(store_err(io::ErrorKind::TimedOut));
+        assert!(matches!(err, ExtensionError::Transient(_)), "{err:?}");
+
+        let err = box_err(IcebergError::ObjectStore(object_store::Error::NotFound {
+            path: "table/metadata/version-hint.text".to_string(),
+            source: Box::new(io::Error::new(io::ErrorKind::Other, "404")),
+        }));
+        assert!(matches!(err, ExtensionError::NotFound(_)), "{err:?}");
+
+        let err = box_err(IcebergError::DataInvalid("bad metadata".to_string()));
+        assert!(matches!(err, ExtensionError::Access(_)), "{err:?}");
+    }
+
+    #[test]
+    fn http_errors_classified() {
+        let http_err = |status: u16| {
+            let resp = http::Response::builder().status(status).body("").unwrap();
+            let source = reqwest::Response::from(resp)
+                .error_for_status()
+                .unwrap_err();
+            IcebergError::ObjectStore(object_store::Error::Generic {
+                store: "S3",
+                source: Box::new(source),
+            })
+        };
+
+        for status in [401, 403] {
+            let err = box_err(http_err(status));
+            assert!(
+                matches!(err, ExtensionError::PermissionDenied(_)),
+                "{status}: {err:?}"
+            );
+        }
+
+        let err = box_err(http_err(404));
+        assert!(matches!(err, ExtensionError::NotFound(_)), "{err:?}");
+
+        for status in [429, 500, 503] {
+            let err = box_err(http_err(status));
+            assert!(
+                matches!(err, ExtensionError::Transient(_)),
+                "{status}: {err:?}"
+            );
+        }
+
+        let err = box_err(http_err(400));
+        assert!(matches!(err, ExtensionError::Access(_)), "{err:?}");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn denied_local_table_is_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = copy_iceberg_test_table("lineitem_simple");
+        let hint = dir.path().join("metadata/version-hint.text");
+        std::fs::set_permissions(&hint, std::fs::Permissions::from_mode(0o000)).unwrap();
+        if std::fs::read(&hint).is_ok() {
+            // Permissions aren't enforced, e.g. when running as root.
+            return;
+        }
+
+        let err = IcebergSnapshots
+            .create_provider(
+                &TestContext::default(),
+                vec![utf8(&dir.path().to_string_lossy())],
+                HashMap::new(),
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, ExtensionError::PermissionDenied(_)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn parse_age_units() {
+        assert_eq!(Duration::seconds(30), parse_age("30s").unwrap());
+        assert_eq!(Duration::hours(12), parse_age("12h").unwrap());
+        assert_eq!(Duration::days(7), parse_age("7d").unwrap());
+        assert_eq!(Duration::weeks(2), parse_age(" 2w ").unwrap());
+
+        parse_age("").unwrap_err();
+        parse_age("d").unwrap_err();
+        parse_age("7").unwrap_err();
+        parse_age("-1d").unwrap_err();
+        parse_age("7y").unwrap_err();
+    }
 }
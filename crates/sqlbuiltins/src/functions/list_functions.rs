@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::array::StringBuilder;
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::{MemTable, TableProvider};
+use datafusion_ext::errors::Result;
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, TableFuncParams,
+};
+use protogen::metastore::types::catalog::RuntimePreference;
+
+use super::BUILTIN_TABLE_FUNCS;
+
+/// List all registered table functions along with their descriptions.
+#[derive(Debug, Clone, Copy)]
+pub struct ListTableFunctions;
+
+#[async_trait]
+impl TableFunc for ListTableFunctions {
+    fn runtime_preference(&self) -> RuntimePreference {
+        RuntimePreference::Unspecified
+    }
+
+    fn detect_runtime(
+        &self,
+        _: &[FuncParamValue],
+        parent: RuntimePreference,
+    ) -> Result<RuntimePreference> {
+        match parent {
+            RuntimePreference::Unspecified => Ok(RuntimePreference::Local),
+            other => Ok(other),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "list_table_functions"
+    }
+
+    fn description(&self) -> &str {
+        "List all available table functions."
+    }
+
+    fn params(&self) -> Option<TableFuncParams> {
+        Some(TableFuncParams::new(&[], &[]))
+    }
+
+    async fn create_provider(
+        &self,
+        _: &dyn TableFuncContextProvider,
+        _: Vec<FuncParamValue>,
+        _: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("function_name", DataType::Utf8, false),
+            Field::new("description", DataType::Utf8, false),
+            Field::new("runtime_preference", DataType::Utf8, false),
+        ]));
+
+        let mut funcs: Vec<_> = BUILTIN_TABLE_FUNCS.iter_funcs().collect();
+        funcs.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut function_name = StringBuilder::new();
+        let mut description = StringBuilder::new();
+        let mut runtime_preference = StringBuilder::new();
+
+        for func in funcs {
+            function_name.append_value(func.name());
+            description.append_value(func.description());
+            runtime_preference.append_value(func.runtime_preference().as_str());
+        }
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(function_name.finish()),
+                Arc::new(description.finish()),
+                Arc::new(runtime_preference.finish()),
+            ],
+        )?;
+
+        Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+    }
+}
@@ -2,40 +2,57 @@
 //! mod bigquery;
 
 mod bigquery;
+mod cache;
 mod delta;
 mod excel;
 mod generate_series;
 mod iceberg;
+mod list_functions;
 mod mongo;
 mod mysql;
 mod object_store;
 mod postgres;
 mod snowflake;
+#[cfg(test)]
+mod testutil;
+mod unnest;
 mod virtual_listing;
 
 use ::object_store::aws::AmazonS3ConfigKey;
 use ::object_store::azure::AzureConfigKey;
 use ::object_store::gcp::GoogleConfigKey;
 use datafusion::logical_expr::{AggregateFunction, BuiltinScalarFunction};
+use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use datafusion_ext::errors::{ExtensionError, Result};
-use datafusion_ext::functions::{FuncParamValue, IdentValue, TableFunc, TableFuncContextProvider};
+use datafusion_ext::functions::{
+    FuncParamValue, IdentValue, TableFunc, TableFuncContextProvider, TableFuncParam,
+    TableFuncParamType, TableFuncParams,
+};
 use datasources::common::url::{DatasourceUrl, DatasourceUrlType};
+use datasources::lake::{RETRIES_OPTION, RETRY_BACKOFF_MS_OPTION};
 use once_cell::sync::Lazy;
 use protogen::metastore::types::options::{CredentialsOptions, StorageOptions};
 
 use self::bigquery::ReadBigQuery;
+pub use self::cache::CachedTableFunc;
 use self::delta::DeltaScan;
 use self::excel::ExcelScan;
 use self::generate_series::GenerateSeries;
-use self::iceberg::{IcebergDataFiles, IcebergScan, IcebergSnapshots};
+use self::iceberg::{
+    IcebergDataFiles, IcebergExpireSnapshots, IcebergRowCount, IcebergScan, IcebergSnapshots,
+    IcebergValidate,
+};
+use self::list_functions::ListTableFunctions;
 use self::mongo::ReadMongoDb;
 use self::mysql::ReadMysql;
 use self::object_store::{CSV_SCAN, JSON_SCAN, PARQUET_SCAN};
 use self::postgres::ReadPostgres;
 use self::snowflake::ReadSnowflake;
+use self::unnest::Unnest;
 use self::virtual_listing::{ListColumns, ListSchemas, ListTables};
 
 /// Builtin table returning functions available for all sessions.
@@ -92,9 +109,14 @@ impl Default for BuiltinScalarFuncs {
     }
 }
 
+/// How long `iceberg_snapshots` results are reused within a session.
+const ICEBERG_SNAPSHOTS_CACHE_TTL: Duration = Duration::from_secs(30);
+
 /// All builtin table functions.
 pub struct BuiltinTableFuncs {
     funcs: HashMap<String, Arc<dyn TableFunc>>,
+    /// Functions registered at runtime. These don't have catalog entries.
+    registered: RwLock<HashMap<String, Arc<dyn TableFunc>>>,
 }
 
 impl BuiltinTableFuncs {
@@ -113,28 +135,66 @@ impl BuiltinTableFuncs {
             // Data lakes
             Arc::new(DeltaScan),
             Arc::new(IcebergScan),
-            Arc::new(IcebergSnapshots),
+            Arc::new(CachedTableFunc::new(
+                Arc::new(IcebergSnapshots),
+                ICEBERG_SNAPSHOTS_CACHE_TTL,
+            )),
             Arc::new(IcebergDataFiles),
+            Arc::new(IcebergRowCount),
+            Arc::new(IcebergValidate),
+            Arc::new(IcebergExpireSnapshots),
             Arc::new(ExcelScan),
             // Listing
             Arc::new(ListSchemas),
             Arc::new(ListTables),
             Arc::new(ListColumns),
+            Arc::new(ListTableFunctions),
             // Series generating
             Arc::new(GenerateSeries),
+            Arc::new(Unnest),
         ];
         let funcs: HashMap<String, Arc<dyn TableFunc>> = funcs
             .into_iter()
             .map(|f| (f.name().to_string(), f))
             .collect();
 
-        BuiltinTableFuncs { funcs }
+        BuiltinTableFuncs {
+            funcs,
+            registered: RwLock::new(HashMap::new()),
+        }
     }
 
     pub fn find_function(&self, name: &str) -> Option<Arc<dyn TableFunc>> {
         self.funcs.get(name).cloned()
     }
 
+    /// Register a table function at runtime, e.g. from an extension.
+    ///
+    /// Registered functions aren't added to the catalog, and can only be
+    /// called by their unqualified name. Registering a function with the same
+    /// name as a previously registered function replaces it. Errors if a
+    /// builtin function has the same name.
+    ///
+    /// Registered functions only exist in this process, so they always run
+    /// locally. Calling one from a remote or hybrid session errors if the
+    /// function would need to run remotely.
+    pub fn register_table_func(&self, func: Arc<dyn TableFunc>) -> Result<()> {
+        let name = func.name().to_string();
+        if self.funcs.contains_key(&name) {
+            return Err(ExtensionError::String(format!(
+                "Cannot register table function '{name}', a builtin function with the same name exists"
+            )));
+        }
+
+        self.registered.write().insert(name, func);
+        Ok(())
+    }
+
+    /// Find a table function registered at runtime.
+    pub fn find_registered_function(&self, name: &str) -> Option<Arc<dyn TableFunc>> {
+        self.registered.read().get(name).cloned()
+    }
+
     pub fn iter_funcs(&self) -> impl Iterator<Item = &Arc<dyn TableFunc>> {
         self.funcs.values()
     }
@@ -146,6 +206,16 @@ impl Default for BuiltinTableFuncs {
     }
 }
 
+/// Parameters for functions reading from a location, optionally using a
+/// credentials object.
+const LOCATION_PARAMS: TableFuncParams = TableFuncParams::new(
+    &[TableFuncParam::new("location", TableFuncParamType::String)],
+    &[TableFuncParam::new(
+        "credentials",
+        TableFuncParamType::Ident,
+    )],
+);
+
 // Parse the data lake table location and object store options from the provided function arguments
 fn table_location_and_opts(
     ctx: &dyn TableFuncContextProvider,
@@ -172,6 +242,8 @@ fn table_location_and_opts(
         );
     }
 
+    const REGION_KEY: &str = "region";
+
     let mut storage_options = StorageOptions::default();
     match (source_url.datasource_url_type(), maybe_cred_opts) {
         (DatasourceUrlType::File, None) => {} // no options fine in this case
@@ -187,7 +259,6 @@ fn table_location_and_opts(
             );
         }
         (DatasourceUrlType::S3, Some(CredentialsOptions::Aws(creds))) => {
-            const REGION_KEY: &str = "region";
             let region = opts
                 .remove(REGION_KEY)
                 .ok_or(ExtensionError::MissingNamedArgument(REGION_KEY))?
@@ -215,6 +286,29 @@ fn table_location_and_opts(
                 creds.access_key,
             );
         }
+        // No credentials object provided, fall back to ambient credentials
+        // (environment, profiles, instance metadata) when building the store.
+        // These are the server's credentials, so only allowed if the server
+        // was configured to allow it.
+        (
+            datasource
+            @ (DatasourceUrlType::S3 | DatasourceUrlType::Gcs | DatasourceUrlType::Azure),
+            None,
+        ) => {
+            if !ctx.get_session_vars().allow_ambient_credentials() {
+                return Err(ExtensionError::String(format!(
+                    "Credentials required for accessing {datasource}"
+                )));
+            }
+            if datasource == DatasourceUrlType::S3 {
+                if let Some(region) = opts.remove(REGION_KEY) {
+                    let region: String = region.param_into()?;
+                    storage_options
+                        .inner
+                        .insert(AmazonS3ConfigKey::Region.as_ref().to_string(), region);
+                }
+            }
+        }
         (DatasourceUrlType::Http, _) => {
             return Err(ExtensionError::String(
                 "Accessing delta tables over http not supported".to_string(),
@@ -232,5 +326,52 @@ fn table_location_and_opts(
         }
     };
 
+    // Retry options apply to every object store, regardless of credentials.
+    for key in [RETRIES_OPTION, RETRY_BACKOFF_MS_OPTION] {
+        if let Some(val) = opts.remove(key) {
+            let val: i64 = val.param_into()?;
+            storage_options
+                .inner
+                .insert(key.to_string(), val.to_string());
+        }
+    }
+
     Ok((source_url, storage_options))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::testutil::{utf8, TestContext};
+    use super::*;
+    use datafusion::variable::VarType;
+    use datafusion_ext::vars::SessionVars;
+
+    #[test]
+    fn ambient_credentials_require_server_setting() {
+        let ctx = TestContext::with_vars(SessionVars::default());
+        for url in [
+            "s3://bucket/table",
+            "gs://bucket/table",
+            "azure://container/table",
+        ] {
+            table_location_and_opts(&ctx, vec![utf8(url)], &mut HashMap::new()).unwrap_err();
+        }
+
+        let ctx = TestContext::with_vars(
+            SessionVars::default().with_allow_ambient_credentials(true, VarType::System),
+        );
+        let mut opts = HashMap::from([("region".to_string(), utf8("us-east-1"))]);
+        let (_, storage_options) =
+            table_location_and_opts(&ctx, vec![utf8("s3://bucket/table")], &mut opts).unwrap();
+        assert_eq!(
+            Some(&"us-east-1".to_string()),
+            storage_options
+                .inner
+                .get(AmazonS3ConfigKey::Region.as_ref())
+        );
+
+        // Local files never need credentials.
+        let ctx = TestContext::with_vars(SessionVars::default());
+        table_location_and_opts(&ctx, vec![utf8("./table")], &mut HashMap::new()).unwrap();
+    }
+}
@@ -21,6 +21,10 @@ impl TableFunc for ReadMongoDb {
     fn name(&self) -> &str {
         "read_mongodb"
     }
+
+    fn description(&self) -> &str {
+        "Read a MongoDB collection."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             3,
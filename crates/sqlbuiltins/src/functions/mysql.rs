@@ -21,6 +21,10 @@ impl TableFunc for ReadMysql {
     fn name(&self) -> &str {
         "read_mysql"
     }
+
+    fn description(&self) -> &str {
+        "Read a MySQL table."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             3,
@@ -90,6 +90,15 @@ impl TableFunc for ObjScanTableFunc {
         name
     }
 
+    fn description(&self) -> &str {
+        match self.0 {
+            FileType::PARQUET => "Scan one or more Parquet files.",
+            FileType::CSV => "Scan one or more CSV files.",
+            FileType::JSON => "Scan one or more newline-delimited JSON files.",
+            _ => "",
+        }
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
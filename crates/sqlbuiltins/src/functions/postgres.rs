@@ -21,6 +21,10 @@ impl TableFunc for ReadPostgres {
     fn name(&self) -> &str {
         "read_postgres"
     }
+
+    fn description(&self) -> &str {
+        "Read a Postgres table."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             3,
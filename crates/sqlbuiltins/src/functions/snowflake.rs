@@ -21,6 +21,10 @@ impl TableFunc for ReadSnowflake {
     fn name(&self) -> &str {
         "read_snowflake"
     }
+
+    fn description(&self) -> &str {
+        "Read a Snowflake table."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             8,
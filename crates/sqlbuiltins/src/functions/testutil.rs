@@ -0,0 +1,101 @@
+//! Helpers shared by table function tests.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::Fields;
+use datafusion::execution::context::SessionState;
+use datafusion::prelude::SessionContext;
+use datafusion::scalar::ScalarValue;
+use datafusion_ext::errors::Result;
+use datafusion_ext::functions::{FuncParamValue, TableFuncContextProvider, VirtualLister};
+use datafusion_ext::vars::SessionVars;
+use protogen::metastore::types::catalog::{CredentialsEntry, DatabaseEntry};
+use tempfile::TempDir;
+
+/// Context for calling table functions outside of a session.
+///
+/// There are no databases or credentials, and listing the catalog returns
+/// nothing.
+#[derive(Debug, Default)]
+pub struct TestContext {
+    vars: SessionVars,
+}
+
+impl TestContext {
+    pub fn with_vars(vars: SessionVars) -> Self {
+        TestContext { vars }
+    }
+}
+
+impl TableFuncContextProvider for TestContext {
+    fn get_database_entry(&self, _name: &str) -> Option<&DatabaseEntry> {
+        None
+    }
+
+    fn get_credentials_entry(&self, _name: &str) -> Option<&CredentialsEntry> {
+        None
+    }
+
+    fn get_session_vars(&self) -> SessionVars {
+        self.vars.clone()
+    }
+
+    fn get_session_state(&self) -> SessionState {
+        SessionContext::new().state()
+    }
+
+    fn get_catalog_lister(&self) -> Box<dyn VirtualLister> {
+        Box::new(EmptyLister)
+    }
+}
+
+struct EmptyLister;
+
+#[async_trait]
+impl VirtualLister for EmptyLister {
+    async fn list_schemas(&self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_tables(&self, _schema: &str) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_columns(&self, _schema: &str, _table: &str) -> Result<Fields> {
+        Ok(Fields::empty())
+    }
+}
+
+/// Utf8 scalar function argument.
+pub fn utf8(s: &str) -> FuncParamValue {
+    FuncParamValue::Scalar(ScalarValue::Utf8(Some(s.to_string())))
+}
+
+/// Path to an iceberg table in the repo's test data.
+pub fn iceberg_test_table(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../testdata/iceberg/tables")
+        .join(name)
+}
+
+/// Copy an iceberg table from the test data into a temporary directory so
+/// that tests can modify it.
+pub fn copy_iceberg_test_table(name: &str) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    copy_dir(&iceberg_test_table(name), dir.path());
+    dir
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for ent in std::fs::read_dir(from).unwrap() {
+        let ent = ent.unwrap();
+        let dest = to.join(ent.file_name());
+        if ent.file_type().unwrap().is_dir() {
+            copy_dir(&ent.path(), &dest);
+        } else {
+            std::fs::copy(ent.path(), dest).unwrap();
+        }
+    }
+}
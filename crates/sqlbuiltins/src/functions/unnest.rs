@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::array::new_empty_array;
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::{MemTable, TableProvider};
+use datafusion::scalar::ScalarValue;
+use datafusion_ext::errors::{ExtensionError, Result};
+use datafusion_ext::functions::{FuncParamValue, TableFunc, TableFuncContextProvider};
+use protogen::metastore::types::catalog::RuntimePreference;
+
+/// Expand an array into a set of rows, one row per element.
+///
+/// The array must be constant, e.g. `unnest([1, 2, 3])` or
+/// `unnest(make_array(1, 2, 3))`. Unnesting a column from an earlier relation
+/// in the FROM clause (`SELECT * FROM t, unnest(t.arr)`) doesn't go through this
+/// function, and is instead planned as an unnest over that relation.
+#[derive(Debug, Clone, Copy)]
+pub struct Unnest;
+
+#[async_trait]
+impl TableFunc for Unnest {
+    fn runtime_preference(&self) -> RuntimePreference {
+        RuntimePreference::Unspecified
+    }
+    fn detect_runtime(
+        &self,
+        _: &[FuncParamValue],
+        parent: RuntimePreference,
+    ) -> Result<RuntimePreference> {
+        match parent {
+            RuntimePreference::Unspecified => Ok(RuntimePreference::Local),
+            other => Ok(other),
+        }
+    }
+    fn name(&self) -> &str {
+        "unnest"
+    }
+
+    fn description(&self) -> &str {
+        "Expand an array into a set of rows."
+    }
+
+    async fn create_provider(
+        &self,
+        _: &dyn TableFuncContextProvider,
+        args: Vec<FuncParamValue>,
+        _: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if args.len() != 1 {
+            return Err(ExtensionError::InvalidNumArgs);
+        }
+
+        let (values, data_type) = match args.into_iter().next().unwrap() {
+            // Array literal, e.g. `[1, 2, 3]`.
+            FuncParamValue::Array(vals) => {
+                let vals = vals
+                    .into_iter()
+                    .map(|val| match val {
+                        FuncParamValue::Scalar(s) => Ok(s),
+                        other => Err(ExtensionError::InvalidParamValue {
+                            param: other.to_string(),
+                            expected: "array of scalars",
+                        }),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let data_type = vals
+                    .first()
+                    .map(|val| val.data_type())
+                    .unwrap_or(DataType::Null);
+                (vals, data_type)
+            }
+            // Constant folded list, e.g. `make_array(1, 2, 3)`.
+            FuncParamValue::Scalar(ScalarValue::List(vals, field)) => {
+                (vals.unwrap_or_default(), field.data_type().clone())
+            }
+            other => {
+                return Err(ExtensionError::InvalidParamValue {
+                    param: other.to_string(),
+                    expected: "array",
+                })
+            }
+        };
+
+        let arr = if values.is_empty() {
+            new_empty_array(&data_type)
+        } else {
+            ScalarValue::iter_to_array(values)?
+        };
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "unnest",
+            arr.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![arr])?;
+
+        Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+    }
+}
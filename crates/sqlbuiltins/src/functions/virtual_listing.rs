@@ -37,6 +37,10 @@ impl TableFunc for ListSchemas {
         "list_schemas"
     }
 
+    fn description(&self) -> &str {
+        "List schemas in an external database."
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
@@ -84,6 +88,10 @@ impl TableFunc for ListTables {
         "list_tables"
     }
 
+    fn description(&self) -> &str {
+        "List tables in a schema of an external database."
+    }
+
     async fn create_provider(
         &self,
         ctx: &dyn TableFuncContextProvider,
@@ -131,6 +139,10 @@ impl TableFunc for ListColumns {
     fn name(&self) -> &str {
         "list_columns"
     }
+
+    fn description(&self) -> &str {
+        "List columns of a table in an external database."
+    }
     fn signature(&self) -> Option<Signature> {
         Some(Signature::uniform(
             3,
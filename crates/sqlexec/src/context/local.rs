@@ -1,8 +1,10 @@
 use crate::background_jobs::JobRunner;
+use crate::dispatch::ViewExpansionStack;
 use crate::environment::EnvironmentReader;
 use crate::errors::{internal, ExecError, Result};
 use crate::metastore::catalog::{CatalogMutator, SessionCatalog, TempCatalog};
 use crate::metrics::SessionMetricsHandler;
+use crate::notice::SessionNotices;
 use crate::parser::StatementWithExtensions;
 use crate::planner::logical_plan::*;
 use crate::planner::session_planner::SessionPlanner;
@@ -24,6 +26,7 @@ use protogen::rpcsrv::types::service::{
     InitializeSessionRequest, InitializeSessionRequestFromClient,
 };
 use sqlbuiltins::builtins::DEFAULT_CATALOG;
+use sqlbuiltins::functions::{BuiltinTableFuncs, BUILTIN_TABLE_FUNCS};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::slice;
@@ -60,8 +63,12 @@ pub struct LocalSessionContext {
     df_ctx: DfSessionContext,
     /// Read tables from the environment.
     env_reader: Option<Box<dyn EnvironmentReader>>,
+    /// Table functions to use instead of the global builtins.
+    table_funcs: Option<Arc<BuiltinTableFuncs>>,
     /// Job runner for background jobs.
     _background_jobs: JobRunner,
+    /// Views currently being planned, used to detect cycles between views.
+    view_expansion: ViewExpansionStack,
 }
 
 impl LocalSessionContext {
@@ -84,7 +91,8 @@ impl LocalSessionContext {
         conf = conf
             .with_extension(Arc::new(catalog_mutator))
             .with_extension(Arc::new(native_tables.clone()))
-            .with_extension(Arc::new(TempCatalog::default()));
+            .with_extension(Arc::new(TempCatalog::default()))
+            .with_extension(Arc::new(SessionNotices::default()));
 
         let state = SessionState::new_with_config_rt(conf, Arc::new(runtime))
             .add_physical_optimizer_rule(Arc::new(RuntimeGroupPullUp {}));
@@ -102,7 +110,9 @@ impl LocalSessionContext {
             metrics_handler,
             df_ctx,
             env_reader: None,
+            table_funcs: None,
             _background_jobs: background_jobs,
+            view_expansion: ViewExpansionStack::default(),
         })
     }
 
@@ -134,7 +144,8 @@ impl LocalSessionContext {
         conf = conf
             .with_extension(Arc::new(CatalogMutator::empty()))
             .with_extension(Arc::new(self.get_native_tables().clone()))
-            .with_extension(Arc::new(TempCatalog::default()));
+            .with_extension(Arc::new(TempCatalog::default()))
+            .with_extension(self.get_notices());
 
         let state = SessionState::new_with_config_rt(conf, runtime)
             .add_physical_optimizer_rule(Arc::new(RuntimeGroupPullUp {}));
@@ -157,6 +168,15 @@ impl LocalSessionContext {
         self.env_reader.as_deref()
     }
 
+    pub fn register_table_funcs(&mut self, table_funcs: Arc<BuiltinTableFuncs>) {
+        self.table_funcs = Some(table_funcs);
+    }
+
+    /// Get the table functions available to this session.
+    pub fn get_table_funcs(&self) -> &BuiltinTableFuncs {
+        self.table_funcs.as_deref().unwrap_or(&BUILTIN_TABLE_FUNCS)
+    }
+
     pub fn get_metrics_handler(&self) -> SessionMetricsHandler {
         self.metrics_handler.clone()
     }
@@ -169,6 +189,10 @@ impl LocalSessionContext {
         &self.tables
     }
 
+    pub fn get_view_expansion(&self) -> &ViewExpansionStack {
+        &self.view_expansion
+    }
+
     pub fn get_temp_objects(&self) -> Arc<TempCatalog> {
         self.df_ctx
             .state()
@@ -177,6 +201,14 @@ impl LocalSessionContext {
             .expect("local contexts should have temp objects")
     }
 
+    pub fn get_notices(&self) -> Arc<SessionNotices> {
+        self.df_ctx
+            .state()
+            .config()
+            .get_extension::<SessionNotices>()
+            .expect("local contexts should have notices")
+    }
+
     /// Return the DF session context.
     pub fn df_ctx(&self) -> &DfSessionContext {
         &self.df_ctx
@@ -363,6 +395,34 @@ impl LocalSessionContext {
         Ok(r)
     }
 
+    /// Resolve a reference to an existing table.
+    ///
+    /// Unlike `resolve_table_ref`, bare names are resolved by checking each
+    /// schema in the search path in order, matching how tables are resolved
+    /// in queries. If the table doesn't exist in any schema, this falls back to
+    /// the first non-implicit schema.
+    pub fn resolve_existing_table_ref(
+        &self,
+        r: TableReference<'_>,
+    ) -> Result<OwnedFullObjectReference> {
+        if let TableReference::Bare { table } = &r {
+            for schema in self.implicit_search_paths() {
+                if self
+                    .catalog
+                    .resolve_entry(DEFAULT_CATALOG, &schema, table)
+                    .is_some()
+                {
+                    return Ok(FullObjectReference {
+                        database: DEFAULT_CATALOG.into(),
+                        schema: schema.into(),
+                        name: table.to_string().into(),
+                    });
+                }
+            }
+        }
+        self.resolve_table_ref(r)
+    }
+
     /// Iterate over the implicit search path. This will have all implicit
     /// schemas prepended to the iterator.
     ///
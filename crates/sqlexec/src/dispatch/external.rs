@@ -535,10 +535,10 @@ impl<'a> ExternalDispatcher<'a> {
             // We only have builtin functions right now.
             None
         };
-        let prov = resolve_func
-            .unwrap()
-            .create_provider(self, args, opts)
-            .await?;
+        let resolve_func = resolve_func.unwrap();
+        resolve_func.validate_args(&args, &opts)?;
+
+        let prov = resolve_func.create_provider(self, args, opts).await?;
         Ok(prov)
     }
 
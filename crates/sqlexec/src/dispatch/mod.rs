@@ -5,6 +5,7 @@ pub mod system;
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use datafusion::datasource::{TableProvider, ViewTable};
@@ -12,9 +13,13 @@ use datafusion::execution::context::SessionState;
 use datafusion::logical_expr::{LogicalPlan, LogicalPlanBuilder};
 use datafusion::prelude::SessionContext as DfSessionContext;
 use datafusion::prelude::{Column, Expr};
-use datafusion_ext::functions::{FuncParamValue, TableFuncContextProvider, VirtualLister};
+use datafusion_ext::functions::{
+    FuncParamValue, TableFunc, TableFuncContextProvider, VirtualLister,
+};
+use datafusion_ext::metrics::TableFuncMetricsProvider;
 use datafusion_ext::vars::SessionVars;
 use datasources::native::access::NativeTableStorage;
+use parking_lot::Mutex;
 use protogen::metastore::types::catalog::{
     CatalogEntry, CredentialsEntry, DatabaseEntry, EntryMeta, EntryType, FunctionEntry, ViewEntry,
 };
@@ -123,20 +128,24 @@ impl DispatchError {
 /// statement and column aliases. We don't track view dependencies.
 #[async_trait]
 pub trait ViewPlanner: Send + Sync {
-    /// Plan a view from SQL, producing a logical plan with the provided
-    /// aliases.
+    /// Plan a view from its SQL, producing a logical plan with the view's
+    /// column aliases.
     ///
-    /// If no column aliases are provided, then columns should be returned
+    /// If the view has no column aliases, then columns should be returned
     /// as-is.
-    async fn plan_view(&self, sql: &str, col_aliases: &[String]) -> Result<LogicalPlan, PlanError>;
+    async fn plan_view(&self, view: &ViewEntry) -> Result<LogicalPlan, PlanError>;
 }
 
 #[async_trait]
 impl ViewPlanner for LocalSessionContext {
-    async fn plan_view(&self, sql: &str, col_aliases: &[String]) -> Result<LogicalPlan, PlanError> {
+    async fn plan_view(&self, view: &ViewEntry) -> Result<LogicalPlan, PlanError> {
+        // Views are planned recursively as they're referenced, so a view that
+        // (indirectly) references itself would never finish planning.
+        let _guard = self.get_view_expansion().enter(view)?;
+
         // TODO: Instead of doing late planning, we should instead try to insert
         // the contents of the view into the parent query prior to any planning.
-        let mut statements = CustomParser::parse_sql(sql)?;
+        let mut statements = CustomParser::parse_sql(&view.sql)?;
         if statements.len() != 1 {
             return Err(PlanError::ExpectedExactlyOneStatement(
                 statements.into_iter().collect(),
@@ -150,6 +159,7 @@ impl ViewPlanner for LocalSessionContext {
 
         // Wrap logical plan in projection if the view was defined with
         // column aliases.
+        let col_aliases = &view.columns;
         if !col_aliases.is_empty() {
             let fields = df_plan.schema().fields().clone();
             df_plan = LogicalPlanBuilder::from(df_plan)
@@ -163,6 +173,45 @@ impl ViewPlanner for LocalSessionContext {
     }
 }
 
+/// Views currently being planned, outermost first.
+#[derive(Debug, Default)]
+pub struct ViewExpansionStack {
+    views: Mutex<Vec<(u32, String)>>,
+}
+
+impl ViewExpansionStack {
+    /// Mark a view as being planned until the returned guard is dropped.
+    ///
+    /// Errors if the view is already being planned, meaning the view depends
+    /// on itself.
+    pub fn enter(&self, view: &ViewEntry) -> Result<ViewExpansionGuard<'_>, PlanError> {
+        let mut views = self.views.lock();
+        if let Some(idx) = views.iter().position(|(oid, _)| *oid == view.meta.id) {
+            let cycle: Vec<_> = views[idx..]
+                .iter()
+                .map(|(_, name)| name.as_str())
+                .chain([view.meta.name.as_str()])
+                .collect();
+            return Err(PlanError::ViewDependencyCycle(cycle.join(" -> ")));
+        }
+        views.push((view.meta.id, view.meta.name.clone()));
+
+        Ok(ViewExpansionGuard { stack: self })
+    }
+}
+
+/// Removes a view from the expansion stack when dropped.
+#[derive(Debug)]
+pub struct ViewExpansionGuard<'a> {
+    stack: &'a ViewExpansionStack,
+}
+
+impl Drop for ViewExpansionGuard<'_> {
+    fn drop(&mut self) {
+        self.stack.views.lock().pop();
+    }
+}
+
 /// Dispatch to table providers.
 pub struct Dispatcher<'a> {
     catalog: &'a SessionCatalog,
@@ -248,7 +297,7 @@ impl<'a> Dispatcher<'a> {
     async fn dispatch_view(&self, view: &ViewEntry) -> Result<Arc<dyn TableProvider>> {
         let plan = self
             .view_planner
-            .plan_view(&view.sql, &view.columns)
+            .plan_view(view)
             .await
             .map_err(|e| DispatchError::ViewPlanning(Box::new(e)))?;
         Ok(Arc::new(ViewTable::try_new(plan, None)?))
@@ -266,11 +315,30 @@ impl<'a> Dispatcher<'a> {
             // We only have builtin functions right now.
             None
         };
-        let prov = resolve_func
-            .unwrap()
-            .create_provider(self, args, opts)
-            .await?;
-        Ok(prov)
+        let resolve_func = resolve_func.unwrap();
+        self.dispatch_table_func(resolve_func, args, opts).await
+    }
+
+    /// Dispatch to a table function that isn't in the catalog, e.g. one
+    /// registered at runtime.
+    pub async fn dispatch_table_func(
+        &self,
+        func: Arc<dyn TableFunc>,
+        args: Vec<FuncParamValue>,
+        opts: HashMap<String, FuncParamValue>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        func.validate_args(&args, &opts)?;
+
+        let start = Instant::now();
+        let prov = func.create_provider(self, args, opts).await?;
+
+        // Provider creation is where table functions fetch metadata, record
+        // it so it shows up in EXPLAIN ANALYZE.
+        Ok(Arc::new(TableFuncMetricsProvider::new(
+            func.name(),
+            start.elapsed(),
+            prov,
+        )))
     }
 }
 
@@ -33,6 +33,7 @@ use crate::planner::logical_plan as plan;
 use crate::planner::physical_plan::alter_database::AlterDatabaseExec;
 use crate::planner::physical_plan::alter_table::AlterTableExec;
 use crate::planner::physical_plan::alter_tunnel_rotate_keys::AlterTunnelRotateKeysExec;
+use crate::planner::physical_plan::copy_from::CopyFromExec;
 use crate::planner::physical_plan::copy_to::CopyToExec;
 use crate::planner::physical_plan::create_credentials::CreateCredentialsExec;
 use crate::planner::physical_plan::create_external_database::CreateExternalDatabaseExec;
@@ -51,9 +52,11 @@ use crate::planner::physical_plan::drop_tables::DropTablesExec;
 use crate::planner::physical_plan::drop_tunnel::DropTunnelExec;
 use crate::planner::physical_plan::drop_views::DropViewsExec;
 use crate::planner::physical_plan::insert::InsertExec;
+use crate::planner::physical_plan::merge_into::MergeIntoExec;
 use crate::planner::physical_plan::remote_scan::ProviderReference;
 use crate::planner::physical_plan::set_var::SetVarExec;
 use crate::planner::physical_plan::show_var::ShowVarExec;
+use crate::planner::physical_plan::truncate_tables::TruncateTablesExec;
 use crate::planner::physical_plan::update::UpdateExec;
 use crate::planner::physical_plan::values::ExtValuesExec;
 use crate::planner::physical_plan::{
@@ -254,6 +257,17 @@ impl<'a> LogicalExtensionCodec for GlareDBExtensionCodec<'a> {
             PlanType::CopyTo(copy_to) => plan::CopyTo::try_decode(copy_to, ctx, self)
                 .map_err(|e| DataFusionError::External(Box::new(e)))?
                 .into_extension(),
+            PlanType::TruncateTables(truncate_tables) => {
+                plan::TruncateTables::try_decode(truncate_tables, ctx, self)
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?
+                    .into_extension()
+            }
+            PlanType::MergeInto(merge_into) => plan::MergeInto::try_decode(merge_into, ctx, self)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?
+                .into_extension(),
+            PlanType::CopyFrom(copy_from) => plan::CopyFrom::try_decode(copy_from, ctx, self)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?
+                .into_extension(),
         })
     }
 
@@ -309,10 +323,15 @@ impl<'a> LogicalExtensionCodec for GlareDBExtensionCodec<'a> {
             ExtensionType::ShowVariable => {
                 plan::ShowVariable::try_encode_extension(node, buf, self)
             }
+            ExtensionType::TruncateTables => {
+                plan::TruncateTables::try_encode_extension(node, buf, self)
+            }
             ExtensionType::CopyTo => plan::CopyTo::try_encode_extension(node, buf, self),
             ExtensionType::Update => plan::Update::try_encode_extension(node, buf, self),
             ExtensionType::Delete => plan::Update::try_encode_extension(node, buf, self),
             ExtensionType::Insert => plan::Insert::try_encode_extension(node, buf, self),
+            ExtensionType::MergeInto => plan::MergeInto::try_encode_extension(node, buf, self),
+            ExtensionType::CopyFrom => plan::CopyFrom::try_encode_extension(node, buf, self),
         }
         .map_err(|e| DataFusionError::External(Box::new(e)))?;
         Ok(())
@@ -486,6 +505,10 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                         DataFusionError::Internal("missing alter table operation".to_string())
                     })?
                     .try_into()?,
+                backfill: ext
+                    .backfill
+                    .map(|expr| parse_expr(&expr, registry))
+                    .transpose()?,
             }),
             proto::ExecutionPlanExtensionType::AlterTunnelRotateKeysExec(ext) => {
                 Arc::new(AlterTunnelRotateKeysExec {
@@ -613,6 +636,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 sql: ext.sql,
                 columns: ext.columns,
                 or_replace: ext.or_replace,
+                if_not_exists: ext.if_not_exists,
             }),
             proto::ExecutionPlanExtensionType::DropCredentialsExec(ext) => {
                 Arc::new(DropCredentialsExec {
@@ -695,6 +719,49 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     where_expr,
                 })
             }
+            proto::ExecutionPlanExtensionType::MergeIntoExec(ext) => {
+                let on = ext.on.ok_or_else(|| {
+                    DataFusionError::Internal("missing on expression".to_string())
+                })?;
+                Arc::new(MergeIntoExec {
+                    table: ext
+                        .table
+                        .ok_or_else(|| DataFusionError::Internal("missing table".to_string()))?
+                        .try_into()?,
+                    source: inputs
+                        .get(0)
+                        .ok_or_else(|| {
+                            DataFusionError::Internal("missing input source".to_string())
+                        })?
+                        .clone(),
+                    target_alias: ext.target_alias,
+                    source_alias: ext.source_alias,
+                    on: parse_expr(&on, registry)?,
+                    clauses: plan::merge_clauses_from_proto(ext.clauses, registry)?,
+                })
+            }
+            proto::ExecutionPlanExtensionType::CopyFromExec(ext) => Arc::new(CopyFromExec {
+                table: ext
+                    .table
+                    .ok_or_else(|| DataFusionError::Internal("missing table".to_string()))?
+                    .try_into()?,
+                location: ext.location,
+                file_type: ext.file_type,
+                storage_options: ext
+                    .storage_options
+                    .ok_or_else(|| {
+                        DataFusionError::Internal("missing storage options".to_string())
+                    })?
+                    .try_into()?,
+            }),
+            proto::ExecutionPlanExtensionType::TruncateTablesExec(ext) => {
+                let tables = ext
+                    .tables
+                    .into_iter()
+                    .map(|t| t.try_into())
+                    .collect::<Result<_, _>>()?;
+                Arc::new(TruncateTablesExec { tables })
+            }
             proto::ExecutionPlanExtensionType::CopyToExec(ext) => Arc::new(CopyToExec {
                 format: ext
                     .format
@@ -842,7 +909,12 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 catalog_version: exec.catalog_version,
                 schema: exec.schema.to_owned(),
                 name: exec.name.to_owned(),
-                operation: Some(exec.operation.clone().into()),
+                operation: Some(exec.operation.clone().try_into()?),
+                backfill: exec
+                    .backfill
+                    .as_ref()
+                    .map(|expr| expr.try_into())
+                    .transpose()?,
             })
         } else if let Some(exec) = node.as_any().downcast_ref::<AlterTunnelRotateKeysExec>() {
             proto::ExecutionPlanExtensionType::AlterTunnelRotateKeysExec(
@@ -923,6 +995,7 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                 sql: exec.sql.clone(),
                 columns: exec.columns.clone(),
                 or_replace: exec.or_replace,
+                if_not_exists: exec.if_not_exists,
             })
         } else if let Some(exec) = node.as_any().downcast_ref::<DescribeTableExec>() {
             proto::ExecutionPlanExtensionType::DescribeTable(proto::DescribeTableExec {
@@ -994,6 +1067,30 @@ impl<'a> PhysicalExtensionCodec for GlareDBExtensionCodec<'a> {
                     .map(|expr| expr.try_into())
                     .transpose()?,
             })
+        } else if let Some(exec) = node.as_any().downcast_ref::<MergeIntoExec>() {
+            proto::ExecutionPlanExtensionType::MergeIntoExec(proto::MergeIntoExec {
+                table: Some(exec.table.clone().try_into()?),
+                target_alias: exec.target_alias.clone(),
+                source_alias: exec.source_alias.clone(),
+                on: Some((&exec.on).try_into()?),
+                clauses: plan::merge_clauses_to_proto(&exec.clauses)?,
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<CopyFromExec>() {
+            proto::ExecutionPlanExtensionType::CopyFromExec(proto::CopyFromExec {
+                table: Some(exec.table.clone().try_into()?),
+                location: exec.location.clone(),
+                file_type: exec.file_type.clone(),
+                storage_options: Some(exec.storage_options.clone().into()),
+            })
+        } else if let Some(exec) = node.as_any().downcast_ref::<TruncateTablesExec>() {
+            let tables = exec
+                .tables
+                .iter()
+                .map(|t| t.clone().try_into())
+                .collect::<Result<_, _>>()?;
+            proto::ExecutionPlanExtensionType::TruncateTablesExec(proto::TruncateTablesExec {
+                tables,
+            })
         } else if let Some(exec) = node.as_any().downcast_ref::<CopyToExec>() {
             proto::ExecutionPlanExtensionType::CopyToExec(proto::CopyToExec {
                 format: Some(exec.format.clone().try_into()?),
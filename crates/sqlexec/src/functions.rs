@@ -208,24 +208,9 @@ impl FromStr for BuiltinPostgresFunctions {
             "user" => Ok(Self::User),
             "current_schema" => Ok(Self::CurrentSchema),
             "current_database" => Ok(Self::CurrentDatabase),
-            s => {
-                let idents: Vec<_> = s.split('.').collect();
-                if idents.len() != 2 {
-                    warn!(
-                        ?idents,
-                        "received pg function name with more than two idents"
-                    );
-                    return Err(datafusion::common::DataFusionError::NotImplemented(
-                        format!("BuiltinScalarFunction::from_str({})", s),
-                    ));
-                }
-                if idents[0] != POSTGRES_SCHEMA {
-                    return Err(datafusion::common::DataFusionError::NotImplemented(
-                        format!("BuiltinScalarFunction::from_str({})", s),
-                    ));
-                }
-                Self::from_str(idents[1])
-            }
+            s => Err(datafusion::common::DataFusionError::NotImplemented(
+                format!("BuiltinPostgresFunctions::from_str({})", s),
+            )),
         }
     }
 }
@@ -234,10 +219,27 @@ impl FromStr for BuiltinScalarFunction {
     type Err = datafusion::common::DataFusionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
+        let s = s.to_lowercase();
+
+        // Builtin functions may be qualified with the postgres schema, e.g.
+        // `pg_catalog.version()`.
+        let name = match s.split_once('.') {
+            Some((schema, name)) => {
+                if schema != POSTGRES_SCHEMA || name.contains('.') {
+                    warn!(%s, "received function name with unknown qualifier");
+                    return Err(datafusion::common::DataFusionError::NotImplemented(
+                        format!("BuiltinScalarFunction::from_str({})", s),
+                    ));
+                }
+                name
+            }
+            None => s.as_str(),
+        };
+
+        match name {
             "connection_id" => Ok(Self::ConnectionId),
             "version" => Ok(Self::Version),
-            s => BuiltinPostgresFunctions::from_str(s).map(Self::Pg),
+            name => BuiltinPostgresFunctions::from_str(name).map(Self::Pg),
         }
     }
 }
@@ -408,6 +410,9 @@ mod tests {
                 HasDatabasePrivilege.into(),
             ),
             ("pg_catalog.has_table_privilege", HasTablePrivilege.into()),
+            ("pg_catalog.version", Version),
+            ("pg_catalog.connection_id", ConnectionId),
+            ("PG_CATALOG.Version", Version),
         ];
         for (s, expected) in pairs {
             let func = BuiltinScalarFunction::from_str(s).unwrap();
@@ -419,6 +424,7 @@ mod tests {
             "pg_get_userbyid.foo",
             "pg_catalo.pg_get_userbyid.",
             "test.pg_catalog.pg_get_userbyid",
+            "public.version",
         ];
 
         for s in failures {
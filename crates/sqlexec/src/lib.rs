@@ -5,6 +5,7 @@ pub mod environment;
 pub mod errors;
 pub mod extension_codec;
 pub mod metastore;
+pub mod notice;
 pub mod parser;
 pub mod remote;
 pub mod session;
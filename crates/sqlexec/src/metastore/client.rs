@@ -641,6 +641,7 @@ mod tests {
                     sql: "select 1".to_string(),
                     or_replace: false,
                     columns: Vec::new(),
+                    if_not_exists: false,
                 })],
             )
             .await
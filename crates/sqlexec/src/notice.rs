@@ -0,0 +1,70 @@
+//! Notices sent to the client while a query is executing.
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+
+/// Number of notices buffered for a subscriber before new notices are
+/// dropped.
+const NOTICE_BUFFER_SIZE: usize = 64;
+
+/// An informational message for the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notice {
+    pub message: String,
+}
+
+impl Notice {
+    pub fn new(message: impl Into<String>) -> Notice {
+        Notice {
+            message: message.into(),
+        }
+    }
+}
+
+/// Notices for a session.
+///
+/// Registered as an extension on the datafusion session config so that
+/// execution plans can send notices to the client. Notices are dropped if
+/// nothing is subscribed, or if the subscriber isn't keeping up.
+#[derive(Debug, Default)]
+pub struct SessionNotices {
+    sender: Mutex<Option<mpsc::Sender<Notice>>>,
+}
+
+impl SessionNotices {
+    /// Subscribe to notices for this session.
+    ///
+    /// Only the most recent subscriber receives notices.
+    pub fn subscribe(&self) -> mpsc::Receiver<Notice> {
+        let (sender, receiver) = mpsc::channel(NOTICE_BUFFER_SIZE);
+        *self.sender.lock() = Some(sender);
+        receiver
+    }
+
+    /// Send a notice to the subscriber, if any.
+    pub fn send(&self, notice: Notice) {
+        if let Some(sender) = self.sender.lock().as_ref() {
+            let _ = sender.try_send(notice);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notices_sent_to_latest_subscriber() {
+        let notices = SessionNotices::default();
+        // Nothing subscribed, dropped.
+        notices.send(Notice::new("dropped"));
+
+        let mut first = notices.subscribe();
+        notices.send(Notice::new("first"));
+        assert_eq!(Notice::new("first"), first.try_recv().unwrap());
+
+        let mut second = notices.subscribe();
+        notices.send(Notice::new("second"));
+        assert_eq!(Notice::new("second"), second.try_recv().unwrap());
+        assert!(first.try_recv().is_err());
+    }
+}
@@ -359,6 +359,76 @@ impl fmt::Display for CopyToStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyFromStmt {
+    /// Table to load the data into.
+    pub table: ObjectName,
+    /// Source to load the data from.
+    pub source: Ident,
+    /// Optional format of the source data.
+    pub format: Option<Ident>,
+    /// Optional credentials (for cloud storage).
+    pub credentials: Option<Ident>,
+    /// COPY FROM specific options.
+    pub options: StmtOptions,
+}
+
+impl fmt::Display for CopyFromStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "COPY {} FROM {}", self.table, self.source)?;
+        if let Some(format) = self.format.as_ref() {
+            write!(f, " FORMAT {format}")?;
+        }
+        if let Some(creds) = self.credentials.as_ref() {
+            write!(f, " CREDENTIALS {creds}")?;
+        }
+        if !self.options.is_empty() {
+            write!(f, " {}", self.options)?;
+        }
+        Ok(())
+    }
+}
+
+/// A CREATE VIEW statement with IF NOT EXISTS, which the underlying parser
+/// does not accept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateViewStmt {
+    /// Name of the view.
+    pub name: ObjectName,
+    /// Optional column aliases.
+    pub columns: Vec<Ident>,
+    /// Body of the view.
+    pub query: ast::Query,
+    /// Optionally replace the view.
+    pub or_replace: bool,
+    /// Optionally skip creating the view if it already exists.
+    pub if_not_exists: bool,
+}
+
+impl fmt::Display for CreateViewStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if self.or_replace {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "VIEW ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.columns.is_empty() {
+            write!(f, " (")?;
+            let mut sep = "";
+            for col in self.columns.iter() {
+                write!(f, "{sep}{col}")?;
+                sep = ", ";
+            }
+            write!(f, ")")?;
+        }
+        write!(f, " AS {}", self.query)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StatementWithExtensions {
     /// Statement parsed by `sqlparser`.
@@ -385,6 +455,10 @@ pub enum StatementWithExtensions {
     DropCredentials(DropCredentialsStmt),
     /// Copy To extension.
     CopyTo(CopyToStmt),
+    /// Copy From extension.
+    CopyFrom(CopyFromStmt),
+    /// Create view extension (IF NOT EXISTS).
+    CreateView(CreateViewStmt),
 }
 
 impl fmt::Display for StatementWithExtensions {
@@ -402,6 +476,8 @@ impl fmt::Display for StatementWithExtensions {
             StatementWithExtensions::CreateCredentials(stmt) => write!(f, "{}", stmt),
             StatementWithExtensions::DropCredentials(stmt) => write!(f, "{}", stmt),
             StatementWithExtensions::CopyTo(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::CopyFrom(stmt) => write!(f, "{}", stmt),
+            StatementWithExtensions::CreateView(stmt) => write!(f, "{}", stmt),
         }
     }
 }
@@ -530,6 +606,14 @@ impl<'a> CustomParser<'a> {
         } else if self.parser.parse_keyword(Keyword::CREDENTIALS) {
             // CREATE CREDENTIALS ...
             self.parse_create_credentials()
+        } else if self.parser.parse_keywords(&[
+            Keyword::VIEW,
+            Keyword::IF,
+            Keyword::NOT,
+            Keyword::EXISTS,
+        ]) {
+            // CREATE VIEW IF NOT EXISTS ...
+            self.parse_create_view_if_not_exists(or_replace)
         } else {
             // Fall back to underlying parser.
 
@@ -545,6 +629,37 @@ impl<'a> CustomParser<'a> {
         }
     }
 
+    /// Parse the rest of a CREATE VIEW IF NOT EXISTS statement.
+    fn parse_create_view_if_not_exists(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<StatementWithExtensions, ParserError> {
+        let name = self.parser.parse_object_name()?;
+
+        // [(col, ..)]
+        let columns = if self.parser.consume_token(&Token::LParen) {
+            let columns = self
+                .parser
+                .parse_comma_separated(Parser::parse_identifier)?;
+            self.parser.expect_token(&Token::RParen)?;
+            columns
+        } else {
+            Vec::new()
+        };
+
+        // AS query
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = self.parser.parse_query()?;
+
+        Ok(StatementWithExtensions::CreateView(CreateViewStmt {
+            name,
+            columns,
+            query,
+            or_replace,
+            if_not_exists: true,
+        }))
+    }
+
     /// Parse a SQL ALTER statement
     fn parse_alter(&mut self) -> Result<StatementWithExtensions, ParserError> {
         if self.parser.parse_keyword(Keyword::DATABASE) {
@@ -575,6 +690,9 @@ impl<'a> CustomParser<'a> {
             CopyToSource::Query(query)
         } else {
             let table_name = self.parser.parse_object_name()?;
+            if self.parser.parse_keyword(Keyword::FROM) {
+                return self.parse_copy_from(table_name);
+            }
             CopyToSource::Table(table_name)
         };
 
@@ -600,6 +718,32 @@ impl<'a> CustomParser<'a> {
         }))
     }
 
+    /// Parse the rest of a COPY FROM statement.
+    fn parse_copy_from(
+        &mut self,
+        table: ObjectName,
+    ) -> Result<StatementWithExtensions, ParserError> {
+        // FROM 'source'
+        let source = self.parser.parse_identifier()?;
+
+        // [FORMAT ..]
+        let format = self.parse_data_format()?;
+
+        // [CREDENTIALS ..]
+        let credentials = self.parse_connection_credentials()?;
+
+        // OPTIONS (..)
+        let options = self.parse_options()?;
+
+        Ok(StatementWithExtensions::CopyFrom(CopyFromStmt {
+            table,
+            source,
+            format,
+            credentials,
+            options,
+        }))
+    }
+
     /// Report unexpected token.
     fn expected<T>(&self, expected: &str, found: Token) -> Result<T, ParserError> {
         Err(ParserError::ParserError(format!(
@@ -1294,6 +1438,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn copy_from_roundtrips() {
+        let test_cases = [
+            "COPY table FROM 's3://bucket/data.parquet'",
+            "COPY table FROM 'data.csv' FORMAT csv",
+            "COPY table FROM 's3://bucket/data' FORMAT parquet CREDENTIALS aws_creds",
+            "COPY table FROM 's3://bucket/data.json' OPTIONS (region = 'us-east-1')",
+        ];
+
+        for test_case in test_cases {
+            let stmt = CustomParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert_eq!(test_case, stmt.to_string().as_str());
+        }
+    }
+
+    #[test]
+    fn create_view_if_not_exists_roundtrips() {
+        let test_cases = [
+            "CREATE VIEW IF NOT EXISTS v AS SELECT 1",
+            "CREATE OR REPLACE VIEW IF NOT EXISTS s.v (a, b) AS SELECT 1, 2",
+        ];
+
+        for test_case in test_cases {
+            let stmt = CustomParser::parse_sql(test_case)
+                .unwrap()
+                .pop_front()
+                .unwrap();
+            assert!(matches!(stmt, StatementWithExtensions::CreateView(_)));
+            assert_eq!(test_case, stmt.to_string().as_str());
+        }
+    }
+
     #[test]
     fn options_parse() {
         let mut options = BTreeMap::new();
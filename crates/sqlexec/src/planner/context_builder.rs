@@ -179,6 +179,36 @@ impl<'a> PartialContextProvider<'a> {
             }
         }
 
+        // Table functions registered at runtime don't have catalog entries,
+        // and only exist in this process so can't be run remotely.
+        let registered = match (&reference, &args) {
+            (TableReference::Bare { table }, Some(_)) => {
+                self.ctx.get_table_funcs().find_registered_function(table)
+            }
+            _ => None,
+        };
+        if let Some(func) = registered {
+            let args = args.unwrap_or_default();
+            let runtime = func
+                .detect_runtime(&args, self.runtime_preference)
+                .map_err(DispatchError::ExtensionError)?;
+            if runtime == RuntimePreference::Remote {
+                return Err(PlanError::String(format!(
+                    "Table function '{}' was registered locally and can't run remotely",
+                    func.name()
+                )));
+            }
+
+            let provider = self
+                .new_dispatcher()
+                .dispatch_table_func(func, args, opts.unwrap_or_default())
+                .await?;
+            return Ok(RuntimeAwareTableProvider::new(
+                RuntimePreference::Local,
+                provider,
+            ));
+        }
+
         let ent = self
             .resolver
             .resolve_entry_from_reference(reference.clone())?;
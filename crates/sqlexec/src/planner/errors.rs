@@ -49,9 +49,21 @@ pub enum PlanError {
     #[error("Invalid insert statement: {msg}")]
     InvalidInsertStatement { msg: &'static str },
 
+    #[error("Invalid merge statement: {msg}")]
+    InvalidMergeStatement { msg: &'static str },
+
     #[error("Invalid alter statement: {msg}")]
     InvalidAlterStatement { msg: &'static str },
 
+    #[error("Unsupported COPY FROM format: {0}")]
+    UnsupportedCopyFromFormat(String),
+
+    #[error("Unable to infer the format of '{location}', specify it with FORMAT")]
+    UnknownCopyFromFormat { location: String },
+
+    #[error("view dependency cycle detected: {0}")]
+    ViewDependencyCycle(String),
+
     #[error("Invalid number of column aliases for view body; sql: {sql}, aliases: {aliases:?}")]
     InvalidNumberOfAliasesForView { sql: String, aliases: Vec<String> },
 
@@ -61,6 +73,9 @@ pub enum PlanError {
     #[error("Expected exactly on SQL statement, got: {0:?}")]
     ExpectedExactlyOneStatement(Vec<crate::parser::StatementWithExtensions>),
 
+    #[error("database is in read-only mode")]
+    ReadOnly,
+
     #[error("Not allowed to write into the object: {0}")]
     ObjectNotAllowedToWriteInto(OwnedTableReference),
 
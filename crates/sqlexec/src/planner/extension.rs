@@ -12,15 +12,16 @@ use crate::{
 use datafusion::{
     execution::{runtime_env::RuntimeEnv, FunctionRegistry},
     logical_expr::{Extension as LogicalPlanExtension, UserDefinedLogicalNodeCore},
-    physical_plan::ExecutionPlan,
+    physical_plan::{ExecutionPlan, Statistics},
     prelude::SessionContext,
 };
 
 use super::logical_plan::{
-    AlterDatabase, AlterTable, AlterTunnelRotateKeys, CopyTo, CreateCredentials,
+    AlterDatabase, AlterTable, AlterTunnelRotateKeys, CopyFrom, CopyTo, CreateCredentials,
     CreateExternalDatabase, CreateExternalTable, CreateSchema, CreateTable, CreateTempTable,
     CreateTunnel, CreateView, Delete, DescribeTable, DropCredentials, DropDatabase, DropSchemas,
-    DropTables, DropTunnel, DropViews, Insert, SetVariable, ShowVariable, Update,
+    DropTables, DropTunnel, DropViews, Insert, MergeInto, SetVariable, ShowVariable,
+    TruncateTables, Update,
 };
 
 /// This tracks all of our extensions so that we can ensure an exhaustive match on anywhere that uses the extension
@@ -48,10 +49,13 @@ pub enum ExtensionType {
     DropViews,
     SetVariable,
     ShowVariable,
+    TruncateTables,
     CopyTo,
     Update,
     Insert,
     Delete,
+    MergeInto,
+    CopyFrom,
 }
 
 impl FromStr for ExtensionType {
@@ -78,15 +82,51 @@ impl FromStr for ExtensionType {
             DropViews::EXTENSION_NAME => Self::DropViews,
             SetVariable::EXTENSION_NAME => Self::SetVariable,
             ShowVariable::EXTENSION_NAME => Self::ShowVariable,
+            TruncateTables::EXTENSION_NAME => Self::TruncateTables,
             CopyTo::EXTENSION_NAME => Self::CopyTo,
             Update::EXTENSION_NAME => Self::Update,
             Insert::EXTENSION_NAME => Self::Insert,
             Delete::EXTENSION_NAME => Self::Delete,
+            MergeInto::EXTENSION_NAME => Self::MergeInto,
+            CopyFrom::EXTENSION_NAME => Self::CopyFrom,
             _ => return Err(internal!("unknown extension type: {}", s)),
         })
     }
 }
 
+impl ExtensionType {
+    /// Returns true if executing this extension modifies the database.
+    pub fn modifies_database(&self) -> bool {
+        match self {
+            Self::DescribeTable | Self::SetVariable | Self::ShowVariable => false,
+            Self::AlterDatabase
+            | Self::AlterTable
+            | Self::AlterTunnelRotateKeys
+            | Self::CreateCredentials
+            | Self::CreateExternalDatabase
+            | Self::CreateExternalTable
+            | Self::CreateSchema
+            | Self::CreateTable
+            | Self::CreateTempTable
+            | Self::CreateTunnel
+            | Self::CreateView
+            | Self::DropTables
+            | Self::DropCredentials
+            | Self::DropDatabase
+            | Self::DropSchemas
+            | Self::DropTunnel
+            | Self::DropViews
+            | Self::TruncateTables
+            | Self::Update
+            | Self::Insert
+            | Self::Delete
+            | Self::MergeInto
+            | Self::CopyTo
+            | Self::CopyFrom => true,
+        }
+    }
+}
+
 pub trait ExtensionNode: Sized + UserDefinedLogicalNodeCore {
     type ProtoRepr;
     const EXTENSION_NAME: &'static str;
@@ -105,6 +145,13 @@ pub trait ExtensionNode: Sized + UserDefinedLogicalNodeCore {
 
     fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self>;
 
+    /// Estimated statistics for the output of this node.
+    ///
+    /// Defaults to unknown statistics.
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+
     fn try_encode(&self, buf: &mut Vec<u8>, _codec: &dyn LogicalExtensionCodec) -> Result<()>;
 
     fn try_decode(
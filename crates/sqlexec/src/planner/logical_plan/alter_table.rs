@@ -1,4 +1,5 @@
 use protogen::metastore::types::service::AlterTableOperation;
+use protogen::FromOptionalField;
 
 use super::*;
 
@@ -7,6 +8,8 @@ pub struct AlterTable {
     pub schema: String,
     pub name: String,
     pub operation: AlterTableOperation,
+    /// Value to fill existing rows with when adding a column.
+    pub backfill: Option<Expr>,
 }
 
 impl UserDefinedLogicalNodeCore for AlterTable {
@@ -43,18 +46,89 @@ impl ExtensionNode for AlterTable {
     type ProtoRepr = protogen::gen::metastore::service::AlterTable;
     const EXTENSION_NAME: &'static str = "AlterTable";
     fn try_decode(
-        _proto: Self::ProtoRepr,
+        proto: Self::ProtoRepr,
         _ctx: &SessionContext,
         _codec: &dyn LogicalExtensionCodec,
     ) -> std::result::Result<Self, ProtoConvError> {
-        unimplemented!()
+        Ok(Self {
+            schema: proto.schema,
+            name: proto.name,
+            operation: proto.operation.required("operation")?,
+            backfill: None,
+        })
     }
 
-    fn try_downcast_extension(_extension: &LogicalPlanExtension) -> Result<Self> {
-        unimplemented!()
+    fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self> {
+        match extension.node.as_any().downcast_ref::<Self>() {
+            Some(s) => Ok(s.clone()),
+            None => Err(internal!("AlterTable::try_decode_extension failed",)),
+        }
     }
 
-    fn try_encode(&self, _buf: &mut Vec<u8>, _codec: &dyn LogicalExtensionCodec) -> Result<()> {
-        unimplemented!()
+    fn try_encode(&self, buf: &mut Vec<u8>, _codec: &dyn LogicalExtensionCodec) -> Result<()> {
+        use ::protogen::{
+            gen::metastore::service as protogen,
+            sqlexec::logical_plan::{LogicalPlanExtension, LogicalPlanExtensionType},
+        };
+
+        // Backfills are only needed when planning physically, which doesn't
+        // go through the logical encoding.
+        if self.backfill.is_some() {
+            return Err(internal!("AlterTable with a backfill can't be encoded"));
+        }
+
+        let proto = protogen::AlterTable {
+            schema: self.schema.clone(),
+            name: self.name.clone(),
+            operation: Some(self.operation.clone().try_into()?),
+        };
+        let plan_type = LogicalPlanExtensionType::AlterTable(proto);
+
+        let lp_extension = LogicalPlanExtension {
+            inner: Some(plan_type),
+        };
+
+        lp_extension
+            .encode(buf)
+            .map_err(|e| internal!("{}", e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::roundtrip;
+    use protogen::metastore::types::options::InternalColumnDefinition;
+
+    #[test]
+    fn roundtrip_operations() {
+        let operations = [
+            AlterTableOperation::RenameTable {
+                new_name: "t2".to_string(),
+            },
+            AlterTableOperation::AddColumn {
+                column: InternalColumnDefinition {
+                    name: "c".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Int64,
+                },
+            },
+            AlterTableOperation::DropColumn {
+                name: "c".to_string(),
+                if_exists: true,
+            },
+        ];
+
+        for operation in operations {
+            let plan = AlterTable {
+                schema: "public".to_string(),
+                name: "t1".to_string(),
+                operation,
+                backfill: None,
+            };
+            assert_eq!(plan, roundtrip(&plan));
+        }
     }
 }
@@ -0,0 +1,130 @@
+use protogen::metastore::types::catalog::TableEntry;
+use protogen::metastore::types::options::StorageOptions;
+use protogen::sqlexec::logical_plan as proto;
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CopyFrom {
+    /// Native table to load the data into.
+    pub table: TableEntry,
+    /// Location of the file(s) to load.
+    pub location: String,
+    /// File type of the source (parquet, csv, or json).
+    pub file_type: String,
+    /// Options for accessing the object store holding the source.
+    pub storage_options: StorageOptions,
+}
+
+impl UserDefinedLogicalNodeCore for CopyFrom {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        Vec::new()
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &GENERIC_OPERATION_AND_COUNT_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for CopyFrom {
+    type ProtoRepr = proto::CopyFrom;
+    const EXTENSION_NAME: &'static str = "CopyFrom";
+
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
+    fn try_decode(
+        proto: Self::ProtoRepr,
+        _ctx: &SessionContext,
+        _codec: &dyn LogicalExtensionCodec,
+    ) -> std::result::Result<Self, ProtoConvError> {
+        let table = proto
+            .table
+            .ok_or(ProtoConvError::RequiredField(
+                "table is required".to_string(),
+            ))?
+            .try_into()?;
+        let storage_options = proto
+            .storage_options
+            .ok_or(ProtoConvError::RequiredField(
+                "storage options is required".to_string(),
+            ))?
+            .try_into()?;
+
+        Ok(Self {
+            table,
+            location: proto.location,
+            file_type: proto.file_type,
+            storage_options,
+        })
+    }
+
+    fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self> {
+        match extension.node.as_any().downcast_ref::<Self>() {
+            Some(s) => Ok(s.clone()),
+            None => Err(internal!(
+                "CopyFrom::try_decode_extension: unsupported extension",
+            )),
+        }
+    }
+
+    fn try_encode(&self, buf: &mut Vec<u8>, _codec: &dyn LogicalExtensionCodec) -> Result<()> {
+        let copy_from = proto::CopyFrom {
+            table: Some(self.table.clone().try_into()?),
+            location: self.location.clone(),
+            file_type: self.file_type.clone(),
+            storage_options: Some(self.storage_options.clone().into()),
+        };
+        let plan_type = proto::LogicalPlanExtensionType::CopyFrom(copy_from);
+
+        let lp_extension = proto::LogicalPlanExtension {
+            inner: Some(plan_type),
+        };
+
+        lp_extension
+            .encode(buf)
+            .map_err(|e| internal!("{}", e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::{roundtrip, table_entry};
+
+    #[test]
+    fn roundtrip_copy_from() {
+        let plan = CopyFrom {
+            table: table_entry(),
+            location: "s3://bucket/data.parquet".to_string(),
+            file_type: "parquet".to_string(),
+            storage_options: StorageOptions::new_from_iter([("region", "us-east-1")]),
+        };
+
+        assert_eq!(plan, roundtrip(&plan));
+    }
+}
@@ -41,14 +41,23 @@ impl UserDefinedLogicalNodeCore for CopyTo {
     fn from_template(
         &self,
         _exprs: &[datafusion::prelude::Expr],
-        _inputs: &[DfLogicalPlan],
+        inputs: &[DfLogicalPlan],
     ) -> Self {
-        self.clone()
+        Self {
+            source: inputs[0].clone(),
+            ..self.clone()
+        }
     }
 }
 impl ExtensionNode for CopyTo {
     type ProtoRepr = protogen::sqlexec::logical_plan::CopyTo;
     const EXTENSION_NAME: &'static str = "CopyTo";
+
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
     fn try_decode(
         proto: Self::ProtoRepr,
         ctx: &SessionContext,
@@ -109,3 +118,32 @@ impl ExtensionNode for CopyTo {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::roundtrip;
+    use datafusion::logical_expr::{lit, LogicalPlanBuilder};
+    use protogen::metastore::types::options::{
+        CopyToDestinationOptionsLocal, CopyToFormatOptionsParquet,
+    };
+
+    #[test]
+    fn roundtrip_copy_to() {
+        let source = LogicalPlanBuilder::values(vec![vec![lit(1_i64), lit("a")]])
+            .unwrap()
+            .build()
+            .unwrap();
+        let plan = CopyTo {
+            source,
+            dest: CopyToDestinationOptions::Local(CopyToDestinationOptionsLocal {
+                location: "/tmp/out.parquet".to_string(),
+            }),
+            format: CopyToFormatOptions::Parquet(CopyToFormatOptionsParquet {
+                row_group_size: 122880,
+            }),
+        };
+
+        assert_eq!(plan, roundtrip(&plan));
+    }
+}
@@ -47,12 +47,13 @@ impl ExtensionNode for CreateExternalTable {
         _ctx: &SessionContext,
         _codec: &dyn LogicalExtensionCodec,
     ) -> std::result::Result<Self, ProtoConvError> {
-        let reference = proto
-            .reference
-            .ok_or(ProtoConvError::RequiredField(
-                "table_name is required".to_string(),
-            ))?
-            .into();
+        let reference =
+            proto
+                .reference
+                .map(decode_table_ref)
+                .ok_or(ProtoConvError::RequiredField(
+                    "table_name is required".to_string(),
+                ))?;
 
         let tbl_options = proto.table_options.ok_or(ProtoConvError::RequiredField(
             "table_options is required".to_string(),
@@ -80,7 +81,7 @@ impl ExtensionNode for CreateExternalTable {
         use protogen::sqlexec::logical_plan as protogen;
 
         let create_table = protogen::CreateExternalTable {
-            reference: Some(self.tbl_reference.clone().into()),
+            reference: Some(encode_table_ref(&self.tbl_reference)),
             or_replace: self.or_replace,
             if_not_exists: self.if_not_exists,
             table_options: Some(self.table_options.clone().try_into().ok().ok_or(
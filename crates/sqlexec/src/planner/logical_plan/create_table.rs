@@ -52,12 +52,13 @@ impl ExtensionNode for CreateTable {
         ctx: &SessionContext,
         codec: &dyn LogicalExtensionCodec,
     ) -> std::result::Result<Self, ProtoConvError> {
-        let reference = proto
-            .reference
-            .ok_or(ProtoConvError::RequiredField(
-                "table_name is required".to_string(),
-            ))?
-            .into();
+        let reference =
+            proto
+                .reference
+                .map(decode_table_ref)
+                .ok_or(ProtoConvError::RequiredField(
+                    "table_name is required".to_string(),
+                ))?;
         let schema = proto
             .schema
             .ok_or(ProtoConvError::RequiredField(
@@ -104,7 +105,7 @@ impl ExtensionNode for CreateTable {
             .transpose()?;
 
         let create_table = protogen::CreateTable {
-            reference: Some(self.tbl_reference.clone().into()),
+            reference: Some(encode_table_ref(&self.tbl_reference)),
             if_not_exists: self.if_not_exists,
             or_replace: self.or_replace,
             schema,
@@ -124,3 +125,31 @@ impl ExtensionNode for CreateTable {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::roundtrip;
+    use datafusion::arrow::datatypes::Field;
+
+    #[test]
+    fn roundtrip_create_flags() {
+        let schema = ArrowSchema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let schema = Arc::new(DFSchema::try_from(schema).unwrap());
+
+        for (if_not_exists, or_replace) in [(false, false), (true, false), (false, true)] {
+            let plan = CreateTable {
+                tbl_reference: OwnedFullObjectReference {
+                    database: "default".into(),
+                    schema: "public".into(),
+                    name: "t1".into(),
+                },
+                if_not_exists,
+                or_replace,
+                schema: schema.clone(),
+                source: None,
+            };
+            assert_eq!(plan, roundtrip(&plan));
+        }
+    }
+}
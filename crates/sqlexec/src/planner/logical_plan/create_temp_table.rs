@@ -50,12 +50,13 @@ impl ExtensionNode for CreateTempTable {
         ctx: &SessionContext,
         codec: &dyn LogicalExtensionCodec,
     ) -> std::result::Result<Self, ProtoConvError> {
-        let reference = proto
-            .reference
-            .ok_or(ProtoConvError::RequiredField(
-                "reference is required".to_string(),
-            ))?
-            .into();
+        let reference =
+            proto
+                .reference
+                .map(decode_table_ref)
+                .ok_or(ProtoConvError::RequiredField(
+                    "reference is required".to_string(),
+                ))?;
         let schema = proto
             .schema
             .ok_or(ProtoConvError::RequiredField(
@@ -98,7 +99,7 @@ impl ExtensionNode for CreateTempTable {
         });
 
         let create_table = protogen::CreateTempTable {
-            reference: Some(self.tbl_reference.clone().into()),
+            reference: Some(encode_table_ref(&self.tbl_reference)),
             if_not_exists: self.if_not_exists,
             or_replace: self.or_replace,
             schema,
@@ -5,6 +5,7 @@ pub struct CreateView {
     pub sql: String,
     pub columns: Vec<String>,
     pub or_replace: bool,
+    pub if_not_exists: bool,
 }
 
 impl UserDefinedLogicalNodeCore for CreateView {
@@ -46,18 +47,20 @@ impl ExtensionNode for CreateView {
         _ctx: &SessionContext,
         _codec: &dyn LogicalExtensionCodec,
     ) -> std::result::Result<Self, ProtoConvError> {
-        let reference = proto
-            .reference
-            .ok_or(ProtoConvError::RequiredField(
-                "reference is required".to_string(),
-            ))?
-            .into();
+        let reference =
+            proto
+                .reference
+                .map(decode_table_ref)
+                .ok_or(ProtoConvError::RequiredField(
+                    "reference is required".to_string(),
+                ))?;
 
         Ok(CreateView {
             view_reference: reference,
             sql: proto.sql,
             columns: proto.columns,
             or_replace: proto.or_replace,
+            if_not_exists: proto.if_not_exists,
         })
     }
     fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self> {
@@ -73,10 +76,11 @@ impl ExtensionNode for CreateView {
         use protogen::sqlexec::logical_plan as protogen;
 
         let proto = protogen::CreateView {
-            reference: Some(self.view_reference.clone().into()),
+            reference: Some(encode_table_ref(&self.view_reference)),
             sql: self.sql.clone(),
             columns: self.columns.clone(),
             or_replace: self.or_replace,
+            if_not_exists: self.if_not_exists,
         };
 
         let extension = protogen::LogicalPlanExtensionType::CreateView(proto);
@@ -92,3 +96,27 @@ impl ExtensionNode for CreateView {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::roundtrip;
+
+    #[test]
+    fn roundtrip_create_flags() {
+        for (if_not_exists, or_replace) in [(false, false), (true, false), (false, true)] {
+            let plan = CreateView {
+                view_reference: OwnedFullObjectReference {
+                    database: "default".into(),
+                    schema: "public".into(),
+                    name: "v1".into(),
+                },
+                sql: "select 1".to_string(),
+                columns: vec!["a".to_string()],
+                or_replace,
+                if_not_exists,
+            };
+            assert_eq!(plan, roundtrip(&plan));
+        }
+    }
+}
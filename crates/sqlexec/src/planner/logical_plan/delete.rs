@@ -43,6 +43,11 @@ impl ExtensionNode for Delete {
     type ProtoRepr = protogen::sqlexec::logical_plan::Delete;
     const EXTENSION_NAME: &'static str = "Delete";
 
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
     fn try_decode(
         _proto: Self::ProtoRepr,
         _ctx: &SessionContext,
@@ -47,7 +47,7 @@ impl ExtensionNode for DropTables {
         let references = proto
             .references
             .into_iter()
-            .map(|r| r.into())
+            .map(decode_table_ref)
             .collect::<Vec<_>>();
 
         Ok(Self {
@@ -69,9 +69,8 @@ impl ExtensionNode for DropTables {
         use protogen::sqlexec::logical_plan as protogen;
         let references = self
             .tbl_references
-            .clone()
-            .into_iter()
-            .map(|r| r.into())
+            .iter()
+            .map(encode_table_ref)
             .collect::<Vec<_>>();
 
         let drop_tables = protogen::DropTables {
@@ -47,7 +47,7 @@ impl ExtensionNode for DropViews {
         let references = proto
             .references
             .into_iter()
-            .map(|r| r.into())
+            .map(decode_table_ref)
             .collect::<Vec<_>>();
 
         Ok(Self {
@@ -68,9 +68,8 @@ impl ExtensionNode for DropViews {
         use protogen::sqlexec::logical_plan as protogen;
         let references = self
             .view_references
-            .clone()
-            .into_iter()
-            .map(|r| r.into())
+            .iter()
+            .map(encode_table_ref)
             .collect::<Vec<_>>();
 
         let drop_tables = protogen::DropViews {
@@ -49,6 +49,11 @@ impl ExtensionNode for Insert {
     type ProtoRepr = protogen::sqlexec::logical_plan::Insert;
     const EXTENSION_NAME: &'static str = "Insert";
 
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
     fn try_decode(
         _proto: Self::ProtoRepr,
         _ctx: &SessionContext,
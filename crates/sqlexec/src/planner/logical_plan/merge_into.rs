@@ -0,0 +1,272 @@
+use datafusion::execution::FunctionRegistry;
+use datafusion_proto::logical_plan::from_proto::parse_expr;
+use datafusion_proto::protobuf::LogicalExprNode;
+use datasources::native::access::MergeIntoClause;
+use protogen::metastore::types::catalog::TableEntry;
+use protogen::sqlexec::logical_plan as proto;
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MergeInto {
+    pub table: TableEntry,
+    pub source: DfLogicalPlan,
+    /// Qualifier for target columns in `on` and the clauses.
+    pub target_alias: String,
+    /// Qualifier for source columns in `on` and the clauses.
+    pub source_alias: String,
+    pub on: Expr,
+    pub clauses: Vec<MergeIntoClause>,
+}
+
+impl UserDefinedLogicalNodeCore for MergeInto {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        vec![&self.source]
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &GENERIC_OPERATION_AND_COUNT_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        Vec::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Self::EXTENSION_NAME)
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        inputs: &[DfLogicalPlan],
+    ) -> Self {
+        Self {
+            source: inputs[0].clone(),
+            ..self.clone()
+        }
+    }
+}
+
+impl ExtensionNode for MergeInto {
+    type ProtoRepr = proto::MergeInto;
+    const EXTENSION_NAME: &'static str = "MergeInto";
+
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
+    fn try_decode(
+        proto: Self::ProtoRepr,
+        ctx: &SessionContext,
+        codec: &dyn LogicalExtensionCodec,
+    ) -> std::result::Result<Self, ProtoConvError> {
+        let table = proto
+            .table
+            .ok_or(ProtoConvError::RequiredField(
+                "table is required".to_string(),
+            ))?
+            .try_into()?;
+        let source = proto
+            .source
+            .ok_or(ProtoConvError::RequiredField(
+                "source is required".to_string(),
+            ))?
+            .try_into_logical_plan(ctx, codec)?;
+        let on = proto
+            .on
+            .ok_or(ProtoConvError::RequiredField("on is required".to_string()))?;
+
+        Ok(Self {
+            table,
+            source,
+            target_alias: proto.target_alias,
+            source_alias: proto.source_alias,
+            on: parse_expr(&on, ctx)?,
+            clauses: merge_clauses_from_proto(proto.clauses, ctx)?,
+        })
+    }
+
+    fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self> {
+        match extension.node.as_any().downcast_ref::<Self>() {
+            Some(s) => Ok(s.clone()),
+            None => Err(internal!(
+                "MergeInto::try_decode_extension: unsupported extension",
+            )),
+        }
+    }
+
+    fn try_encode(&self, buf: &mut Vec<u8>, codec: &dyn LogicalExtensionCodec) -> Result<()> {
+        let source = LogicalPlanNode::try_from_logical_plan(&self.source, codec)
+            .map_err(|e| internal!("unable to encode source: {}", e.to_string()))?;
+
+        let merge_into = proto::MergeInto {
+            table: Some(self.table.clone().try_into()?),
+            source: Some(source),
+            target_alias: self.target_alias.clone(),
+            source_alias: self.source_alias.clone(),
+            on: Some(expr_to_proto(&self.on)?),
+            clauses: merge_clauses_to_proto(&self.clauses)?,
+        };
+        let plan_type = proto::LogicalPlanExtensionType::MergeInto(merge_into);
+
+        let lp_extension = proto::LogicalPlanExtension {
+            inner: Some(plan_type),
+        };
+
+        lp_extension
+            .encode(buf)
+            .map_err(|e| internal!("{}", e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn expr_to_proto(expr: &Expr) -> std::result::Result<LogicalExprNode, ProtoConvError> {
+    Ok(expr.try_into()?)
+}
+
+/// Convert merge clauses to their proto representation.
+///
+/// Shared with the physical plan codec.
+pub fn merge_clauses_to_proto(
+    clauses: &[MergeIntoClause],
+) -> std::result::Result<Vec<proto::MergeIntoClause>, ProtoConvError> {
+    fn assignments_to_proto(
+        assignments: &[(String, Expr)],
+    ) -> std::result::Result<Vec<proto::MergeIntoAssignment>, ProtoConvError> {
+        assignments
+            .iter()
+            .map(|(column, expr)| {
+                Ok(proto::MergeIntoAssignment {
+                    column: column.clone(),
+                    expr: Some(expr_to_proto(expr)?),
+                })
+            })
+            .collect()
+    }
+
+    clauses
+        .iter()
+        .map(|clause| {
+            let clause = match clause {
+                MergeIntoClause::MatchedUpdate {
+                    predicate,
+                    assignments,
+                } => proto::MergeIntoClauseType::MatchedUpdate(proto::MergeIntoMatchedUpdate {
+                    predicate: predicate.as_ref().map(expr_to_proto).transpose()?,
+                    assignments: assignments_to_proto(assignments)?,
+                }),
+                MergeIntoClause::MatchedDelete { predicate } => {
+                    proto::MergeIntoClauseType::MatchedDelete(proto::MergeIntoMatchedDelete {
+                        predicate: predicate.as_ref().map(expr_to_proto).transpose()?,
+                    })
+                }
+                MergeIntoClause::NotMatchedInsert { predicate, values } => {
+                    proto::MergeIntoClauseType::NotMatchedInsert(proto::MergeIntoNotMatchedInsert {
+                        predicate: predicate.as_ref().map(expr_to_proto).transpose()?,
+                        values: assignments_to_proto(values)?,
+                    })
+                }
+            };
+            Ok(proto::MergeIntoClause {
+                clause: Some(clause),
+            })
+        })
+        .collect()
+}
+
+/// Convert merge clauses from their proto representation.
+pub fn merge_clauses_from_proto(
+    clauses: Vec<proto::MergeIntoClause>,
+    registry: &dyn FunctionRegistry,
+) -> std::result::Result<Vec<MergeIntoClause>, ProtoConvError> {
+    let parse_opt = |expr: Option<LogicalExprNode>| {
+        expr.map(|expr| parse_expr(&expr, registry))
+            .transpose()
+            .map_err(ProtoConvError::from)
+    };
+    let parse_assignments = |assignments: Vec<proto::MergeIntoAssignment>| {
+        assignments
+            .into_iter()
+            .map(|a| {
+                let expr = a.expr.ok_or(ProtoConvError::RequiredField(
+                    "assignment expression is required".to_string(),
+                ))?;
+                Ok((a.column, parse_expr(&expr, registry)?))
+            })
+            .collect::<std::result::Result<Vec<_>, ProtoConvError>>()
+    };
+
+    clauses
+        .into_iter()
+        .map(|clause| {
+            let clause = clause.clause.ok_or(ProtoConvError::RequiredField(
+                "merge clause is required".to_string(),
+            ))?;
+            Ok(match clause {
+                proto::MergeIntoClauseType::MatchedUpdate(c) => MergeIntoClause::MatchedUpdate {
+                    predicate: parse_opt(c.predicate)?,
+                    assignments: parse_assignments(c.assignments)?,
+                },
+                proto::MergeIntoClauseType::MatchedDelete(c) => MergeIntoClause::MatchedDelete {
+                    predicate: parse_opt(c.predicate)?,
+                },
+                proto::MergeIntoClauseType::NotMatchedInsert(c) => {
+                    MergeIntoClause::NotMatchedInsert {
+                        predicate: parse_opt(c.predicate)?,
+                        values: parse_assignments(c.values)?,
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::{roundtrip, table_entry};
+    use datafusion::logical_expr::{col, lit, LogicalPlanBuilder};
+
+    #[test]
+    fn roundtrip_merge_into() {
+        let source = LogicalPlanBuilder::values(vec![vec![lit(1_i64), lit("a")]])
+            .unwrap()
+            .alias("s")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let plan = MergeInto {
+            table: table_entry(),
+            source,
+            target_alias: "t".to_string(),
+            source_alias: "s".to_string(),
+            on: col("t.id").eq(col("s.column1")),
+            clauses: vec![
+                MergeIntoClause::MatchedDelete {
+                    predicate: Some(col("s.column2").eq(lit("delete"))),
+                },
+                MergeIntoClause::MatchedUpdate {
+                    predicate: None,
+                    assignments: vec![("v".to_string(), col("s.column2"))],
+                },
+                MergeIntoClause::NotMatchedInsert {
+                    predicate: None,
+                    values: vec![
+                        ("id".to_string(), col("s.column1")),
+                        ("v".to_string(), col("s.column2")),
+                    ],
+                },
+            ],
+        };
+
+        assert_eq!(plan, roundtrip(&plan));
+    }
+}
@@ -1,6 +1,7 @@
 mod alter_database;
 mod alter_table;
 mod alter_tunnel_rotate_keys;
+mod copy_from;
 mod copy_to;
 mod create_credentials;
 mod create_external_database;
@@ -19,17 +20,24 @@ mod drop_tables;
 mod drop_tunnel;
 mod drop_views;
 mod insert;
+mod merge_into;
 mod set_variable;
 mod show_variable;
+mod truncate_tables;
 mod update;
 
+#[cfg(test)]
+pub(crate) mod testutil;
+
 use crate::errors::{internal, Result};
 use crate::planner::extension::ExtensionNode;
+use crate::planner::physical_plan::new_operation_statistics;
 
 use datafusion::arrow::datatypes::{DataType, Schema as ArrowSchema};
 use datafusion::common::{DFField, DFSchema, DFSchemaRef};
 use datafusion::logical_expr::{Explain, Expr, LogicalPlan as DfLogicalPlan};
 use datafusion::logical_expr::{Extension as LogicalPlanExtension, UserDefinedLogicalNodeCore};
+use datafusion::physical_plan::Statistics;
 use datafusion::prelude::SessionContext;
 use datafusion::scalar::ScalarValue;
 use datafusion::sql::sqlparser::ast;
@@ -51,6 +59,7 @@ use std::sync::Arc;
 pub use alter_database::*;
 pub use alter_table::*;
 pub use alter_tunnel_rotate_keys::*;
+pub use copy_from::*;
 pub use copy_to::*;
 pub use create_credentials::*;
 pub use create_external_database::*;
@@ -69,8 +78,10 @@ pub use drop_tables::*;
 pub use drop_tunnel::*;
 pub use drop_views::*;
 pub use insert::*;
+pub use merge_into::*;
 pub use set_variable::*;
 pub use show_variable::*;
+pub use truncate_tables::*;
 pub use update::*;
 
 use super::physical_plan::{
@@ -222,6 +233,23 @@ impl<'a> From<FullObjectReference<'a>> for protogen::sqlexec::common::FullObject
     }
 }
 
+/// Encode a table reference for use in a logical plan extension proto.
+///
+/// All extension nodes should go through this (and `decode_table_ref`) so that
+/// every part of the reference is preserved.
+pub fn encode_table_ref(
+    reference: &OwnedFullObjectReference,
+) -> protogen::sqlexec::common::FullObjectReference {
+    reference.clone().into()
+}
+
+/// Decode a table reference from a logical plan extension proto.
+pub fn decode_table_ref(
+    reference: protogen::sqlexec::common::FullObjectReference,
+) -> OwnedFullObjectReference {
+    reference.into()
+}
+
 /// A fully qualified reference to a database schema.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FullSchemaReference<'a> {
@@ -267,3 +295,40 @@ impl From<TransactionPlan> for LogicalPlan {
         LogicalPlan::Transaction(plan)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::testutil::table_entry;
+    use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn extension_statistics() {
+        let delete = Delete {
+            table: table_entry(),
+            where_expr: None,
+        };
+        let stats = delete.statistics();
+        assert_ne!(Statistics::default(), stats);
+        assert_eq!(Some(1), stats.num_rows);
+
+        // Nodes without estimates report unknown statistics.
+        let truncate = TruncateTables {
+            tbl_references: Vec::new(),
+        };
+        assert_eq!(Statistics::default(), truncate.statistics());
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip_table_ref(database in ".*", schema in ".*", name in ".*") {
+            let reference = OwnedFullObjectReference {
+                database: database.into(),
+                schema: schema.into(),
+                name: name.into(),
+            };
+            let got = decode_table_ref(encode_table_ref(&reference));
+            assert_eq!(reference, got);
+        }
+    }
+}
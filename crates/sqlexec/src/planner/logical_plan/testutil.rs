@@ -0,0 +1,54 @@
+//! Helpers shared by logical plan tests.
+
+use datafusion::arrow::datatypes::DataType;
+use datafusion::prelude::SessionContext;
+use datafusion_proto::logical_plan::LogicalExtensionCodec;
+use protogen::metastore::types::catalog::{EntryMeta, EntryType, SourceAccessMode, TableEntry};
+use protogen::metastore::types::options::{
+    InternalColumnDefinition, TableOptions, TableOptionsInternal,
+};
+
+use crate::extension_codec::GlareDBExtensionCodec;
+use crate::planner::extension::ExtensionNode;
+
+/// Encode an extension node, then decode it with the extension codec.
+pub fn roundtrip<T: ExtensionNode>(plan: &T) -> T {
+    let codec = GlareDBExtensionCodec::new_encoder();
+    let mut buf = Vec::new();
+    plan.try_encode(&mut buf, &codec).unwrap();
+
+    let ext = codec.try_decode(&buf, &[], &SessionContext::new()).unwrap();
+    T::try_downcast_extension(&ext).unwrap()
+}
+
+/// Native table named "target" with an integer "id" column and a string "v"
+/// column.
+pub fn table_entry() -> TableEntry {
+    TableEntry {
+        meta: EntryMeta {
+            entry_type: EntryType::Table,
+            id: 12345,
+            parent: 2,
+            name: "target".to_string(),
+            builtin: false,
+            external: false,
+            is_temp: false,
+        },
+        options: TableOptions::Internal(TableOptionsInternal {
+            columns: vec![
+                InternalColumnDefinition {
+                    name: "id".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Int64,
+                },
+                InternalColumnDefinition {
+                    name: "v".to_string(),
+                    nullable: true,
+                    arrow_type: DataType::Utf8,
+                },
+            ],
+        }),
+        tunnel_id: None,
+        access_mode: SourceAccessMode::ReadWrite,
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+/// Remove all rows from the referenced tables while keeping their schemas.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TruncateTables {
+    pub tbl_references: Vec<OwnedFullObjectReference>,
+}
+
+impl UserDefinedLogicalNodeCore for TruncateTables {
+    fn name(&self) -> &str {
+        Self::EXTENSION_NAME
+    }
+
+    fn inputs(&self) -> Vec<&DfLogicalPlan> {
+        vec![]
+    }
+
+    fn schema(&self) -> &datafusion::common::DFSchemaRef {
+        &GENERIC_OPERATION_LOGICAL_SCHEMA
+    }
+
+    fn expressions(&self) -> Vec<datafusion::prelude::Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "TruncateTables")
+    }
+
+    fn from_template(
+        &self,
+        _exprs: &[datafusion::prelude::Expr],
+        _inputs: &[DfLogicalPlan],
+    ) -> Self {
+        self.clone()
+    }
+}
+
+impl ExtensionNode for TruncateTables {
+    type ProtoRepr = protogen::sqlexec::logical_plan::TruncateTables;
+    const EXTENSION_NAME: &'static str = "TruncateTables";
+
+    fn try_decode(
+        proto: Self::ProtoRepr,
+        _ctx: &SessionContext,
+        _codec: &dyn LogicalExtensionCodec,
+    ) -> std::result::Result<Self, ProtoConvError> {
+        let references = proto
+            .references
+            .into_iter()
+            .map(decode_table_ref)
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            tbl_references: references,
+        })
+    }
+
+    fn try_downcast_extension(extension: &LogicalPlanExtension) -> Result<Self> {
+        match extension.node.as_any().downcast_ref::<Self>() {
+            Some(s) => Ok(s.clone()),
+            None => Err(internal!(
+                "TruncateTables::try_decode_extension: unsupported extension",
+            )),
+        }
+    }
+
+    fn try_encode(&self, buf: &mut Vec<u8>, _codec: &dyn LogicalExtensionCodec) -> Result<()> {
+        use protogen::sqlexec::logical_plan as protogen;
+        let references = self
+            .tbl_references
+            .iter()
+            .map(encode_table_ref)
+            .collect::<Vec<_>>();
+
+        let truncate_tables = protogen::TruncateTables { references };
+        let plan_type = protogen::LogicalPlanExtensionType::TruncateTables(truncate_tables);
+
+        let lp_extension = protogen::LogicalPlanExtension {
+            inner: Some(plan_type),
+        };
+
+        lp_extension
+            .encode(buf)
+            .map_err(|e| internal!("{}", e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical_plan::testutil::roundtrip;
+
+    #[test]
+    fn roundtrip_references() {
+        let plan = TruncateTables {
+            tbl_references: vec![
+                OwnedFullObjectReference {
+                    database: "default".into(),
+                    schema: "public".into(),
+                    name: "t1".into(),
+                },
+                OwnedFullObjectReference {
+                    database: "default".into(),
+                    schema: "other".into(),
+                    name: "t2".into(),
+                },
+            ],
+        };
+
+        assert_eq!(plan, roundtrip(&plan));
+    }
+}
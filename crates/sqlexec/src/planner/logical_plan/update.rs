@@ -44,6 +44,11 @@ impl ExtensionNode for Update {
     type ProtoRepr = protogen::sqlexec::logical_plan::Update;
     const EXTENSION_NAME: &'static str = "Update";
 
+    /// Always outputs a single row describing the operation.
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+
     fn try_decode(
         _proto: Self::ProtoRepr,
         _ctx: &SessionContext,
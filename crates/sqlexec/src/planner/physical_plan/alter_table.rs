@@ -1,4 +1,4 @@
-use crate::metastore::catalog::CatalogMutator;
+use crate::metastore::catalog::{CatalogMutator, SessionCatalog};
 use datafusion::arrow::datatypes::Schema;
 use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::error::{DataFusionError, Result as DataFusionResult};
@@ -8,13 +8,19 @@ use datafusion::physical_plan::{
     stream::RecordBatchStreamAdapter, DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning,
     SendableRecordBatchStream, Statistics,
 };
+use datafusion::prelude::Expr;
+use datasources::native::access::NativeTableStorage;
 use futures::stream;
+use protogen::metastore::types::catalog::TableEntry;
+use protogen::metastore::types::options::{InternalColumnDefinition, TableOptions};
 use protogen::metastore::types::service::{self, AlterTableOperation, Mutation};
+use sqlbuiltins::builtins::DEFAULT_CATALOG;
 use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
+use tracing::warn;
 
-use super::{new_operation_batch, GENERIC_OPERATION_PHYSICAL_SCHEMA};
+use super::{new_operation_batch, new_operation_statistics, GENERIC_OPERATION_PHYSICAL_SCHEMA};
 
 #[derive(Debug, Clone)]
 pub struct AlterTableExec {
@@ -22,6 +28,8 @@ pub struct AlterTableExec {
     pub schema: String,
     pub name: String,
     pub operation: AlterTableOperation,
+    /// Value to fill existing rows with when adding a column.
+    pub backfill: Option<Expr>,
 }
 
 impl ExecutionPlan for AlterTableExec {
@@ -69,8 +77,12 @@ impl ExecutionPlan for AlterTableExec {
             .session_config()
             .get_extension::<CatalogMutator>()
             .expect("context should have catalog mutator");
+        let storage = context
+            .session_config()
+            .get_extension::<NativeTableStorage>()
+            .expect("context should have native table storage");
 
-        let stream = stream::once(alter_table_rename(mutator, self.clone()));
+        let stream = stream::once(alter_table(mutator, storage, self.clone()));
 
         Ok(Box::pin(RecordBatchStreamAdapter::new(
             self.schema(),
@@ -79,7 +91,7 @@ impl ExecutionPlan for AlterTableExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -89,22 +101,109 @@ impl DisplayAs for AlterTableExec {
     }
 }
 
-async fn alter_table_rename(
+async fn alter_table(
     mutator: Arc<CatalogMutator>,
+    storage: Arc<NativeTableStorage>,
     plan: AlterTableExec,
 ) -> DataFusionResult<RecordBatch> {
+    let to_exec_err =
+        |e: &dyn fmt::Display| DataFusionError::Execution(format!("failed to alter table: {e}"));
+
+    // Column changes are committed to the table's delta log before the
+    // catalog, and reverted if the catalog mutation fails, so the two never
+    // disagree about the table's columns.
+    let mut previous = None;
+    if let Some((ent, columns)) = altered_columns(&mutator, &plan).await? {
+        let metadata = match (&plan.operation, &plan.backfill) {
+            (AlterTableOperation::AddColumn { column }, Some(value)) => {
+                storage
+                    .update_table_schema_with_backfill(&ent, &columns, &column.name, value.clone())
+                    .await
+            }
+            _ => storage.update_table_schema(&ent, &columns).await,
+        }
+        .map_err(|e| to_exec_err(&e))?;
+        previous = Some((ent, metadata));
+    }
+
     // TODO: Error if schemas between references differ.
-    mutator
+    let result = mutator
         .mutate(
             plan.catalog_version,
             [Mutation::AlterTable(service::AlterTable {
-                schema: plan.schema,
-                name: plan.name,
+                schema: plan.schema.clone(),
+                name: plan.name.clone(),
                 operation: plan.operation,
             })],
         )
-        .await
-        .map_err(|e| DataFusionError::Execution(format!("failed to alter table: {e}")))?;
+        .await;
+
+    if let Err(e) = result {
+        if let Some((ent, metadata)) = previous {
+            if let Err(e) = storage.restore_table_metadata(&ent, metadata).await {
+                warn!(%e, table = %plan.name, "failed to restore table schema");
+            }
+        }
+        return Err(to_exec_err(&e));
+    }
 
     Ok(new_operation_batch("alter_table"))
 }
+
+/// Resolve the table being altered and the columns it will have after adding
+/// or dropping a column.
+///
+/// Returns `None` if the operation doesn't change the stored columns. Invalid
+/// operations are left for the metastore to reject.
+async fn altered_columns(
+    mutator: &CatalogMutator,
+    plan: &AlterTableExec,
+) -> DataFusionResult<Option<(TableEntry, Vec<InternalColumnDefinition>)>> {
+    if !matches!(
+        plan.operation,
+        AlterTableOperation::AddColumn { .. } | AlterTableOperation::DropColumn { .. }
+    ) {
+        return Ok(None);
+    }
+
+    let client = match mutator.get_metastore_client() {
+        Some(client) => client,
+        None => return Ok(None),
+    };
+    let state = async {
+        client.refresh_cached_state().await?;
+        client.get_cached_state().await
+    }
+    .await
+    .map_err(|e| DataFusionError::Execution(format!("failed to alter table: {e}")))?;
+
+    let ent =
+        match SessionCatalog::new(state).resolve_table(DEFAULT_CATALOG, &plan.schema, &plan.name) {
+            Some(ent) => ent.clone(),
+            None => return Ok(None),
+        };
+    let mut columns = match &ent.options {
+        TableOptions::Internal(opts) => opts.columns.clone(),
+        _ => return Ok(None),
+    };
+
+    match &plan.operation {
+        AlterTableOperation::AddColumn { column } => {
+            if columns.iter().any(|col| col.name == column.name) {
+                return Ok(None);
+            }
+            columns.push(column.clone());
+        }
+        AlterTableOperation::DropColumn { name, .. } => {
+            match columns.iter().position(|col| &col.name == name) {
+                Some(idx) if columns.len() > 1 => {
+                    columns.remove(idx);
+                }
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    }
+
+    Ok(Some((ent, columns)))
+}
@@ -14,7 +14,7 @@ use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{new_operation_batch, GENERIC_OPERATION_PHYSICAL_SCHEMA};
+use super::{new_operation_batch, new_operation_statistics, GENERIC_OPERATION_PHYSICAL_SCHEMA};
 
 #[derive(Debug, Clone)]
 pub struct AlterTunnelRotateKeysExec {
@@ -79,7 +79,7 @@ impl ExecutionPlan for AlterTunnelRotateKeysExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -0,0 +1,191 @@
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::common::FileType;
+use datafusion::datasource::object_store::ObjectStoreUrl;
+use datafusion::datasource::TableProvider;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::logical_expr::{cast, Expr};
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::{
+    stream::RecordBatchStreamAdapter, DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning,
+    SendableRecordBatchStream, Statistics,
+};
+use datafusion::prelude::{CsvReadOptions, NdJsonReadOptions, ParquetReadOptions, SessionContext};
+use datasources::common::url::DatasourceUrl;
+use datasources::lake::storage_options_into_object_store;
+use datasources::native::access::NativeTableStorage;
+use futures::stream;
+use object_store_util::counting::CountingObjectStore;
+use protogen::metastore::types::catalog::TableEntry;
+use protogen::metastore::types::options::StorageOptions;
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use super::copy_progress::{CopyDirection, CopyProgressExec, CopyProgressTracker};
+use super::insert::InsertExec;
+use super::{
+    get_count_from_batch, new_operation_statistics, new_operation_with_count_batch,
+    GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
+};
+
+#[derive(Debug, Clone)]
+pub struct CopyFromExec {
+    pub table: TableEntry,
+    pub location: String,
+    pub file_type: String,
+    pub storage_options: StorageOptions,
+}
+
+impl ExecutionPlan for CopyFromExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        Err(DataFusionError::Plan(
+            "Cannot change children for CopyFromExec".to_string(),
+        ))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Execution(
+                "CopyFromExec only supports 1 partition".to_string(),
+            ));
+        }
+
+        let storage = context
+            .session_config()
+            .get_extension::<NativeTableStorage>()
+            .expect("context should have native table storage");
+
+        let progress = Arc::new(CopyProgressTracker::new(CopyDirection::From, &context));
+        let stream = stream::once(copy_from(self.clone(), storage, progress));
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+}
+
+impl DisplayAs for CopyFromExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CopyFromExec")
+    }
+}
+
+async fn copy_from(
+    plan: CopyFromExec,
+    storage: impl AsRef<NativeTableStorage>,
+    progress: Arc<CopyProgressTracker>,
+) -> DataFusionResult<RecordBatch> {
+    let storage = storage.as_ref();
+
+    // Read the source through a separate context that has the object store
+    // for the location registered.
+    let url = DatasourceUrl::try_new(&plan.location)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let store = storage_options_into_object_store(&url, &plan.storage_options)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let store = Arc::new(CountingObjectStore::new(store, progress.counts()));
+    let store_url = ObjectStoreUrl::try_from(&url)?;
+
+    let ctx = SessionContext::new();
+    ctx.runtime_env()
+        .register_object_store(store_url.as_ref(), store);
+
+    // The location may point at a file without the usual extension, so don't
+    // filter on one.
+    let source = match FileType::from_str(&plan.file_type)? {
+        FileType::PARQUET => {
+            let opts = ParquetReadOptions {
+                file_extension: "",
+                ..Default::default()
+            };
+            ctx.read_parquet(plan.location.as_str(), opts).await?
+        }
+        FileType::CSV => {
+            let opts = CsvReadOptions::new().file_extension("");
+            ctx.read_csv(plan.location.as_str(), opts).await?
+        }
+        FileType::JSON => {
+            let opts = NdJsonReadOptions {
+                file_extension: "",
+                ..Default::default()
+            };
+            ctx.read_json(plan.location.as_str(), opts).await?
+        }
+        other => {
+            return Err(DataFusionError::Plan(format!(
+                "Unsupported file type for COPY FROM: {other}"
+            )))
+        }
+    };
+
+    let table = storage
+        .load_table(&plan.table)
+        .await
+        .map_err(|e| DataFusionError::Execution(format!("failed to load table: {e}")))?;
+    let table_schema = TableProvider::schema(&table);
+
+    // Columns are matched by position, and cast to the table's types.
+    let source_fields = source.schema().fields();
+    if source_fields.len() != table_schema.fields().len() {
+        return Err(DataFusionError::Plan(format!(
+            "COPY FROM source has {} columns, but table '{}' has {} columns",
+            source_fields.len(),
+            plan.table.meta.name,
+            table_schema.fields().len(),
+        )));
+    }
+    let projection: Vec<_> = source_fields
+        .iter()
+        .zip(table_schema.fields())
+        .map(|(source_field, table_field)| {
+            cast(
+                Expr::Column(source_field.qualified_column()),
+                table_field.data_type().clone(),
+            )
+            .alias(table_field.name())
+        })
+        .collect();
+    let source = source.select(projection)?.create_physical_plan().await?;
+    let source = Arc::new(CopyProgressExec::new(source, progress.clone()));
+
+    let batch = InsertExec::do_insert(table.into_table_provider(), source, ctx.task_ctx()).await?;
+    let num_copied = get_count_from_batch(&batch).unwrap_or_default();
+    progress.finish();
+
+    Ok(new_operation_with_count_batch("copy", num_copied))
+}
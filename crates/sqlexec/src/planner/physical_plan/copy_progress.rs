@@ -0,0 +1,366 @@
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, RecordBatchStream,
+    SendableRecordBatchStream, Statistics,
+};
+use futures::{Stream, StreamExt};
+use object_store_util::counting::ObjectStoreCounts;
+use std::any::Any;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tracing::info;
+
+use crate::notice::{Notice, SessionNotices};
+
+/// Number of rows between progress reports for a COPY.
+const PROGRESS_REPORT_INTERVAL_ROWS: u64 = 100_000;
+
+/// Which way a COPY is moving data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyDirection {
+    To,
+    From,
+}
+
+/// Progress of a COPY.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CopyProgress {
+    /// Rows read from the source so far.
+    pub rows: u64,
+    /// Bytes written to (COPY TO) or read from (COPY FROM) object storage so
+    /// far.
+    pub bytes: u64,
+    /// Files written so far. Always zero for COPY FROM.
+    pub files: u64,
+}
+
+/// Tracks the progress of a COPY, reporting it to the client as notices.
+///
+/// Bytes and files are counted by wrapping the object store used for the COPY
+/// with a `CountingObjectStore` using `counts`.
+#[derive(Debug)]
+pub struct CopyProgressTracker {
+    direction: CopyDirection,
+    rows: AtomicU64,
+    counts: Arc<ObjectStoreCounts>,
+    notices: Option<Arc<SessionNotices>>,
+    reported: AtomicBool,
+}
+
+impl CopyProgressTracker {
+    pub fn new(direction: CopyDirection, context: &TaskContext) -> CopyProgressTracker {
+        CopyProgressTracker {
+            direction,
+            rows: AtomicU64::new(0),
+            counts: Arc::new(ObjectStoreCounts::default()),
+            // Only local sessions have notices.
+            notices: context.session_config().get_extension::<SessionNotices>(),
+            reported: AtomicBool::new(false),
+        }
+    }
+
+    /// Counts to use for the object store the COPY reads from or writes to.
+    pub fn counts(&self) -> Arc<ObjectStoreCounts> {
+        self.counts.clone()
+    }
+
+    pub fn progress(&self) -> CopyProgress {
+        let rows = self.rows.load(Ordering::Relaxed);
+        match self.direction {
+            CopyDirection::To => CopyProgress {
+                rows,
+                bytes: self.counts.bytes_written(),
+                files: self.counts.objects_written(),
+            },
+            CopyDirection::From => CopyProgress {
+                rows,
+                bytes: self.counts.bytes_read(),
+                files: 0,
+            },
+        }
+    }
+
+    /// Report the current progress.
+    pub fn report(&self) {
+        let progress = self.progress();
+        info!(
+            rows = progress.rows,
+            bytes = progress.bytes,
+            files = progress.files,
+            "copy progress"
+        );
+
+        let message = match self.direction {
+            CopyDirection::To => format!(
+                "COPY TO progress: {} rows, {} bytes written to {} files",
+                progress.rows, progress.bytes, progress.files
+            ),
+            CopyDirection::From => format!(
+                "COPY FROM progress: {} rows, {} bytes read",
+                progress.rows, progress.bytes
+            ),
+        };
+        if let Some(notices) = &self.notices {
+            notices.send(Notice::new(message));
+        }
+        self.reported.store(true, Ordering::Relaxed);
+    }
+
+    /// Report the final progress once the COPY completes.
+    ///
+    /// Nothing is reported for a COPY that finished before its first progress
+    /// report.
+    pub fn finish(&self) {
+        if self.reported.load(Ordering::Relaxed) {
+            self.report();
+        }
+    }
+
+    /// Wrap a stream of the rows being copied, reporting progress as rows
+    /// are read from it.
+    pub fn stream(self: &Arc<Self>, inner: SendableRecordBatchStream) -> SendableRecordBatchStream {
+        Box::pin(CopyProgressStream::new(
+            inner,
+            self.clone(),
+            PROGRESS_REPORT_INTERVAL_ROWS,
+        ))
+    }
+
+    fn add_rows(&self, rows: u64) -> u64 {
+        self.rows.fetch_add(rows, Ordering::Relaxed) + rows
+    }
+}
+
+/// Stream adapter that counts the rows passing through it, reporting
+/// progress every `interval` rows.
+struct CopyProgressStream {
+    inner: SendableRecordBatchStream,
+    tracker: Arc<CopyProgressTracker>,
+    interval: u64,
+    next_report: u64,
+}
+
+impl CopyProgressStream {
+    fn new(
+        inner: SendableRecordBatchStream,
+        tracker: Arc<CopyProgressTracker>,
+        interval: u64,
+    ) -> Self {
+        let next_report = tracker.progress().rows + interval;
+        CopyProgressStream {
+            inner,
+            tracker,
+            interval,
+            next_report,
+        }
+    }
+}
+
+impl Stream for CopyProgressStream {
+    type Item = DataFusionResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let poll = this.inner.poll_next_unpin(cx);
+        if let Poll::Ready(Some(Ok(batch))) = &poll {
+            let rows = this.tracker.add_rows(batch.num_rows() as u64);
+            if rows >= this.next_report {
+                this.tracker.report();
+                // Skip over any intervals covered by a large batch.
+                while this.next_report <= rows {
+                    this.next_report += this.interval;
+                }
+            }
+        }
+        poll
+    }
+}
+
+impl RecordBatchStream for CopyProgressStream {
+    fn schema(&self) -> SchemaRef {
+        self.inner.schema()
+    }
+}
+
+/// Reports progress for the rows read from its input.
+///
+/// Used when the rows being copied are consumed by another plan (e.g. the
+/// insert for COPY FROM).
+#[derive(Debug)]
+pub struct CopyProgressExec {
+    input: Arc<dyn ExecutionPlan>,
+    tracker: Arc<CopyProgressTracker>,
+}
+
+impl CopyProgressExec {
+    pub fn new(input: Arc<dyn ExecutionPlan>, tracker: Arc<CopyProgressTracker>) -> Self {
+        CopyProgressExec { input, tracker }
+    }
+}
+
+impl ExecutionPlan for CopyProgressExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.input.output_partitioning()
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.input.output_ordering()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        match children.as_slice() {
+            [input] => Ok(Arc::new(CopyProgressExec::new(
+                input.clone(),
+                self.tracker.clone(),
+            ))),
+            _ => Err(DataFusionError::Plan(
+                "CopyProgressExec expects exactly one child".to_string(),
+            )),
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        let stream = self.input.execute(partition, context)?;
+        Ok(self.tracker.stream(stream))
+    }
+
+    fn statistics(&self) -> Statistics {
+        self.input.statistics()
+    }
+}
+
+impl DisplayAs for CopyProgressExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CopyProgressExec")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::arrow::array::Int64Array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::execution::context::{SessionConfig, SessionContext};
+    use datafusion::physical_plan::insert::DataSink;
+    use datafusion::physical_plan::memory::MemoryStream;
+    use datasources::common::sink::csv::{CsvSink, CsvSinkOpts};
+    use object_store::memory::InMemory;
+    use object_store::path::Path as ObjectPath;
+    use object_store::ObjectStore;
+    use object_store_util::counting::CountingObjectStore;
+
+    /// Stream of `num_batches` batches of 10 rows each.
+    fn source(num_batches: i64) -> SendableRecordBatchStream {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batches = (0..num_batches)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![Arc::new(Int64Array::from(vec![i; 10]))],
+                )
+                .unwrap()
+            })
+            .collect();
+        Box::pin(MemoryStream::try_new(batches, schema, None).unwrap())
+    }
+
+    #[tokio::test]
+    async fn progress_reported_across_exported_files() {
+        let notices = Arc::new(SessionNotices::default());
+        let mut receiver = notices.subscribe();
+        let context =
+            SessionContext::new_with_config(SessionConfig::new().with_extension(notices.clone()))
+                .task_ctx();
+
+        let tracker = Arc::new(CopyProgressTracker::new(CopyDirection::To, &context));
+        let inner: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let store: Arc<dyn ObjectStore> =
+            Arc::new(CountingObjectStore::new(inner.clone(), tracker.counts()));
+
+        let paths: Vec<_> = (0..3)
+            .map(|i| ObjectPath::from(format!("export/{i}.csv")))
+            .collect();
+        for path in &paths {
+            let sink = CsvSink::from_obj_store(store.clone(), path.clone(), CsvSinkOpts::default());
+            let stream = Box::pin(CopyProgressStream::new(source(5), tracker.clone(), 20));
+            sink.write_all(vec![stream], &context).await.unwrap();
+        }
+        tracker.finish();
+
+        let mut bytes = 0;
+        for path in &paths {
+            bytes += inner.head(path).await.unwrap().size as u64;
+        }
+        assert_eq!(
+            CopyProgress {
+                rows: 150,
+                bytes,
+                files: 3,
+            },
+            tracker.progress()
+        );
+
+        // Every 20 rows read from each file's source, then once more on
+        // completion.
+        let mut reported = Vec::new();
+        while let Ok(notice) = receiver.try_recv() {
+            reported.push(notice.message);
+        }
+        assert_eq!(7, reported.len(), "reported: {reported:?}");
+        for (message, rows) in reported.iter().zip([20, 40, 70, 90, 120, 140]) {
+            assert!(
+                message.starts_with(&format!("COPY TO progress: {rows} rows")),
+                "message: {message}"
+            );
+        }
+        assert_eq!(
+            &format!("COPY TO progress: 150 rows, {bytes} bytes written to 3 files"),
+            reported.last().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn nothing_reported_for_short_copy() {
+        let notices = Arc::new(SessionNotices::default());
+        let mut receiver = notices.subscribe();
+        let context =
+            SessionContext::new_with_config(SessionConfig::new().with_extension(notices.clone()))
+                .task_ctx();
+
+        let tracker = Arc::new(CopyProgressTracker::new(CopyDirection::From, &context));
+        let batches: Vec<_> = CopyProgressStream::new(source(1), tracker.clone(), 20)
+            .collect()
+            .await;
+        assert_eq!(1, batches.len());
+        tracker.finish();
+
+        assert_eq!(10, tracker.progress().rows);
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -21,6 +21,8 @@ use datasources::object_store::s3::S3StoreAccess;
 use datasources::object_store::ObjStoreAccess;
 use futures::stream;
 use object_store::azure::AzureConfigKey;
+use object_store::ObjectStore;
+use object_store_util::counting::CountingObjectStore;
 use protogen::metastore::types::options::{
     CopyToDestinationOptions, CopyToFormatOptions, StorageOptions,
 };
@@ -28,7 +30,11 @@ use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{new_operation_with_count_batch, GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA};
+use super::copy_progress::{CopyDirection, CopyProgressTracker};
+use super::{
+    new_operation_statistics, new_operation_with_count_batch,
+    GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
+};
 
 #[derive(Debug, Clone)]
 pub struct CopyToExec {
@@ -92,7 +98,7 @@ impl ExecutionPlan for CopyToExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -104,6 +110,7 @@ impl DisplayAs for CopyToExec {
 
 impl CopyToExec {
     async fn copy_to(self, context: Arc<TaskContext>) -> DataFusionResult<RecordBatch> {
+        let progress = Arc::new(CopyProgressTracker::new(CopyDirection::To, &context));
         let sink = match (self.dest, self.format) {
             (CopyToDestinationOptions::Local(local_options), format) => {
                 {
@@ -111,14 +118,14 @@ impl CopyToExec {
                     let _ = tokio::fs::File::create(&local_options.location).await?;
                 }
                 let access = LocalStoreAccess;
-                get_sink_for_obj(format, &access, &local_options.location)?
+                get_sink_for_obj(format, &access, &local_options.location, &progress)?
             }
             (CopyToDestinationOptions::Gcs(gcs_options), format) => {
                 let access = GcsStoreAccess {
                     bucket: gcs_options.bucket,
                     service_account_key: gcs_options.service_account_key,
                 };
-                get_sink_for_obj(format, &access, &gcs_options.location)?
+                get_sink_for_obj(format, &access, &gcs_options.location, &progress)?
             }
             (CopyToDestinationOptions::S3(s3_options), format) => {
                 let access = S3StoreAccess {
@@ -127,7 +134,7 @@ impl CopyToExec {
                     access_key_id: s3_options.access_key_id,
                     secret_access_key: s3_options.secret_access_key,
                 };
-                get_sink_for_obj(format, &access, &s3_options.location)?
+                get_sink_for_obj(format, &access, &s3_options.location, &progress)?
             }
             (CopyToDestinationOptions::Azure(azure_options), format) => {
                 // Create storage options using well-known key names.
@@ -156,12 +163,15 @@ impl CopyToExec {
                 let source_url = DatasourceUrl::try_new(&azure_options.location)
                     .map_err(|e| DataFusionError::External(Box::new(e)))?;
 
-                get_sink_for_obj(format, &access, &source_url.path())?
+                get_sink_for_obj(format, &access, &source_url.path(), &progress)?
             }
         };
 
         let stream = execute_stream(self.source, context.clone())?;
-        let count = sink.write_all(vec![stream], &context).await?;
+        let count = sink
+            .write_all(vec![progress.stream(stream)], &context)
+            .await?;
+        progress.finish();
 
         Ok(new_operation_with_count_batch("copy", count))
     }
@@ -172,10 +182,12 @@ fn get_sink_for_obj(
     format: CopyToFormatOptions,
     access: &dyn ObjStoreAccess,
     location: &str,
+    progress: &CopyProgressTracker,
 ) -> DataFusionResult<Box<dyn DataSink>> {
     let store = access
         .create_store()
         .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    let store: Arc<dyn ObjectStore> = Arc::new(CountingObjectStore::new(store, progress.counts()));
     let path = access
         .path(location)
         .map_err(|e| DataFusionError::External(Box::new(e)))?;
@@ -112,7 +112,7 @@ impl ExecutionPlan for CreateCredentialsExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -18,7 +18,7 @@ use protogen::metastore::types::{service, service::Mutation};
 use sqlbuiltins::builtins::DEFAULT_CATALOG;
 use tracing::debug;
 
-use super::GENERIC_OPERATION_PHYSICAL_SCHEMA;
+use super::{new_operation_statistics, GENERIC_OPERATION_PHYSICAL_SCHEMA};
 use crate::{
     errors::ExecError,
     metastore::catalog::{CatalogMutator, SessionCatalog},
@@ -104,7 +104,7 @@ impl ExecutionPlan for CreateTableExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
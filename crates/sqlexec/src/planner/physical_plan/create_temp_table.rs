@@ -74,7 +74,7 @@ impl ExecutionPlan for CreateTempTableExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
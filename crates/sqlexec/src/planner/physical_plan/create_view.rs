@@ -15,7 +15,7 @@ use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{new_operation_batch, GENERIC_OPERATION_PHYSICAL_SCHEMA};
+use super::{new_operation_batch, new_operation_statistics, GENERIC_OPERATION_PHYSICAL_SCHEMA};
 
 #[derive(Debug, Clone)]
 pub struct CreateViewExec {
@@ -24,6 +24,7 @@ pub struct CreateViewExec {
     pub sql: String,
     pub columns: Vec<String>,
     pub or_replace: bool,
+    pub if_not_exists: bool,
 }
 
 impl ExecutionPlan for CreateViewExec {
@@ -81,7 +82,7 @@ impl ExecutionPlan for CreateViewExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -104,6 +105,7 @@ async fn create_view(
                 sql: plan.sql,
                 or_replace: plan.or_replace,
                 columns: plan.columns,
+                if_not_exists: plan.if_not_exists,
             })],
         )
         .await
@@ -15,7 +15,10 @@ use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
-use super::{new_operation_with_count_batch, GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA};
+use super::{
+    new_operation_statistics, new_operation_with_count_batch,
+    GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
+};
 
 #[derive(Debug, Clone)]
 pub struct DeleteExec {
@@ -78,7 +81,7 @@ impl ExecutionPlan for DeleteExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
@@ -20,7 +20,10 @@ use std::fmt;
 use std::sync::Arc;
 
 use super::remote_scan::ProviderReference;
-use super::{new_operation_with_count_batch, GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA};
+use super::{
+    new_operation_statistics, new_operation_with_count_batch,
+    GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
+};
 
 #[derive(Debug, Clone)]
 pub struct InsertExec {
@@ -92,7 +95,7 @@ impl ExecutionPlan for InsertExec {
     }
 
     fn statistics(&self) -> Statistics {
-        Statistics::default()
+        new_operation_statistics()
     }
 }
 
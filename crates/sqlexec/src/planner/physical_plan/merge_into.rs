@@ -0,0 +1,163 @@
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::streaming::StreamingTable;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::execution::TaskContext;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::{
+    stream::RecordBatchStreamAdapter, streaming::PartitionStream, DisplayAs, DisplayFormatType,
+    ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+};
+use datafusion::prelude::{Expr, SessionContext};
+use datasources::native::access::{MergeIntoClause, NativeTableStorage};
+use futures::stream;
+use protogen::metastore::types::catalog::TableEntry;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use super::{
+    new_operation_statistics, new_operation_with_count_batch,
+    GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA,
+};
+
+#[derive(Debug, Clone)]
+pub struct MergeIntoExec {
+    pub table: TableEntry,
+    pub source: Arc<dyn ExecutionPlan>,
+    pub target_alias: String,
+    pub source_alias: String,
+    pub on: Expr,
+    pub clauses: Vec<MergeIntoClause>,
+}
+
+impl ExecutionPlan for MergeIntoExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.source.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> DataFusionResult<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(MergeIntoExec {
+            source: children.get(0).unwrap().clone(),
+            ..self.as_ref().clone()
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> DataFusionResult<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Execution(
+                "MergeIntoExec only supports 1 partition".to_string(),
+            ));
+        }
+
+        let storage = context
+            .session_config()
+            .get_extension::<NativeTableStorage>()
+            .expect("context should have native table storage");
+
+        let stream = stream::once(merge_into(self.clone(), storage, context));
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn statistics(&self) -> Statistics {
+        new_operation_statistics()
+    }
+}
+
+impl DisplayAs for MergeIntoExec {
+    fn fmt_as(&self, _t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MergeIntoExec")
+    }
+}
+
+async fn merge_into(
+    plan: MergeIntoExec,
+    storage: impl AsRef<NativeTableStorage>,
+    context: Arc<TaskContext>,
+) -> DataFusionResult<RecordBatch> {
+    let storage = storage.as_ref();
+
+    // The source is joined against the table by delta, which needs it as a
+    // data frame. Each partition of the source is streamed in as delta reads
+    // it.
+    let schema = plan.source.schema();
+    let partitions = (0..plan.source.output_partitioning().partition_count())
+        .map(|partition| {
+            Arc::new(SourcePartition {
+                schema: schema.clone(),
+                source: plan.source.clone(),
+                partition,
+                context: context.clone(),
+            }) as Arc<dyn PartitionStream>
+        })
+        .collect();
+    let source =
+        SessionContext::new().read_table(Arc::new(StreamingTable::try_new(schema, partitions)?))?;
+
+    let num_merged = storage
+        .merge_into(
+            &plan.table,
+            source,
+            &plan.target_alias,
+            &plan.source_alias,
+            plan.on,
+            plan.clauses,
+        )
+        .await
+        .map_err(|e| DataFusionError::Execution(format!("failed to merge: {e}")))?;
+
+    Ok(new_operation_with_count_batch("merge", num_merged as u64))
+}
+
+/// A single partition of the merge source.
+struct SourcePartition {
+    schema: Arc<Schema>,
+    source: Arc<dyn ExecutionPlan>,
+    partition: usize,
+    /// Context the merge is executing in, the source is executed with this
+    /// instead of the context delta provides.
+    context: Arc<TaskContext>,
+}
+
+impl PartitionStream for SourcePartition {
+    fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        match self.source.execute(self.partition, self.context.clone()) {
+            Ok(stream) => stream,
+            Err(e) => Box::pin(RecordBatchStreamAdapter::new(
+                self.schema.clone(),
+                stream::once(async move { Err(e) }),
+            )),
+        }
+    }
+}
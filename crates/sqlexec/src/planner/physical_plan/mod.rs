@@ -3,6 +3,8 @@ pub mod alter_table;
 pub mod alter_tunnel_rotate_keys;
 pub mod client_recv;
 pub mod client_send;
+pub mod copy_from;
+pub mod copy_progress;
 pub mod copy_to;
 pub mod create_credentials;
 pub mod create_external_database;
@@ -22,11 +24,13 @@ pub mod drop_temp_tables;
 pub mod drop_tunnel;
 pub mod drop_views;
 pub mod insert;
+pub mod merge_into;
 pub mod remote_exec;
 pub mod remote_scan;
 pub mod send_recv;
 pub mod set_var;
 pub mod show_var;
+pub mod truncate_tables;
 pub mod update;
 pub mod values;
 
@@ -76,6 +80,19 @@ pub static GENERIC_OPERATION_AND_COUNT_PHYSICAL_SCHEMA: Lazy<Arc<Schema>> = Lazy
     ]))
 });
 
+/// Statistics for plans that output a single operation batch.
+///
+/// These plans always produce exactly one row, which lets the optimizer
+/// account for them when they're used as inputs to other plans.
+pub fn new_operation_statistics() -> Statistics {
+    Statistics {
+        num_rows: Some(1),
+        total_byte_size: None,
+        column_statistics: None,
+        is_exact: true,
+    }
+}
+
 /// Create a new single-row record batch representing the ouptput for updates
 /// and deletes where count is rows affected.
 pub fn new_operation_with_count_batch(operation: impl Into<String>, count: u64) -> RecordBatch {
@@ -108,3 +125,23 @@ pub fn get_count_from_batch(batch: &RecordBatch) -> Option<u64> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::delete::DeleteExec;
+    use super::*;
+    use crate::planner::logical_plan::testutil::table_entry;
+
+    #[test]
+    fn operation_execs_report_single_row() {
+        let exec = DeleteExec {
+            table: table_entry(),
+            where_expr: None,
+        };
+
+        let stats = exec.statistics();
+        assert_ne!(Statistics::default(), stats);
+        assert_eq!(Some(1), stats.num_rows);
+        assert!(stats.is_exact);
+    }
+}
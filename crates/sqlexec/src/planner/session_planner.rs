@@ -6,8 +6,10 @@ use datafusion::arrow::datatypes::{
     DataType, Field, Schema, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL_DEFAULT_SCALE,
 };
 use datafusion::common::parsers::CompressionTypeVariant;
-use datafusion::common::{FileType, OwnedSchemaReference, OwnedTableReference, ToDFSchema};
-use datafusion::logical_expr::{cast, col, LogicalPlanBuilder};
+use datafusion::common::{
+    DFSchema, FileType, OwnedSchemaReference, OwnedTableReference, ToDFSchema,
+};
+use datafusion::logical_expr::{cast, col, LogicalPlan as DfLogicalPlan, LogicalPlanBuilder};
 use datafusion::sql::planner::{object_name_to_table_reference, IdentNormalizer, PlannerContext};
 use datafusion::sql::sqlparser::ast::{self, Ident, ObjectName, ObjectType};
 use datafusion::sql::TableReference;
@@ -21,6 +23,7 @@ use datasources::lake::delta::access::{load_table_direct, DeltaLakeAccessor};
 use datasources::lake::iceberg::table::IcebergTable;
 use datasources::mongodb::{MongoAccessor, MongoDbConnection};
 use datasources::mysql::{MysqlAccessor, MysqlDbConnection, MysqlTableAccess};
+use datasources::native::access::MergeIntoClause;
 use datasources::object_store::gcs::GcsStoreAccess;
 use datasources::object_store::generic::GenericStoreAccess;
 use datasources::object_store::local::LocalStoreAccess;
@@ -43,10 +46,11 @@ use protogen::metastore::types::options::{
     CredentialsOptionsGcp, DatabaseOptions, DatabaseOptionsBigQuery, DatabaseOptionsDebug,
     DatabaseOptionsDeltaLake, DatabaseOptionsMongo, DatabaseOptionsMysql, DatabaseOptionsPostgres,
     DatabaseOptionsSnowflake, DatabaseOptionsSqlServer, DeltaLakeCatalog, DeltaLakeUnityCatalog,
-    StorageOptions, TableOptions, TableOptionsBigQuery, TableOptionsDebug, TableOptionsGcs,
-    TableOptionsLocal, TableOptionsMongo, TableOptionsMysql, TableOptionsObjectStore,
-    TableOptionsPostgres, TableOptionsS3, TableOptionsSnowflake, TableOptionsSqlServer,
-    TunnelOptions, TunnelOptionsDebug, TunnelOptionsInternal, TunnelOptionsSsh,
+    InternalColumnDefinition, StorageOptions, TableOptions, TableOptionsBigQuery,
+    TableOptionsDebug, TableOptionsGcs, TableOptionsLocal, TableOptionsMongo, TableOptionsMysql,
+    TableOptionsObjectStore, TableOptionsPostgres, TableOptionsS3, TableOptionsSnowflake,
+    TableOptionsSqlServer, TunnelOptions, TunnelOptionsDebug, TunnelOptionsInternal,
+    TunnelOptionsSsh,
 };
 use protogen::metastore::types::service::{AlterDatabaseOperation, AlterTableOperation};
 use sqlbuiltins::builtins::{CURRENT_SESSION_SCHEMA, DEFAULT_CATALOG};
@@ -61,9 +65,9 @@ use crate::context::local::LocalSessionContext;
 use crate::parser::options::StmtOptions;
 use crate::parser::{
     self, validate_ident, validate_object_name, AlterDatabaseStmt, AlterTableStmtExtension,
-    AlterTunnelAction, AlterTunnelStmt, CopyToSource, CopyToStmt, CreateCredentialsStmt,
-    CreateExternalDatabaseStmt, CreateExternalTableStmt, CreateTunnelStmt, DropCredentialsStmt,
-    DropDatabaseStmt, DropTunnelStmt, StatementWithExtensions,
+    AlterTunnelAction, AlterTunnelStmt, CopyFromStmt, CopyToSource, CopyToStmt,
+    CreateCredentialsStmt, CreateExternalDatabaseStmt, CreateExternalTableStmt, CreateTunnelStmt,
+    CreateViewStmt, DropCredentialsStmt, DropDatabaseStmt, DropTunnelStmt, StatementWithExtensions,
 };
 use crate::planner::errors::{internal, PlanError, Result};
 use crate::planner::logical_plan::*;
@@ -72,7 +76,7 @@ use crate::remote::table::StubRemoteTableProvider;
 use crate::resolve::{EntryResolver, ResolvedEntry};
 
 use super::context_builder::PartialContextProvider;
-use super::extension::ExtensionNode;
+use super::extension::{ExtensionNode, ExtensionType};
 use super::physical_plan::remote_scan::ProviderReference;
 
 /// Plan SQL statements for a session.
@@ -94,7 +98,7 @@ impl<'a> SessionPlanner<'a> {
             preprocess(inner, &mut EscapedStringToDoubleQuoted)?;
         }
 
-        match statement {
+        let plan = match statement {
             StatementWithExtensions::Statement(stmt) => self.plan_statement(stmt).await,
             StatementWithExtensions::CreateExternalTable(stmt) => {
                 self.plan_create_external_table(stmt).await
@@ -113,7 +117,15 @@ impl<'a> SessionPlanner<'a> {
             StatementWithExtensions::CreateCredentials(stmt) => self.plan_create_credentials(stmt),
             StatementWithExtensions::DropCredentials(stmt) => self.plan_drop_credentials(stmt),
             StatementWithExtensions::CopyTo(stmt) => self.plan_copy_to(stmt).await,
+            StatementWithExtensions::CopyFrom(stmt) => self.plan_copy_from(stmt),
+            StatementWithExtensions::CreateView(stmt) => self.plan_create_view(stmt).await,
+        }?;
+
+        if self.ctx.get_session_vars().read_only() && plan_modifies_database(&plan)? {
+            return Err(PlanError::ReadOnly);
         }
+
+        Ok(plan)
     }
 
     async fn plan_create_external_database(
@@ -916,49 +928,18 @@ impl<'a> SessionPlanner<'a> {
                 with_options,
                 ..
             } => {
-                validate_object_name(&name)?;
-                let name = object_name_to_table_ref(name)?;
-
                 if !with_options.is_empty() {
                     return Err(PlanError::UnsupportedFeature("view options"));
                 }
 
-                match query.body.as_ref() {
-                    ast::SetExpr::Select(select) => select.projection.len(),
-                    ast::SetExpr::Values(values) => {
-                        values.rows.first().map(|first| first.len()).unwrap_or(0)
-                    }
-                    _ => {
-                        return Err(PlanError::InvalidViewStatement {
-                            msg: "view body must either be a SELECT or VALUES statement",
-                        })
-                    }
-                };
-
-                let query_string = query.to_string();
-
-                // Check that this is a valid body.
-                // TODO: Avoid cloning.
-                let mut planner = SqlQueryPlanner::new(&mut context_provider);
-                let input = planner.query_to_plan(*query).await?;
-
-                let columns: Vec<_> = columns.into_iter().map(normalize_ident).collect();
-                // Only validate number of aliases equals number of fields in
-                // the ouput if aliases were actually provided.
-                if !columns.is_empty() && input.schema().fields().len() != columns.len() {
-                    Err(PlanError::InvalidNumberOfAliasesForView {
-                        sql: query_string,
-                        aliases: columns,
-                    })
-                } else {
-                    Ok(CreateView {
-                        view_reference: self.ctx.resolve_table_ref(name)?,
-                        sql: query_string,
-                        columns,
-                        or_replace,
-                    }
-                    .into_logical_plan())
-                }
+                self.plan_create_view(CreateViewStmt {
+                    name,
+                    columns,
+                    query: *query,
+                    or_replace,
+                    if_not_exists: false,
+                })
+                .await
             }
 
             ast::Statement::Insert {
@@ -1042,15 +1023,15 @@ impl<'a> SessionPlanner<'a> {
                 }
                 let operation = operations.pop().unwrap();
 
-                match operation {
-                    ast::AlterTableOperation::RenameTable { table_name } => {
-                        validate_object_name(&name)?;
-                        let name = object_name_to_table_ref(name)?;
-                        let name = self.ctx.resolve_table_ref(name)?;
+                validate_object_name(&name)?;
+                let name = object_name_to_table_ref(name)?;
+                let name = self.ctx.resolve_table_ref(name)?;
 
-                        let schema = name.schema.into_owned();
-                        let name = name.name.into_owned();
+                let schema = name.schema.into_owned();
+                let name = name.name.into_owned();
 
+                match operation {
+                    ast::AlterTableOperation::RenameTable { table_name } => {
                         let new_name = match table_name {
                             ObjectName(mut objs) if objs.len() == 1 => objs.pop().unwrap(),
                             _ => {
@@ -1066,6 +1047,87 @@ impl<'a> SessionPlanner<'a> {
                             schema,
                             name,
                             operation: AlterTableOperation::RenameTable { new_name },
+                            backfill: None,
+                        }
+                        .into_logical_plan())
+                    }
+                    ast::AlterTableOperation::AddColumn {
+                        if_not_exists,
+                        column_def,
+                        ..
+                    } => {
+                        if if_not_exists {
+                            return Err(PlanError::UnsupportedFeature(
+                                "ALTER TABLE ADD COLUMN IF NOT EXISTS",
+                            ));
+                        }
+
+                        // Existing rows are filled with the default if one
+                        // is provided, and read back as nulls otherwise.
+                        let mut nullable = true;
+                        let mut default = None;
+                        for opt in column_def.options {
+                            match opt.option {
+                                ast::ColumnOption::Null => nullable = true,
+                                ast::ColumnOption::NotNull => nullable = false,
+                                ast::ColumnOption::Default(expr) => default = Some(expr),
+                                _ => {
+                                    return Err(PlanError::UnsupportedFeature(
+                                        "ALTER TABLE ADD COLUMN with column constraints",
+                                    ))
+                                }
+                            }
+                        }
+                        if !nullable && default.is_none() {
+                            return Err(PlanError::InvalidAlterStatement {
+                                msg: "NOT NULL column must have a DEFAULT to fill existing rows",
+                            });
+                        }
+
+                        validate_ident(&column_def.name)?;
+                        let column = InternalColumnDefinition {
+                            name: normalize_ident(column_def.name),
+                            nullable,
+                            arrow_type: convert_data_type(&column_def.data_type)?,
+                        };
+
+                        let backfill = match default {
+                            Some(expr) => {
+                                let mut planner = SqlQueryPlanner::new(&mut context_provider);
+                                let expr = planner
+                                    .sql_to_expr(
+                                        expr,
+                                        &DFSchema::empty(),
+                                        &mut PlannerContext::new(),
+                                    )
+                                    .await?;
+                                Some(cast(expr, column.arrow_type.clone()))
+                            }
+                            None => None,
+                        };
+
+                        Ok(AlterTable {
+                            schema,
+                            name,
+                            operation: AlterTableOperation::AddColumn { column },
+                            backfill,
+                        }
+                        .into_logical_plan())
+                    }
+                    ast::AlterTableOperation::DropColumn {
+                        column_name,
+                        if_exists,
+                        ..
+                    } => {
+                        validate_ident(&column_name)?;
+                        Ok(AlterTable {
+                            schema,
+                            name,
+                            operation: AlterTableOperation::DropColumn {
+                                name: normalize_ident(column_name),
+                                if_exists,
+                            },
+                            backfill: None,
                         }
                         .into_logical_plan())
                     }
@@ -1084,7 +1146,7 @@ impl<'a> SessionPlanner<'a> {
                 for name in names.into_iter() {
                     validate_object_name(&name)?;
                     let r = object_name_to_table_ref(name)?;
-                    refs.push(self.ctx.resolve_table_ref(r)?);
+                    refs.push(self.ctx.resolve_existing_table_ref(r)?);
                 }
 
                 let plan = DropTables {
@@ -1094,6 +1156,36 @@ impl<'a> SessionPlanner<'a> {
                 Ok(plan.into_logical_plan())
             }
 
+            // "TRUNCATE <table>"
+            //
+            // Removes all rows from the table, keeping its schema.
+            ast::Statement::Truncate {
+                table_name,
+                partitions: None,
+                ..
+            } => {
+                validate_object_name(&table_name)?;
+                let r = object_name_to_table_ref(table_name)?;
+                // Bare names resolve to temp tables first, same as in queries.
+                let r = match r {
+                    TableReference::Bare { ref table }
+                        if self.ctx.get_temp_objects().contains_table(table) =>
+                    {
+                        FullObjectReference {
+                            database: DEFAULT_CATALOG.into(),
+                            schema: CURRENT_SESSION_SCHEMA.into(),
+                            name: table.to_string().into(),
+                        }
+                    }
+                    r => self.ctx.resolve_table_ref(r)?,
+                };
+
+                Ok(TruncateTables {
+                    tbl_references: vec![r],
+                }
+                .into_logical_plan())
+            }
+
             // Drop views
             ast::Statement::Drop {
                 object_type: ObjectType::View,
@@ -1306,6 +1398,196 @@ impl<'a> SessionPlanner<'a> {
                 .into_logical_plan())
             }
 
+            // "MERGE INTO <table> USING <source> ON <expression> WHEN ..."
+            //
+            // update, delete, or insert rows in a table depending on whether
+            // they match rows in the source.
+            ast::Statement::Merge {
+                table,
+                source,
+                on,
+                clauses,
+                ..
+            } => {
+                let (table_name, target_alias) = match table {
+                    ast::TableFactor::Table { name, alias, .. } => {
+                        validate_object_name(&name)?;
+                        let target_alias = match alias {
+                            Some(alias) => normalize_ident(alias.name),
+                            None => normalize_ident(name.0.last().cloned().unwrap()),
+                        };
+                        (object_name_to_table_ref(name)?, target_alias)
+                    }
+                    _ => {
+                        return Err(PlanError::InvalidMergeStatement {
+                            msg: "MERGE target must be a table",
+                        })
+                    }
+                };
+
+                let source_alias = match &source {
+                    ast::TableFactor::Table {
+                        alias: Some(alias), ..
+                    }
+                    | ast::TableFactor::Derived {
+                        alias: Some(alias), ..
+                    } => normalize_ident(alias.name.clone()),
+                    ast::TableFactor::Table { name, .. } => {
+                        normalize_ident(name.0.last().cloned().unwrap())
+                    }
+                    _ => {
+                        return Err(PlanError::InvalidMergeStatement {
+                            msg: "MERGE source must be a table or an aliased subquery",
+                        })
+                    }
+                };
+                if source_alias == target_alias {
+                    return Err(PlanError::InvalidMergeStatement {
+                        msg: "MERGE source and target must have different names",
+                    });
+                }
+
+                let table_source = context_provider
+                    .get_table_provider(table_name.clone())
+                    .await?;
+                let table_schema = table_source.schema();
+
+                let mut planner = SqlQueryPlanner::new(&mut context_provider);
+                let source = planner.relation_to_plan(source).await?;
+                let source = LogicalPlanBuilder::from(source)
+                    .alias(source_alias.as_str())?
+                    .build()?;
+
+                let mut schema =
+                    DFSchema::try_from_qualified_schema(target_alias.as_str(), &table_schema)?;
+                schema.merge(source.schema());
+
+                let on = planner
+                    .sql_to_expr(*on, &schema, &mut PlannerContext::new())
+                    .await?;
+
+                let mut merge_clauses = Vec::with_capacity(clauses.len());
+                for clause in clauses {
+                    let clause = match clause {
+                        ast::MergeClause::MatchedUpdate {
+                            predicate,
+                            assignments,
+                        } => {
+                            let predicate = match predicate {
+                                Some(expr) => Some(
+                                    planner
+                                        .sql_to_expr(expr, &schema, &mut PlannerContext::new())
+                                        .await?,
+                                ),
+                                None => None,
+                            };
+                            let mut updates = Vec::with_capacity(assignments.len());
+                            for assignment in assignments {
+                                // Allow the column to be qualified with the
+                                // target name.
+                                let column =
+                                    normalize_ident(assignment.id.last().cloned().unwrap());
+                                let value = planner
+                                    .sql_to_expr(
+                                        assignment.value,
+                                        &schema,
+                                        &mut PlannerContext::new(),
+                                    )
+                                    .await?;
+                                updates.push((column, value));
+                            }
+                            MergeIntoClause::MatchedUpdate {
+                                predicate,
+                                assignments: updates,
+                            }
+                        }
+                        ast::MergeClause::MatchedDelete(predicate) => {
+                            let predicate = match predicate {
+                                Some(expr) => Some(
+                                    planner
+                                        .sql_to_expr(expr, &schema, &mut PlannerContext::new())
+                                        .await?,
+                                ),
+                                None => None,
+                            };
+                            MergeIntoClause::MatchedDelete { predicate }
+                        }
+                        ast::MergeClause::NotMatched {
+                            predicate,
+                            columns,
+                            values,
+                        } => {
+                            let predicate = match predicate {
+                                Some(expr) => Some(
+                                    planner
+                                        .sql_to_expr(expr, &schema, &mut PlannerContext::new())
+                                        .await?,
+                                ),
+                                None => None,
+                            };
+                            let mut rows = values.rows;
+                            if rows.len() != 1 {
+                                return Err(PlanError::InvalidMergeStatement {
+                                    msg: "MERGE INSERT must provide exactly one row of values",
+                                });
+                            }
+                            let row = rows.pop().unwrap();
+
+                            let columns: Vec<_> = if columns.is_empty() {
+                                table_schema
+                                    .fields()
+                                    .iter()
+                                    .map(|f| f.name().clone())
+                                    .collect()
+                            } else {
+                                columns.into_iter().map(normalize_ident).collect()
+                            };
+                            if columns.len() != row.len() {
+                                return Err(PlanError::InvalidMergeStatement {
+                                    msg:
+                                        "MERGE INSERT has a different number of columns and values",
+                                });
+                            }
+
+                            let mut inserts = Vec::with_capacity(row.len());
+                            for (column, expr) in columns.into_iter().zip(row) {
+                                let value = planner
+                                    .sql_to_expr(expr, &schema, &mut PlannerContext::new())
+                                    .await?;
+                                inserts.push((column, value));
+                            }
+                            MergeIntoClause::NotMatchedInsert {
+                                predicate,
+                                values: inserts,
+                            }
+                        }
+                    };
+                    merge_clauses.push(clause);
+                }
+
+                let resolver = EntryResolver::from_context(self.ctx);
+                let ent = resolver
+                    .resolve_entry_from_reference(table_name)?
+                    .try_into_table_entry()?;
+                // External merges not supported yet.
+                if ent.meta.external {
+                    return Err(PlanError::UnsupportedFeature("MERGE with external tables"));
+                }
+                if ent.meta.is_temp {
+                    return Err(PlanError::UnsupportedFeature("MERGE with temp tables"));
+                }
+
+                Ok(MergeInto {
+                    table: ent,
+                    source,
+                    target_alias,
+                    source_alias,
+                    on,
+                    clauses: merge_clauses,
+                }
+                .into_logical_plan())
+            }
+
             stmt => Err(PlanError::UnsupportedSQLStatement(stmt.to_string())),
         }
     }
@@ -1413,6 +1695,7 @@ impl<'a> SessionPlanner<'a> {
             schema,
             name,
             operation,
+            backfill: None,
         }
         .into_logical_plan())
     }
@@ -1648,6 +1931,109 @@ impl<'a> SessionPlanner<'a> {
         .into_logical_plan())
     }
 
+    async fn plan_create_view(&self, stmt: CreateViewStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.name)?;
+        let name = object_name_to_table_ref(stmt.name)?;
+        let query = stmt.query;
+
+        match query.body.as_ref() {
+            ast::SetExpr::Select(select) => select.projection.len(),
+            ast::SetExpr::Values(values) => {
+                values.rows.first().map(|first| first.len()).unwrap_or(0)
+            }
+            _ => {
+                return Err(PlanError::InvalidViewStatement {
+                    msg: "view body must either be a SELECT or VALUES statement",
+                })
+            }
+        };
+
+        let query_string = query.to_string();
+
+        // Check that this is a valid body.
+        let state = self.ctx.df_ctx().state();
+        let mut context_provider = PartialContextProvider::new(self.ctx, &state)?;
+        let mut planner = SqlQueryPlanner::new(&mut context_provider);
+        let input = planner.query_to_plan(query).await?;
+
+        let columns: Vec<_> = stmt.columns.into_iter().map(normalize_ident).collect();
+        // Only validate number of aliases equals number of fields in
+        // the ouput if aliases were actually provided.
+        if !columns.is_empty() && input.schema().fields().len() != columns.len() {
+            return Err(PlanError::InvalidNumberOfAliasesForView {
+                sql: query_string,
+                aliases: columns,
+            });
+        }
+
+        Ok(CreateView {
+            view_reference: self.ctx.resolve_table_ref(name)?,
+            sql: query_string,
+            columns,
+            or_replace: stmt.or_replace,
+            if_not_exists: stmt.if_not_exists,
+        }
+        .into_logical_plan())
+    }
+
+    fn plan_copy_from(&self, stmt: CopyFromStmt) -> Result<LogicalPlan> {
+        validate_object_name(&stmt.table)?;
+        let table_ref = object_name_to_table_ref(stmt.table)?;
+
+        let resolver = EntryResolver::from_context(self.ctx);
+        let table = resolver
+            .resolve_entry_from_reference(table_ref)?
+            .try_into_table_entry()?;
+        // Data can only be loaded into native tables.
+        if table.meta.external {
+            return Err(PlanError::UnsupportedFeature(
+                "COPY FROM into external tables",
+            ));
+        }
+        if table.meta.is_temp {
+            return Err(PlanError::UnsupportedFeature("COPY FROM into temp tables"));
+        }
+
+        let location = normalize_ident(stmt.source);
+        // Validate the location up front.
+        let _ = DatasourceUrl::try_new(&location)?;
+
+        let ext = Path::new(&location)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase());
+        let file_type = match stmt
+            .format
+            .map(normalize_ident)
+            // Choose from specified format "OR" from location.
+            .or(ext)
+        {
+            Some(file_type) => match file_type.as_str() {
+                "parquet" | "csv" | "json" => file_type,
+                "ndjson" | "jsonl" => "json".to_string(),
+                other => return Err(PlanError::UnsupportedCopyFromFormat(other.to_string())),
+            },
+            None => return Err(PlanError::UnknownCopyFromFormat { location }),
+        };
+
+        let creds = stmt.credentials.map(normalize_ident);
+        let creds_options = self.get_credentials_opts(&creds)?;
+
+        let mut m = stmt.options;
+        let mut storage_options = StorageOptions::try_from(&mut m)?;
+        if let Some(creds) = creds_options {
+            storage_options_with_credentials(&mut storage_options, creds);
+        }
+
+        Ok(CopyFrom {
+            table,
+            location,
+            file_type,
+            storage_options,
+        }
+        .into_logical_plan())
+    }
+
     fn get_tunnel_opts(&self, tunnel: &Option<String>) -> Result<Option<TunnelOptions>> {
         // Check if the tunnel exists, get tunnel options and pass them on for
         // connection validation.
@@ -1768,6 +2154,29 @@ async fn validate_and_get_file_type_and_compression(
     Ok((file_type, compression))
 }
 
+/// Check if executing the plan would modify the database, either through DDL
+/// or DML.
+fn plan_modifies_database(plan: &LogicalPlan) -> Result<bool> {
+    match plan {
+        LogicalPlan::Datafusion(plan) => df_plan_modifies_database(plan),
+        _ => Ok(false),
+    }
+}
+
+fn df_plan_modifies_database(plan: &DfLogicalPlan) -> Result<bool> {
+    Ok(match plan {
+        DfLogicalPlan::Extension(ext) => ext
+            .node
+            .name()
+            .parse::<ExtensionType>()?
+            .modifies_database(),
+        DfLogicalPlan::Dml(_) | DfLogicalPlan::Ddl(_) | DfLogicalPlan::Copy(_) => true,
+        // EXPLAIN ANALYZE executes its input.
+        DfLogicalPlan::Analyze(analyze) => df_plan_modifies_database(&analyze.input)?,
+        _ => false,
+    })
+}
+
 /// Resolves an ident (unquoted -> lowercase else case sensitive).
 fn normalize_ident(ident: Ident) -> String {
     let normalizer = IdentNormalizer::new(/* normalize = */ true);
@@ -2069,3 +2478,49 @@ fn is_show_transaction_isolation_level(variable: &[String]) -> bool {
     const TRANSACTION_ISOLATION_LEVEL_STMT: [&str; 3] = ["transaction", "isolation", "level"];
     variable.iter().eq(TRANSACTION_ISOLATION_LEVEL_STMT.iter())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::{Engine, SessionStorageConfig};
+    use datafusion::variable::VarType;
+    use datafusion_ext::vars::SessionVars;
+
+    #[tokio::test]
+    async fn read_only_session_rejects_writes() {
+        let engine = Engine::from_data_dir(None).await.unwrap();
+        let vars = SessionVars::default().with_read_only(true, VarType::System);
+        let mut session = engine
+            .new_local_session_context(vars, SessionStorageConfig::default())
+            .await
+            .unwrap();
+
+        let plan = session.sql_to_lp("select 1").await.unwrap();
+        session.execute_inner(plan).await.unwrap();
+
+        let err = session
+            .sql_to_lp("drop table if exists t1")
+            .await
+            .unwrap_err();
+        assert_eq!("database is in read-only mode", err.to_string());
+
+        let err = session
+            .sql_to_lp(
+                "select * from iceberg_expire_snapshots('../../testdata/iceberg/tables/lineitem_versioned')",
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("read-only mode"), "{err}");
+
+        let err = session
+            .sql_to_lp("copy (select 1) to '/tmp/read_only_copy.csv'")
+            .await
+            .unwrap_err();
+        assert_eq!("database is in read-only mode", err.to_string());
+
+        let err = session
+            .sql_to_lp("explain analyze insert into glare_catalog.databases select * from glare_catalog.databases")
+            .await
+            .unwrap_err();
+        assert_eq!("database is in read-only mode", err.to_string());
+    }
+}
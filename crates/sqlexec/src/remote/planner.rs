@@ -14,6 +14,7 @@ use datafusion_ext::runtime::runtime_group::RuntimeGroupExec;
 use datafusion_ext::transform::TreeNodeExt;
 use protogen::metastore::types::catalog::RuntimePreference;
 use protogen::metastore::types::options::CopyToDestinationOptions;
+use sqlbuiltins::builtins::CURRENT_SESSION_SCHEMA;
 use tracing::debug;
 use uuid::Uuid;
 
@@ -22,16 +23,18 @@ use std::sync::Arc;
 use crate::metastore::catalog::{SessionCatalog, TempCatalog};
 use crate::planner::extension::ExtensionType;
 use crate::planner::logical_plan::{
-    AlterDatabase, AlterTable, AlterTunnelRotateKeys, CopyTo, CreateCredentials,
+    AlterDatabase, AlterTable, AlterTunnelRotateKeys, CopyFrom, CopyTo, CreateCredentials,
     CreateExternalDatabase, CreateExternalTable, CreateSchema, CreateTable, CreateTempTable,
     CreateTunnel, CreateView, Delete, DescribeTable, DropCredentials, DropDatabase, DropSchemas,
-    DropTables, DropTunnel, DropViews, Insert, SetVariable, ShowVariable, Update,
+    DropTables, DropTunnel, DropViews, Insert, MergeInto, SetVariable, ShowVariable,
+    TruncateTables, Update,
 };
 use crate::planner::physical_plan::alter_database::AlterDatabaseExec;
 use crate::planner::physical_plan::alter_table::AlterTableExec;
 use crate::planner::physical_plan::alter_tunnel_rotate_keys::AlterTunnelRotateKeysExec;
 use crate::planner::physical_plan::client_recv::ClientExchangeRecvExec;
 use crate::planner::physical_plan::client_send::ClientExchangeSendExec;
+use crate::planner::physical_plan::copy_from::CopyFromExec;
 use crate::planner::physical_plan::copy_to::CopyToExec;
 use crate::planner::physical_plan::create_credentials::CreateCredentialsExec;
 use crate::planner::physical_plan::create_external_database::CreateExternalDatabaseExec;
@@ -51,11 +54,13 @@ use crate::planner::physical_plan::drop_temp_tables::DropTempTablesExec;
 use crate::planner::physical_plan::drop_tunnel::DropTunnelExec;
 use crate::planner::physical_plan::drop_views::DropViewsExec;
 use crate::planner::physical_plan::insert::InsertExec;
+use crate::planner::physical_plan::merge_into::MergeIntoExec;
 use crate::planner::physical_plan::remote_exec::RemoteExecutionExec;
 use crate::planner::physical_plan::remote_scan::ProviderReference;
 use crate::planner::physical_plan::send_recv::SendRecvJoinExec;
 use crate::planner::physical_plan::set_var::SetVarExec;
 use crate::planner::physical_plan::show_var::ShowVarExec;
+use crate::planner::physical_plan::truncate_tables::TruncateTablesExec;
 use crate::planner::physical_plan::update::UpdateExec;
 
 use super::client::RemoteSessionClient;
@@ -92,12 +97,15 @@ impl ExtensionPlanner for DDLExtensionPlanner {
             }
             ExtensionType::AlterTable => {
                 let lp = require_downcast_lp::<AlterTable>(node);
-                let exec = AlterTableExec {
+                let exec = Arc::new(AlterTableExec {
                     catalog_version: self.catalog.version(),
                     schema: lp.schema.to_owned(),
                     name: lp.name.to_owned(),
                     operation: lp.operation.clone(),
-                };
+                    backfill: lp.backfill.clone(),
+                });
+                // Column changes need to update the table's storage.
+                let exec = RuntimeGroupExec::new(RuntimePreference::Remote, exec);
                 Ok(Some(Arc::new(exec)))
             }
             ExtensionType::AlterTunnelRotateKeys => {
@@ -189,6 +197,7 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     sql: lp.sql.clone(),
                     columns: lp.columns.clone(),
                     or_replace: lp.or_replace,
+                    if_not_exists: lp.if_not_exists,
                 })))
             }
             ExtensionType::DescribeTable => {
@@ -217,7 +226,7 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                 let mut temp_table_drops = Vec::with_capacity(plan.tbl_references.len());
 
                 for r in &plan.tbl_references {
-                    if tmp_catalog.contains_table(&r.name) {
+                    if r.schema == CURRENT_SESSION_SCHEMA && tmp_catalog.contains_table(&r.name) {
                         temp_table_drops.push(r.clone());
                     } else if self
                         .catalog
@@ -376,6 +385,39 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                 let exec = Arc::new(RuntimeGroupExec::new(lp.runtime_preference, exec));
                 Ok(Some(exec))
             }
+            ExtensionType::TruncateTables => {
+                let tmp_catalog = session_state
+                    .task_ctx()
+                    .session_config()
+                    .get_extension::<TempCatalog>()
+                    .unwrap();
+                let plan = require_downcast_lp::<TruncateTables>(node);
+                let mut tables = Vec::with_capacity(plan.tbl_references.len());
+
+                for r in &plan.tbl_references {
+                    if r.schema == CURRENT_SESSION_SCHEMA && tmp_catalog.contains_table(&r.name) {
+                        return Err(DataFusionError::Plan(
+                            "TRUNCATE is not supported for temp tables".to_string(),
+                        ));
+                    }
+                    let ent = self
+                        .catalog
+                        .resolve_table(&r.database, &r.schema, &r.name)
+                        .ok_or_else(|| {
+                            DataFusionError::Plan(format!("Table '{}' does not exist", r.name))
+                        })?;
+                    if ent.meta.external {
+                        return Err(DataFusionError::Plan(
+                            "TRUNCATE is not supported for external tables".to_string(),
+                        ));
+                    }
+                    tables.push(ent.clone());
+                }
+
+                let exec = Arc::new(TruncateTablesExec { tables });
+                let exec = RuntimeGroupExec::new(RuntimePreference::Remote, exec);
+                Ok(Some(Arc::new(exec)))
+            }
             ExtensionType::Delete => {
                 let lp = require_downcast_lp::<Delete>(node);
                 Ok(Some(Arc::new(DeleteExec {
@@ -383,6 +425,26 @@ impl ExtensionPlanner for DDLExtensionPlanner {
                     where_expr: lp.where_expr.clone(),
                 })))
             }
+            ExtensionType::MergeInto => {
+                let lp = require_downcast_lp::<MergeInto>(node);
+                Ok(Some(Arc::new(MergeIntoExec {
+                    table: lp.table.clone(),
+                    source: physical_inputs.get(0).unwrap().clone(),
+                    target_alias: lp.target_alias.clone(),
+                    source_alias: lp.source_alias.clone(),
+                    on: lp.on.clone(),
+                    clauses: lp.clauses.clone(),
+                })))
+            }
+            ExtensionType::CopyFrom => {
+                let lp = require_downcast_lp::<CopyFrom>(node);
+                Ok(Some(Arc::new(CopyFromExec {
+                    table: lp.table.clone(),
+                    location: lp.location.clone(),
+                    file_type: lp.file_type.clone(),
+                    storage_options: lp.storage_options.clone(),
+                })))
+            }
         }
     }
 }
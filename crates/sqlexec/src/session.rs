@@ -28,7 +28,9 @@ use datasources::native::access::NativeTableStorage;
 use futures::{Stream, StreamExt};
 use once_cell::sync::Lazy;
 use pgrepr::format::Format;
+use sqlbuiltins::functions::BuiltinTableFuncs;
 use telemetry::Tracker;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::background_jobs::JobRunner;
@@ -38,6 +40,7 @@ use crate::errors::{ExecError, Result};
 use crate::metrics::{
     BatchStreamWithMetricSender, ExecutionStatus, QueryMetrics, SessionMetricsHandler,
 };
+use crate::notice::Notice;
 use crate::parser::StatementWithExtensions;
 use crate::planner::logical_plan::*;
 
@@ -71,6 +74,8 @@ pub enum ExecutionResult {
     DeleteSuccess { deleted_rows: usize },
     /// Data successfully updated.
     UpdateSuccess { updated_rows: usize },
+    /// Data successfully merged.
+    MergeSuccess { merged_rows: usize },
     /// Data successfully copied.
     CopySuccess,
     /// Table created.
@@ -105,6 +110,8 @@ pub enum ExecutionResult {
     DropTunnel,
     /// Credentials are dropped.
     DropCredentials,
+    /// Tables truncated.
+    TruncateTables,
 }
 
 impl ExecutionResult {
@@ -166,6 +173,7 @@ impl ExecutionResult {
             ExecutionResult::InsertSuccess { .. } => "insert",
             ExecutionResult::DeleteSuccess { .. } => "delete",
             ExecutionResult::UpdateSuccess { .. } => "update",
+            ExecutionResult::MergeSuccess { .. } => "merge",
             ExecutionResult::CopySuccess => "copy",
             ExecutionResult::CreateTable => "create_table",
             ExecutionResult::CreateDatabase => "create_database",
@@ -183,6 +191,7 @@ impl ExecutionResult {
             ExecutionResult::DropDatabase => "drop_database",
             ExecutionResult::DropTunnel => "drop_tunnel",
             ExecutionResult::DropCredentials => "drop_credentials",
+            ExecutionResult::TruncateTables => "truncate_tables",
         }
     }
 
@@ -225,6 +234,9 @@ impl ExecutionResult {
             "update" => ExecutionResult::UpdateSuccess {
                 updated_rows: count.unwrap_or_default() as usize,
             },
+            "merge" => ExecutionResult::MergeSuccess {
+                merged_rows: count.unwrap_or_default() as usize,
+            },
             "copy" => ExecutionResult::CopySuccess,
             "create_table" => ExecutionResult::CreateTable,
             "create_database" => ExecutionResult::CreateDatabase,
@@ -242,6 +254,7 @@ impl ExecutionResult {
             "drop_database" => ExecutionResult::DropDatabase,
             "drop_tunnel" => ExecutionResult::DropTunnel,
             "drop_credentials" => ExecutionResult::DropCredentials,
+            "truncate_tables" => ExecutionResult::TruncateTables,
             _ => return None,
         })
     }
@@ -281,6 +294,13 @@ impl fmt::Display for ExecutionResult {
                     write!(f, "Updated {} rows", updated_rows)
                 }
             }
+            ExecutionResult::MergeSuccess { merged_rows } => {
+                if *merged_rows == 1 {
+                    write!(f, "Merged 1 row")
+                } else {
+                    write!(f, "Merged {} rows", merged_rows)
+                }
+            }
             ExecutionResult::CopySuccess => write!(f, "Copy success"),
             ExecutionResult::CreateTable => write!(f, "Table created"),
             ExecutionResult::CreateDatabase => write!(f, "Database created"),
@@ -298,6 +318,7 @@ impl fmt::Display for ExecutionResult {
             ExecutionResult::DropDatabase => write!(f, "Database(s) dropped"),
             ExecutionResult::DropTunnel => write!(f, "Tunnel(s) dropped"),
             ExecutionResult::DropCredentials => write!(f, "Credentials dropped"),
+            ExecutionResult::TruncateTables => write!(f, "Table(s) truncated"),
         }
     }
 }
@@ -326,6 +347,40 @@ impl RecordBatchStream for StreamAndFirstResult {
     }
 }
 
+/// Stream adapter that errors once more than `max_rows` rows have been
+/// returned.
+struct MaxRowsStream {
+    stream: SendableRecordBatchStream,
+    max_rows: usize,
+    rows: usize,
+}
+
+impl Stream for MaxRowsStream {
+    type Item = DataFusionResult<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.stream.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(batch))) => {
+                self.rows += batch.num_rows();
+                if self.rows > self.max_rows {
+                    return Poll::Ready(Some(Err(DataFusionError::Execution(format!(
+                        "Query returned more than the maximum of {} rows",
+                        self.max_rows
+                    )))));
+                }
+                Poll::Ready(Some(Ok(batch)))
+            }
+            other => other,
+        }
+    }
+}
+
+impl RecordBatchStream for MaxRowsStream {
+    fn schema(&self) -> Arc<Schema> {
+        self.stream.schema()
+    }
+}
+
 /// A per-client user session.
 ///
 /// This is a thin wrapper around a session context. Having a layer between
@@ -386,6 +441,17 @@ impl Session {
         self.ctx.register_env_reader(env_reader);
     }
 
+    /// Use `table_funcs` for resolving table functions registered at runtime
+    /// instead of the global builtins.
+    pub fn register_table_funcs(&mut self, table_funcs: Arc<BuiltinTableFuncs>) {
+        self.ctx.register_table_funcs(table_funcs);
+    }
+
+    /// Subscribe to notices raised while executing queries in this session.
+    pub fn subscribe_notices(&self) -> mpsc::Receiver<Notice> {
+        self.ctx.get_notices().subscribe()
+    }
+
     /// Return the DF session context.
     pub fn df_ctx(&self) -> &datafusion::prelude::SessionContext {
         self.ctx.df_ctx()
@@ -489,7 +555,21 @@ impl Session {
                 let physical = self.create_physical_plan(plan).await?;
                 let stream = self.execute_physical(physical.clone())?;
 
-                let stream = ExecutionResult::from_stream(stream).await;
+                let stream = match ExecutionResult::from_stream(stream).await {
+                    ExecutionResult::Query { stream } => {
+                        match self.ctx.get_session_vars().max_result_rows() {
+                            Some(max_rows) => ExecutionResult::Query {
+                                stream: Box::pin(MaxRowsStream {
+                                    stream,
+                                    max_rows,
+                                    rows: 0,
+                                }),
+                            },
+                            None => ExecutionResult::Query { stream },
+                        }
+                    }
+                    result => result,
+                };
 
                 // If we're attached to a remote node, and the result indicates
                 // the operation was a DDL operation, then fetch the newer
@@ -672,3 +752,149 @@ impl Session {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ExecutionResult, Session};
+    use crate::engine::{Engine, SessionStorageConfig};
+    use crate::errors::Result;
+    use async_trait::async_trait;
+    use datafusion::arrow::array::{Int64Array, StringArray};
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::datasource::{MemTable, TableProvider};
+    use datafusion::variable::VarType;
+    use datafusion_ext::functions::{FuncParamValue, TableFunc, TableFuncContextProvider};
+    use datafusion_ext::vars::SessionVars;
+    use futures::TryStreamExt;
+    use protogen::metastore::types::catalog::RuntimePreference;
+    use sqlbuiltins::functions::BuiltinTableFuncs;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn max_result_rows_enforced() {
+        let engine = Engine::from_data_dir(None).await.unwrap();
+        let vars = SessionVars::default().with_max_result_rows(5, VarType::System);
+        let mut session = engine
+            .new_local_session_context(vars, SessionStorageConfig::default())
+            .await
+            .unwrap();
+
+        async fn collect_rows(session: &mut Session, sql: &str) -> Result<usize> {
+            let plan = session.sql_to_lp(sql).await?;
+            let stream = match session.execute_inner(plan).await?.1 {
+                ExecutionResult::Query { stream } => stream,
+                _ => panic!("expected query result"),
+            };
+            let batches: Vec<_> = stream.try_collect().await?;
+            Ok(batches.iter().map(|b| b.num_rows()).sum())
+        }
+
+        let rows = collect_rows(&mut session, "select * from generate_series(1, 5)")
+            .await
+            .unwrap();
+        assert_eq!(5, rows);
+
+        let rows = collect_rows(
+            &mut session,
+            "select * from generate_series(1, 100) limit 3",
+        )
+        .await
+        .unwrap();
+        assert_eq!(3, rows);
+
+        let err = collect_rows(&mut session, "select * from generate_series(1, 6)")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Query returned more than the maximum of 5 rows"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn registered_table_func_callable_from_sql() {
+        /// Produces `n` rows of the string 'custom'.
+        struct RepeatCustom {
+            name: &'static str,
+        }
+
+        #[async_trait]
+        impl TableFunc for RepeatCustom {
+            fn name(&self) -> &str {
+                self.name
+            }
+
+            fn runtime_preference(&self) -> RuntimePreference {
+                RuntimePreference::Local
+            }
+
+            async fn create_provider(
+                &self,
+                _: &dyn TableFuncContextProvider,
+                args: Vec<FuncParamValue>,
+                _: HashMap<String, FuncParamValue>,
+            ) -> datafusion_ext::errors::Result<Arc<dyn TableProvider>> {
+                let n: i64 = args.into_iter().next().unwrap().param_into()?;
+                let schema = Arc::new(Schema::new(vec![Field::new("v", DataType::Utf8, false)]));
+                let batch = RecordBatch::try_new(
+                    schema.clone(),
+                    vec![Arc::new(StringArray::from(vec!["custom"; n as usize]))],
+                )?;
+                Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+            }
+        }
+
+        // Registered on a registry for this session only, other tests use the
+        // global builtins.
+        let funcs = Arc::new(BuiltinTableFuncs::new());
+        funcs
+            .register_table_func(Arc::new(RepeatCustom {
+                name: "repeat_custom",
+            }))
+            .unwrap();
+
+        // Builtins can't be replaced.
+        funcs
+            .register_table_func(Arc::new(RepeatCustom {
+                name: "generate_series",
+            }))
+            .unwrap_err();
+
+        let engine = Engine::from_data_dir(None).await.unwrap();
+        let mut session = engine
+            .new_local_session_context(SessionVars::default(), SessionStorageConfig::default())
+            .await
+            .unwrap();
+        session.register_table_funcs(funcs);
+
+        let plan = session
+            .sql_to_lp("select count(*) from repeat_custom(3) where v = 'custom'")
+            .await
+            .unwrap();
+        let stream = match session.execute_inner(plan).await.unwrap().1 {
+            ExecutionResult::Query { stream } => stream,
+            _ => panic!("expected query result"),
+        };
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let count = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .value(0);
+        assert_eq!(3, count);
+
+        // Not visible to other sessions.
+        let mut other = engine
+            .new_local_session_context(SessionVars::default(), SessionStorageConfig::default())
+            .await
+            .unwrap();
+        other
+            .sql_to_lp("select * from repeat_custom(3)")
+            .await
+            .unwrap_err();
+    }
+}
@@ -212,6 +212,9 @@ impl Cli {
                     None,
                     /* integration_testing = */ true,
                     /* disable_rpc_auth = */ self.rpc_test,
+                    /* allow_ambient_credentials = */ false,
+                    /* read_only = */ false,
+                    /* max_result_rows = */ None,
                 )
                 .await?;
                 tokio::spawn(server.serve(server_conf));
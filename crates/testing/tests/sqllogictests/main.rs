@@ -5,7 +5,7 @@ use anyhow::Result;
 use hooks::{AllTestsHook, SshTunnelHook};
 use std::sync::Arc;
 use testing::slt::runner::SltRunner;
-use tests::{PgBinaryEncoding, SshKeysTest};
+use tests::{IcebergMetadataFetchMetric, PgBinaryEncoding, SshKeysTest};
 
 fn main() -> Result<()> {
     SltRunner::new()
@@ -13,6 +13,10 @@ fn main() -> Result<()> {
         // Rust tests
         .test("sqllogictests/ssh_keys", Box::new(SshKeysTest))?
         .test("pgproto/binary_encoding", Box::new(PgBinaryEncoding))?
+        .test(
+            "sqllogictests_iceberg/metadata_fetch_metric",
+            Box::new(IcebergMetadataFetchMetric),
+        )?
         // Add hooks
         .hook("*", Arc::new(AllTestsHook))?
         // SSH Tunnels hook
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use sqllogictest::{AsyncDB, DBOutput};
 use testing::slt::runner::{FnTest, TestClient};
 use tokio_postgres::Config;
 
@@ -146,3 +147,32 @@ impl FnTest for PgBinaryEncoding {
         Ok(())
     }
 }
+
+/// Checks that the time spent creating a table function's provider is
+/// reported by EXPLAIN ANALYZE.
+pub struct IcebergMetadataFetchMetric;
+
+#[async_trait]
+impl FnTest for IcebergMetadataFetchMetric {
+    async fn run(
+        &self,
+        _config: &Config,
+        mut client: TestClient,
+        _vars: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        let output = client
+            .run("explain analyze select * from iceberg_snapshots('../../testdata/iceberg/tables/lineitem_versioned')")
+            .await?;
+        let rows = match output {
+            DBOutput::Rows { rows, .. } => rows,
+            _ => return Err(anyhow!("expected rows from explain analyze")),
+        };
+
+        test_assert!(
+            rows.iter().flatten().any(|v| v.contains("metadata_fetch")),
+            anyhow!("metadata_fetch missing from explain analyze output: {rows:?}")
+        );
+
+        Ok(())
+    }
+}